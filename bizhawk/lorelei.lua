@@ -0,0 +1,67 @@
+-- Sends the current state to the Lorelei Simulator when a hotkey is pressed, and prints the results.
+--
+-- Start the simulator with:   lorelei_simulator_cli bizhawk path/to/rom
+-- Then start BizHawk with:    EmuHawk --socket_ip=127.0.0.1 --socket_port=9999
+-- and load this script in the Lua console. Press the hotkey just before the AI decides (the same point you'd make a
+-- save state at).
+
+-- Key to press (as named by BizHawk's input.get(), e.g. F10 or NumberPad5)
+local HOTKEY = "F10"
+
+-- How long to wait for results, in milliseconds
+local TIMEOUT = 120000
+
+local function hex(domain, address, length)
+    local bytes = memory.read_bytes_as_array(address, length, domain)
+    local digits = {}
+    for i, byte in ipairs(bytes) do
+        digits[i] = string.format("%02X", byte)
+    end
+    return table.concat(digits)
+end
+
+local function pair(registers, high, low)
+    return string.format("%04X", registers[high] * 256 + registers[low])
+end
+
+local function state()
+    local r = emu.getregisters()
+    local lines = {
+        "LORELEI BIZHAWK 1",
+        "pc " .. string.format("%04X", r["PC"]),
+        "sp " .. string.format("%04X", r["SP"]),
+        "af " .. pair(r, "A", "F"),
+        "bc " .. pair(r, "B", "C"),
+        "de " .. pair(r, "D", "E"),
+        "hl " .. pair(r, "H", "L"),
+        "ie " .. hex("System Bus", 0xFFFF, 1),
+        "io " .. hex("System Bus", 0xFF00, 0x80),
+        "wram " .. hex("WRAM", 0, memory.getmemorydomainsize("WRAM")),
+        "vram " .. hex("VRAM", 0, memory.getmemorydomainsize("VRAM")),
+        "oam " .. hex("OAM", 0, 0xA0),
+        "hram " .. hex("HRAM", 0, 0x7F)
+    }
+    if r["IME"] ~= nil then
+        table.insert(lines, "ime " .. string.format("%02X", r["IME"]))
+    end
+    return table.concat(lines, "\n")
+end
+
+if not comm.socketServerIsConnected() then
+    print("Not connected to the simulator; start BizHawk with --socket_ip and --socket_port")
+    return
+end
+comm.socketServerSetTimeout(TIMEOUT)
+print("Press " .. HOTKEY .. " to simulate")
+
+local was_pressed = false
+while true do
+    local pressed = input.get()[HOTKEY] == true
+    if pressed and not was_pressed then
+        print("Simulating...")
+        comm.socketServerSend(state())
+        print(comm.socketServerResponse())
+    end
+    was_pressed = pressed
+    emu.frameadvance()
+end
@@ -0,0 +1,420 @@
+//! Parsers for the Pokémon structs the games keep in WRAM.
+//!
+//! All multi-byte values are stored big-endian by the games.
+
+use std::fmt::{Display, Formatter};
+use crate::data::{Generation, Species, TrainerCategory};
+
+/// Non-volatile status condition of a Pokémon.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StatusCondition {
+    None,
+
+    /// Asleep for the given number of turns
+    Sleep(u8),
+    Poison,
+    Burn,
+    Freeze,
+    Paralysis
+}
+
+impl StatusCondition {
+    /// Decode a status byte.
+    ///
+    /// This is the same for Generation 1 and 2; bad poison is tracked separately as a battle substatus.
+    pub const fn from_u8(value: u8) -> Self {
+        if value & 0b111 != 0 {
+            Self::Sleep(value & 0b111)
+        }
+        else if value & (1 << 3) != 0 {
+            Self::Poison
+        }
+        else if value & (1 << 4) != 0 {
+            Self::Burn
+        }
+        else if value & (1 << 5) != 0 {
+            Self::Freeze
+        }
+        else if value & (1 << 6) != 0 {
+            Self::Paralysis
+        }
+        else {
+            Self::None
+        }
+    }
+
+    /// Get the abbreviation shown in-game, or `None` if there is no status.
+    pub const fn abbreviation(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Sleep(_) => Some("SLP"),
+            Self::Poison => Some("PSN"),
+            Self::Burn => Some("BRN"),
+            Self::Freeze => Some("FRZ"),
+            Self::Paralysis => Some("PAR")
+        }
+    }
+}
+
+/// Calculated stats of a Pokémon.
+///
+/// In Generation 1, `special_attack` and `special_defense` are both the Special stat.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Stats {
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub special_attack: u16,
+    pub special_defense: u16
+}
+
+/// A Pokémon as stored in the active battle struct (i.e. the Pokémon currently out).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BattleMon {
+    /// Raw species index for the generation
+    pub species_index: u8,
+
+    /// Species, or `None` if the index is unused
+    pub species: Option<Species>,
+
+    /// Held item (Generation 2 only)
+    pub item: Option<u8>,
+    pub level: u8,
+    pub hp: u16,
+    pub max_hp: u16,
+    pub status: StatusCondition,
+
+    /// Raw type indices
+    pub types: [u8; 2],
+
+    /// Move indices; 0 is an empty slot
+    pub moves: [u8; 4],
+
+    /// Current PP; the upper two bits are PP Ups
+    pub pp: [u8; 4],
+    pub dvs: u16,
+    pub stats: Stats
+}
+
+impl BattleMon {
+    /// Get the size of the battle struct for the given generation.
+    pub const fn size(generation: Generation) -> usize {
+        match generation {
+            Generation::One => 0x1D,
+            Generation::Two => 0x20
+        }
+    }
+
+    /// Parse a battle struct.
+    ///
+    /// Returns `None` if `data` is smaller than [`BattleMon::size`].
+    pub fn parse(generation: Generation, data: &[u8]) -> Option<Self> {
+        let data = data.get(..Self::size(generation))?;
+        let mon = match generation {
+            Generation::One => Self {
+                species_index: data[0],
+                species: Species::from_index(generation, data[0]),
+                item: None,
+                hp: read_u16(data, 0x01),
+                status: StatusCondition::from_u8(data[0x04]),
+                types: [data[0x05], data[0x06]],
+                moves: read_array(data, 0x08),
+                dvs: read_u16(data, 0x0C),
+                level: data[0x0E],
+                max_hp: read_u16(data, 0x0F),
+                stats: Stats {
+                    attack: read_u16(data, 0x11),
+                    defense: read_u16(data, 0x13),
+                    speed: read_u16(data, 0x15),
+                    special_attack: read_u16(data, 0x17),
+                    special_defense: read_u16(data, 0x17)
+                },
+                pp: read_array(data, 0x19),
+            },
+            Generation::Two => Self {
+                species_index: data[0],
+                species: Species::from_index(generation, data[0]),
+                item: Some(data[0x01]),
+                moves: read_array(data, 0x02),
+                dvs: read_u16(data, 0x06),
+                pp: read_array(data, 0x08),
+                level: data[0x0D],
+                status: StatusCondition::from_u8(data[0x0E]),
+                hp: read_u16(data, 0x10),
+                max_hp: read_u16(data, 0x12),
+                stats: Stats {
+                    attack: read_u16(data, 0x14),
+                    defense: read_u16(data, 0x16),
+                    speed: read_u16(data, 0x18),
+                    special_attack: read_u16(data, 0x1A),
+                    special_defense: read_u16(data, 0x1C)
+                },
+                types: [data[0x1E], data[0x1F]],
+            }
+        };
+        Some(mon)
+    }
+}
+
+/// A Pokémon as stored in a party.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PartyMon {
+    /// Raw species index for the generation
+    pub species_index: u8,
+
+    /// Species, or `None` if the index is unused
+    pub species: Option<Species>,
+
+    /// Held item (Generation 2 only)
+    pub item: Option<u8>,
+    pub level: u8,
+    pub hp: u16,
+    pub max_hp: u16,
+    pub status: StatusCondition,
+
+    /// Move indices; 0 is an empty slot
+    pub moves: [u8; 4],
+
+    /// Current PP; the upper two bits are PP Ups
+    pub pp: [u8; 4],
+    pub ot_id: u16,
+    pub experience: u32,
+    pub dvs: u16,
+    pub stats: Stats
+}
+
+impl PartyMon {
+    /// Get the size of the party struct for the given generation.
+    pub const fn size(generation: Generation) -> usize {
+        match generation {
+            Generation::One => 0x2C,
+            Generation::Two => 0x30
+        }
+    }
+
+    /// Parse a party struct.
+    ///
+    /// Returns `None` if `data` is smaller than [`PartyMon::size`].
+    pub fn parse(generation: Generation, data: &[u8]) -> Option<Self> {
+        let data = data.get(..Self::size(generation))?;
+        let mon = match generation {
+            Generation::One => Self {
+                species_index: data[0],
+                species: Species::from_index(generation, data[0]),
+                item: None,
+                hp: read_u16(data, 0x01),
+                status: StatusCondition::from_u8(data[0x04]),
+                moves: read_array(data, 0x08),
+                ot_id: read_u16(data, 0x0C),
+                experience: read_u24(data, 0x0E),
+                dvs: read_u16(data, 0x1B),
+                pp: read_array(data, 0x1D),
+                level: data[0x21],
+                max_hp: read_u16(data, 0x22),
+                stats: Stats {
+                    attack: read_u16(data, 0x24),
+                    defense: read_u16(data, 0x26),
+                    speed: read_u16(data, 0x28),
+                    special_attack: read_u16(data, 0x2A),
+                    special_defense: read_u16(data, 0x2A)
+                }
+            },
+            Generation::Two => Self {
+                species_index: data[0],
+                species: Species::from_index(generation, data[0]),
+                item: Some(data[0x01]),
+                moves: read_array(data, 0x02),
+                ot_id: read_u16(data, 0x06),
+                experience: read_u24(data, 0x08),
+                dvs: read_u16(data, 0x15),
+                pp: read_array(data, 0x17),
+                level: data[0x1F],
+                status: StatusCondition::from_u8(data[0x20]),
+                hp: read_u16(data, 0x22),
+                max_hp: read_u16(data, 0x24),
+                stats: Stats {
+                    attack: read_u16(data, 0x26),
+                    defense: read_u16(data, 0x28),
+                    speed: read_u16(data, 0x2A),
+                    special_attack: read_u16(data, 0x2C),
+                    special_defense: read_u16(data, 0x2E)
+                }
+            }
+        };
+        Some(mon)
+    }
+}
+
+/// Stat stages of the active Pokémon, from -6 to +6.
+///
+/// In Generation 1, `special_attack` and `special_defense` are both the Special stage.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct StatStages {
+    pub attack: i8,
+    pub defense: i8,
+    pub speed: i8,
+    pub special_attack: i8,
+    pub special_defense: i8,
+    pub accuracy: i8,
+    pub evasion: i8
+}
+
+impl StatStages {
+    /// Get the number of stat stage bytes stored for the given generation.
+    pub const fn size(generation: Generation) -> usize {
+        match generation {
+            Generation::One => 6,
+            Generation::Two => 7
+        }
+    }
+
+    /// Parse stat stages, which the games store with 7 as the neutral stage.
+    ///
+    /// Returns `None` if `data` is smaller than [`StatStages::size`].
+    pub fn parse(generation: Generation, data: &[u8]) -> Option<Self> {
+        let data = data.get(..Self::size(generation))?;
+        let stage = |offset: usize| data[offset] as i8 - 7;
+        let stages = match generation {
+            Generation::One => Self {
+                attack: stage(0),
+                defense: stage(1),
+                speed: stage(2),
+                special_attack: stage(3),
+                special_defense: stage(3),
+                accuracy: stage(4),
+                evasion: stage(5)
+            },
+            Generation::Two => Self {
+                attack: stage(0),
+                defense: stage(1),
+                speed: stage(2),
+                special_attack: stage(3),
+                special_defense: stage(4),
+                accuracy: stage(5),
+                evasion: stage(6)
+            }
+        };
+        Some(stages)
+    }
+}
+
+/// One of the two active Pokémon.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Side {
+    Player,
+    Enemy
+}
+
+/// A change to the active Pokémon's battle structs, for seeing how the AI would decide in a slightly different
+/// battle (see [`crate::Simulator::patch_battle`]).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum BattlePatch {
+    /// Set the HP to a percentage of the max HP, rounding down but leaving at least 1 HP
+    HpPercent { side: Side, percent: u8 },
+
+    /// Set the status byte (see [`StatusCondition::from_u8`])
+    Status { side: Side, status: u8 },
+
+    /// Replace the move in a slot (0-3)
+    Move { side: Side, slot: u8, move_index: u8 },
+
+    /// Write a byte anywhere in memory
+    Memory { address: u16, value: u8 }
+}
+
+impl BattlePatch {
+    /// Get the bytes to write for this patch, given the address of the side's battle struct and the struct itself.
+    pub(crate) fn writes(self, generation: Generation, battle_mon: u16, mon: &BattleMon) -> Vec<(u16, u8)> {
+        let (hp, status, moves) = match generation {
+            Generation::One => (0x01, 0x04, 0x08),
+            Generation::Two => (0x10, 0x0E, 0x02)
+        };
+        match self {
+            Self::HpPercent { percent, .. } => {
+                let value = ((mon.max_hp as u32 * percent.min(100) as u32 / 100) as u16).max(1);
+                let [high, low] = value.to_be_bytes();
+                vec![(battle_mon + hp, high), (battle_mon + hp + 1, low)]
+            },
+            Self::Status { status: value, .. } => vec![(battle_mon + status, value)],
+            Self::Move { slot, move_index, .. } => vec![(battle_mon + moves + slot.min(3) as u16, move_index)],
+            Self::Memory { address, value } => vec![(address, value)]
+        }
+    }
+
+    /// Get the side this patches, if any.
+    pub const fn side(self) -> Option<Side> {
+        match self {
+            Self::HpPercent { side, .. } | Self::Status { side, .. } | Self::Move { side, .. } => Some(side),
+            Self::Memory { .. } => None
+        }
+    }
+}
+
+/// Both sides of the battle in a save state.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BattleContext {
+    /// Whether or not this is a trainer battle (as opposed to a wild battle)
+    pub trainer_battle: bool,
+    pub player: BattleMon,
+    pub player_stat_stages: StatStages,
+    pub enemy: BattleMon,
+    pub enemy_stat_stages: StatStages,
+
+    /// The player's badges as bit flags (see [`badge_names`])
+    pub badges: u16
+}
+
+/// Get the names of the badges set in `badges`.
+///
+/// In Generation 2, the low byte is the Johto badges and the high byte is the Kanto badges.
+pub fn badge_names(generation: Generation, badges: u16) -> Vec<&'static str> {
+    const KANTO: [&str; 8] = ["BOULDER", "CASCADE", "THUNDER", "RAINBOW", "SOUL", "MARSH", "VOLCANO", "EARTH"];
+    const JOHTO: [&str; 8] = ["ZEPHYR", "HIVE", "PLAIN", "FOG", "STORM", "MINERAL", "GLACIER", "RISING"];
+
+    let all: &[&str] = match generation {
+        Generation::One => &KANTO,
+        Generation::Two => &[JOHTO, KANTO].concat()
+    };
+    all.iter().enumerate().filter(|(i, _)| badges & (1 << i) != 0).map(|(_, &name)| name).collect()
+}
+
+/// The opposing trainer in a trainer battle.
+///
+/// This displays like `LORELEI (Elite Four)`, or `YOUNGSTER #3` for regular trainers.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Opponent {
+    /// Raw trainer class index
+    pub class: u8,
+
+    /// Which of the class's parties the trainer uses, starting at 1
+    pub id: u8,
+
+    /// Name of the trainer class, or `None` if the class is unknown
+    pub name: Option<&'static str>,
+    pub category: TrainerCategory
+}
+
+impl Display for Opponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => f.write_str(name)?,
+            None => write!(f, "UNK (0x{:02X})", self.class)?
+        }
+        match self.category.name() {
+            Some(category) => write!(f, " ({category})"),
+            None => write!(f, " #{}", self.id)
+        }
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u24(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([0, data[offset], data[offset + 1], data[offset + 2]])
+}
+
+fn read_array(data: &[u8], offset: usize) -> [u8; 4] {
+    data[offset..offset + 4].try_into().unwrap()
+}
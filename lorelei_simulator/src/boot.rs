@@ -0,0 +1,51 @@
+//! Minimal boot ROMs for starting the games from power-on.
+//!
+//! The emulator core doesn't ship with boot ROMs, so these just set up the registers the way the real boot ROMs
+//! leave them and hand control over to the cartridge. There's no logo or boot animation.
+
+/// The DMG boot ROM (0x100 bytes).
+const DMG: &[u8] = &[
+    0x31, 0xFE, 0xFF,       // ld sp, $FFFE
+    0x3E, 0x91,             // ld a, $91
+    0xE0, 0x40,             // ldh [rLCDC], a
+    0x3E, 0xFC,             // ld a, $FC
+    0xE0, 0x47,             // ldh [rBGP], a
+    0x21, 0xB0, 0x01,       // ld hl, $01B0
+    0xE5,                   // push hl
+    0xF1,                   // pop af
+    0x01, 0x13, 0x00,       // ld bc, $0013
+    0x11, 0xD8, 0x00,       // ld de, $00D8
+    0x21, 0x4D, 0x01,       // ld hl, $014D
+    0xC3, 0xFE, 0x00        // jp $00FE
+];
+
+/// The CGB boot ROM (0x900 bytes).
+const CGB: &[u8] = &[
+    0x31, 0xFE, 0xFF,       // ld sp, $FFFE
+    0xFA, 0x43, 0x01,       // ld a, [$0143]
+    0xCB, 0x7F,             // bit 7, a
+    0x20, 0x02,             // jr nz, .cgb
+    0x3E, 0x04,             // ld a, $04 (DMG compatibility mode)
+                            // .cgb
+    0xE0, 0x4C,             // ldh [rKEY0], a
+    0x3E, 0x91,             // ld a, $91
+    0xE0, 0x40,             // ldh [rLCDC], a
+    0x3E, 0xFC,             // ld a, $FC
+    0xE0, 0x47,             // ldh [rBGP], a
+    0x21, 0x80, 0x11,       // ld hl, $1180
+    0xE5,                   // push hl
+    0xF1,                   // pop af
+    0x01, 0x00, 0x00,       // ld bc, $0000
+    0x11, 0x56, 0xFF,       // ld de, $FF56
+    0x21, 0x0D, 0x00,       // ld hl, $000D
+    0xC3, 0xFE, 0x00        // jp $00FE
+];
+
+/// Build a boot ROM. The last instruction unmaps it, so the CPU continues at $0100 in the cartridge.
+pub fn boot_rom(cgb: bool) -> Vec<u8> {
+    let (code, size) = if cgb { (CGB, 0x900) } else { (DMG, 0x100) };
+    let mut rom = vec![0u8; size];
+    rom[..code.len()].copy_from_slice(code);
+    rom[0xFE..0x100].copy_from_slice(&[0xE0, 0x50]); // ldh [rBANK], a
+    rom
+}
@@ -0,0 +1,85 @@
+//! The format of [`crate::Simulator::checkpoint`], which has everything needed to carry on a run later.
+//!
+//! All values are little-endian:
+//!
+//! - the magic `LSCP` and a `u32` format version
+//! - the state hash (`u64`) and whether the save state is warm (`u8`)
+//! - the number of results (`u32`), then each one's kind (`u8`, 0 for a move or 1 for an item), index (`u8`), and
+//!   count (`u64`)
+//! - the save state's length (`u64`) and the save state itself
+
+use crate::Outcome;
+
+const MAGIC: &[u8; 4] = b"LSCP";
+const VERSION: u32 = 1;
+
+pub(crate) struct Checkpoint {
+    pub state_hash: u64,
+    pub warm: bool,
+    pub results: Vec<(Outcome, u64)>,
+    pub save_state: Vec<u8>
+}
+
+impl Checkpoint {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.save_state.len() + self.results.len() * 10 + 32);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.state_hash.to_le_bytes());
+        bytes.push(self.warm as u8);
+        bytes.extend_from_slice(&(self.results.len() as u32).to_le_bytes());
+        for &(outcome, count) in &self.results {
+            let (kind, index) = match outcome {
+                Outcome::Move(index) => (0, index),
+                Outcome::Item(index) => (1, index)
+            };
+            bytes.extend_from_slice(&[kind, index]);
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(self.save_state.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.save_state);
+        bytes
+    }
+
+    /// Read a checkpoint, or return `None` if it isn't one or it's from a newer version.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader { bytes };
+        if reader.take(4)? != MAGIC || u32::from_le_bytes(reader.array()?) != VERSION {
+            return None;
+        }
+        let state_hash = u64::from_le_bytes(reader.array()?);
+        let warm = reader.array::<1>()?[0] != 0;
+        let result_count = u32::from_le_bytes(reader.array()?);
+        let results = (0..result_count).map(|_| {
+            let [kind, index] = reader.array()?;
+            let outcome = match kind {
+                0 => Outcome::Move(index),
+                1 => Outcome::Item(index),
+                _ => return None
+            };
+            Some((outcome, u64::from_le_bytes(reader.array()?)))
+        }).collect::<Option<Vec<_>>>()?;
+        let save_state_length = usize::try_from(u64::from_le_bytes(reader.array()?)).ok()?;
+        let save_state = reader.take(save_state_length)?.to_vec();
+        reader.bytes.is_empty().then_some(Self { state_hash, warm, results, save_state })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8]
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < length {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N).map(|bytes| bytes.try_into().unwrap())
+    }
+}
@@ -1,3 +1,81 @@
+mod items;
+mod move_names;
+mod moves;
+mod species;
+mod text;
+mod trainers;
+mod types;
+
+pub use items::item_name;
+pub use move_names::localized_move_name;
+pub use moves::{move_info, MoveInfo};
+pub use species::Species;
+pub use text::{decode_text, encode_text, Language, TEXT_TERMINATOR};
+pub use trainers::{trainer_class, Gen1TrainerClass, TrainerAiAction, TrainerAiActionKind, TrainerCategory};
+pub use types::{Effectiveness, Type};
+
+use crate::json::JsonValue;
+
+/// A generation of Pokémon games.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Generation {
+    /// Pokémon Red, Blue, and Yellow
+    One,
+
+    /// Pokémon Gold, Silver, and Crystal
+    Two
+}
+
+impl Generation {
+    /// Get the generation's number (1 or 2).
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2
+        }
+    }
+}
+
+/// Build a JSON object with the move, species, and item tables.
+pub fn tables_json() -> JsonValue {
+    let moves = (1..=u8::MAX).filter_map(|index| {
+        let m = MoveType::from_u8(index)?;
+        let generation = if index <= MoveType::Struggle as u8 { Generation::One } else { Generation::Two };
+        Some(JsonValue::object([
+            ("index", index.into()),
+            ("name", m.name().into()),
+            ("generation", generation.number().into())
+        ]))
+    }).collect();
+
+    let species = (1..=u8::MAX).filter_map(Species::from_dex).map(|s| {
+        let generation = if s.gen1_index().is_some() { Generation::One } else { Generation::Two };
+        JsonValue::object([
+            ("dex", s.dex().into()),
+            ("name", s.name().into()),
+            ("gen1_index", s.gen1_index().into()),
+            ("generation", generation.number().into())
+        ])
+    }).collect();
+
+    let items = |generation| (1..=u8::MAX).filter_map(|index| {
+        let name = item_name(generation, index)?;
+        Some(JsonValue::object([
+            ("index", index.into()),
+            ("name", name.into())
+        ]))
+    }).collect();
+
+    JsonValue::object([
+        ("moves", JsonValue::Array(moves)),
+        ("species", JsonValue::Array(species)),
+        ("items", JsonValue::object([
+            ("gen1", JsonValue::Array(items(Generation::One))),
+            ("gen2", JsonValue::Array(items(Generation::Two)))
+        ]))
+    ])
+}
+
 /// Corresponds to all of the moves in Generation 1 and 2.
 #[derive(Copy, Clone, Default, PartialEq)]
 #[repr(u8)]
@@ -520,6 +598,16 @@ impl MoveType {
         Some(move_type)
     }
 
+    /// Look up a move by its English name, ignoring case.
+    ///
+    /// Returns `None` if no move has that name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        (0x01..=u8::MAX)
+            .filter_map(Self::from_u8)
+            .find(|m| m.name().eq_ignore_ascii_case(name))
+    }
+
     /// Get the English name of the move.
     pub const fn name(self) -> &'static str {
         match self {
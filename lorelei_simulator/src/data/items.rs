@@ -0,0 +1,428 @@
+use super::Generation;
+
+/// Generation 1 item names, starting at 0x01.
+const GEN1_ITEMS: [Option<&str>; 83] = [
+    Some("MASTER BALL"), // 0x01
+    Some("ULTRA BALL"), // 0x02
+    Some("GREAT BALL"), // 0x03
+    Some("POKé BALL"), // 0x04
+    Some("TOWN MAP"), // 0x05
+    Some("BICYCLE"), // 0x06
+    Some("?????"), // 0x07
+    Some("SAFARI BALL"), // 0x08
+    Some("POKéDEX"), // 0x09
+    Some("MOON STONE"), // 0x0A
+    Some("ANTIDOTE"), // 0x0B
+    Some("BURN HEAL"), // 0x0C
+    Some("ICE HEAL"), // 0x0D
+    Some("AWAKENING"), // 0x0E
+    Some("PARLYZ HEAL"), // 0x0F
+    Some("FULL RESTORE"), // 0x10
+    Some("MAX POTION"), // 0x11
+    Some("HYPER POTION"), // 0x12
+    Some("SUPER POTION"), // 0x13
+    Some("POTION"), // 0x14
+    Some("BOULDERBADGE"), // 0x15
+    Some("CASCADEBADGE"), // 0x16
+    Some("THUNDERBADGE"), // 0x17
+    Some("RAINBOWBADGE"), // 0x18
+    Some("SOULBADGE"), // 0x19
+    Some("MARSHBADGE"), // 0x1A
+    Some("VOLCANOBADGE"), // 0x1B
+    Some("EARTHBADGE"), // 0x1C
+    Some("ESCAPE ROPE"), // 0x1D
+    Some("REPEL"), // 0x1E
+    Some("OLD AMBER"), // 0x1F
+    Some("FIRE STONE"), // 0x20
+    Some("THUNDERSTONE"), // 0x21
+    Some("WATER STONE"), // 0x22
+    Some("HP UP"), // 0x23
+    Some("PROTEIN"), // 0x24
+    Some("IRON"), // 0x25
+    Some("CARBOS"), // 0x26
+    Some("CALCIUM"), // 0x27
+    Some("RARE CANDY"), // 0x28
+    Some("DOME FOSSIL"), // 0x29
+    Some("HELIX FOSSIL"), // 0x2A
+    Some("SECRET KEY"), // 0x2B
+    Some("?????"), // 0x2C
+    Some("BIKE VOUCHER"), // 0x2D
+    Some("X ACCURACY"), // 0x2E
+    Some("LEAF STONE"), // 0x2F
+    Some("CARD KEY"), // 0x30
+    Some("NUGGET"), // 0x31
+    Some("PP UP"), // 0x32
+    Some("POKé DOLL"), // 0x33
+    Some("FULL HEAL"), // 0x34
+    Some("REVIVE"), // 0x35
+    Some("MAX REVIVE"), // 0x36
+    Some("GUARD SPEC."), // 0x37
+    Some("SUPER REPEL"), // 0x38
+    Some("MAX REPEL"), // 0x39
+    Some("DIRE HIT"), // 0x3A
+    Some("COIN"), // 0x3B
+    Some("FRESH WATER"), // 0x3C
+    Some("SODA POP"), // 0x3D
+    Some("LEMONADE"), // 0x3E
+    Some("S.S.TICKET"), // 0x3F
+    Some("GOLD TEETH"), // 0x40
+    Some("X ATTACK"), // 0x41
+    Some("X DEFEND"), // 0x42
+    Some("X SPEED"), // 0x43
+    Some("X SPECIAL"), // 0x44
+    Some("COIN CASE"), // 0x45
+    Some("OAK's PARCEL"), // 0x46
+    Some("ITEMFINDER"), // 0x47
+    Some("SILPH SCOPE"), // 0x48
+    Some("POKé FLUTE"), // 0x49
+    Some("LIFT KEY"), // 0x4A
+    Some("EXP.ALL"), // 0x4B
+    Some("OLD ROD"), // 0x4C
+    Some("GOOD ROD"), // 0x4D
+    Some("SUPER ROD"), // 0x4E
+    Some("PP UP"), // 0x4F
+    Some("ETHER"), // 0x50
+    Some("MAX ETHER"), // 0x51
+    Some("ELIXER"), // 0x52
+    Some("MAX ELIXER"), // 0x53
+];
+
+/// Generation 1 HMs and TMs, starting at 0xC4.
+const GEN1_MACHINES: [Option<&str>; 55] = [
+    Some("HM01"), // 0xC4
+    Some("HM02"), // 0xC5
+    Some("HM03"), // 0xC6
+    Some("HM04"), // 0xC7
+    Some("HM05"), // 0xC8
+    Some("TM01"), // 0xC9
+    Some("TM02"), // 0xCA
+    Some("TM03"), // 0xCB
+    Some("TM04"), // 0xCC
+    Some("TM05"), // 0xCD
+    Some("TM06"), // 0xCE
+    Some("TM07"), // 0xCF
+    Some("TM08"), // 0xD0
+    Some("TM09"), // 0xD1
+    Some("TM10"), // 0xD2
+    Some("TM11"), // 0xD3
+    Some("TM12"), // 0xD4
+    Some("TM13"), // 0xD5
+    Some("TM14"), // 0xD6
+    Some("TM15"), // 0xD7
+    Some("TM16"), // 0xD8
+    Some("TM17"), // 0xD9
+    Some("TM18"), // 0xDA
+    Some("TM19"), // 0xDB
+    Some("TM20"), // 0xDC
+    Some("TM21"), // 0xDD
+    Some("TM22"), // 0xDE
+    Some("TM23"), // 0xDF
+    Some("TM24"), // 0xE0
+    Some("TM25"), // 0xE1
+    Some("TM26"), // 0xE2
+    Some("TM27"), // 0xE3
+    Some("TM28"), // 0xE4
+    Some("TM29"), // 0xE5
+    Some("TM30"), // 0xE6
+    Some("TM31"), // 0xE7
+    Some("TM32"), // 0xE8
+    Some("TM33"), // 0xE9
+    Some("TM34"), // 0xEA
+    Some("TM35"), // 0xEB
+    Some("TM36"), // 0xEC
+    Some("TM37"), // 0xED
+    Some("TM38"), // 0xEE
+    Some("TM39"), // 0xEF
+    Some("TM40"), // 0xF0
+    Some("TM41"), // 0xF1
+    Some("TM42"), // 0xF2
+    Some("TM43"), // 0xF3
+    Some("TM44"), // 0xF4
+    Some("TM45"), // 0xF5
+    Some("TM46"), // 0xF6
+    Some("TM47"), // 0xF7
+    Some("TM48"), // 0xF8
+    Some("TM49"), // 0xF9
+    Some("TM50"), // 0xFA
+];
+
+/// Generation 2 item names, starting at 0x01; TMs and HMs start at 0xBF.
+const GEN2_ITEMS: [Option<&str>; 249] = [
+    Some("MASTER BALL"), // 0x01
+    Some("ULTRA BALL"), // 0x02
+    Some("BRIGHTPOWDER"), // 0x03
+    Some("GREAT BALL"), // 0x04
+    Some("POKé BALL"), // 0x05
+    Some("TERU-SAMA"), // 0x06
+    Some("BICYCLE"), // 0x07
+    Some("MOON STONE"), // 0x08
+    Some("ANTIDOTE"), // 0x09
+    Some("BURN HEAL"), // 0x0A
+    Some("ICE HEAL"), // 0x0B
+    Some("AWAKENING"), // 0x0C
+    Some("PARLYZ HEAL"), // 0x0D
+    Some("FULL RESTORE"), // 0x0E
+    Some("MAX POTION"), // 0x0F
+    Some("HYPER POTION"), // 0x10
+    Some("SUPER POTION"), // 0x11
+    Some("POTION"), // 0x12
+    Some("ESCAPE ROPE"), // 0x13
+    Some("REPEL"), // 0x14
+    Some("MAX ELIXER"), // 0x15
+    Some("FIRE STONE"), // 0x16
+    Some("THUNDERSTONE"), // 0x17
+    Some("WATER STONE"), // 0x18
+    Some("TERU-SAMA"), // 0x19
+    Some("HP UP"), // 0x1A
+    Some("PROTEIN"), // 0x1B
+    Some("IRON"), // 0x1C
+    Some("CARBOS"), // 0x1D
+    Some("LUCKY PUNCH"), // 0x1E
+    Some("CALCIUM"), // 0x1F
+    Some("RARE CANDY"), // 0x20
+    Some("X ACCURACY"), // 0x21
+    Some("LEAF STONE"), // 0x22
+    Some("METAL POWDER"), // 0x23
+    Some("NUGGET"), // 0x24
+    Some("POKé DOLL"), // 0x25
+    Some("FULL HEAL"), // 0x26
+    Some("REVIVE"), // 0x27
+    Some("MAX REVIVE"), // 0x28
+    Some("GUARD SPEC."), // 0x29
+    Some("SUPER REPEL"), // 0x2A
+    Some("MAX REPEL"), // 0x2B
+    Some("DIRE HIT"), // 0x2C
+    Some("TERU-SAMA"), // 0x2D
+    Some("FRESH WATER"), // 0x2E
+    Some("SODA POP"), // 0x2F
+    Some("LEMONADE"), // 0x30
+    Some("X ATTACK"), // 0x31
+    Some("TERU-SAMA"), // 0x32
+    Some("X DEFEND"), // 0x33
+    Some("X SPEED"), // 0x34
+    Some("X SPECIAL"), // 0x35
+    Some("COIN CASE"), // 0x36
+    Some("ITEMFINDER"), // 0x37
+    Some("TERU-SAMA"), // 0x38
+    Some("EXP.SHARE"), // 0x39
+    Some("OLD ROD"), // 0x3A
+    Some("GOOD ROD"), // 0x3B
+    Some("SILVER LEAF"), // 0x3C
+    Some("SUPER ROD"), // 0x3D
+    Some("PP UP"), // 0x3E
+    Some("ETHER"), // 0x3F
+    Some("MAX ETHER"), // 0x40
+    Some("ELIXER"), // 0x41
+    Some("RED SCALE"), // 0x42
+    Some("SECRETPOTION"), // 0x43
+    Some("S.S.TICKET"), // 0x44
+    Some("MYSTERY EGG"), // 0x45
+    Some("CLEAR BELL"), // 0x46
+    Some("SILVER WING"), // 0x47
+    Some("MOOMOO MILK"), // 0x48
+    Some("QUICK CLAW"), // 0x49
+    Some("PSNCUREBERRY"), // 0x4A
+    Some("GOLD LEAF"), // 0x4B
+    Some("SOFT SAND"), // 0x4C
+    Some("SHARP BEAK"), // 0x4D
+    Some("PRZCUREBERRY"), // 0x4E
+    Some("BURNT BERRY"), // 0x4F
+    Some("ICE BERRY"), // 0x50
+    Some("POISON BARB"), // 0x51
+    Some("KING'S ROCK"), // 0x52
+    Some("BITTER BERRY"), // 0x53
+    Some("MINT BERRY"), // 0x54
+    Some("RED APRICORN"), // 0x55
+    Some("TINYMUSHROOM"), // 0x56
+    Some("BIG MUSHROOM"), // 0x57
+    Some("SILVERPOWDER"), // 0x58
+    Some("BLU APRICORN"), // 0x59
+    Some("TERU-SAMA"), // 0x5A
+    Some("AMULET COIN"), // 0x5B
+    Some("YLW APRICORN"), // 0x5C
+    Some("GRN APRICORN"), // 0x5D
+    Some("CLEANSE TAG"), // 0x5E
+    Some("MYSTIC WATER"), // 0x5F
+    Some("TWISTEDSPOON"), // 0x60
+    Some("WHT APRICORN"), // 0x61
+    Some("BLACKBELT"), // 0x62
+    Some("BLK APRICORN"), // 0x63
+    Some("TERU-SAMA"), // 0x64
+    Some("PNK APRICORN"), // 0x65
+    Some("BLACKGLASSES"), // 0x66
+    Some("SLOWPOKETAIL"), // 0x67
+    Some("PINK BOW"), // 0x68
+    Some("STICK"), // 0x69
+    Some("SMOKE BALL"), // 0x6A
+    Some("NEVERMELTICE"), // 0x6B
+    Some("MAGNET"), // 0x6C
+    Some("MIRACLEBERRY"), // 0x6D
+    Some("PEARL"), // 0x6E
+    Some("BIG PEARL"), // 0x6F
+    Some("EVERSTONE"), // 0x70
+    Some("SPELL TAG"), // 0x71
+    Some("RAGECANDYBAR"), // 0x72
+    Some("GS BALL"), // 0x73
+    Some("BLUE CARD"), // 0x74
+    Some("MIRACLE SEED"), // 0x75
+    Some("THICK CLUB"), // 0x76
+    Some("FOCUS BAND"), // 0x77
+    Some("TERU-SAMA"), // 0x78
+    Some("ENERGYPOWDER"), // 0x79
+    Some("ENERGY ROOT"), // 0x7A
+    Some("HEAL POWDER"), // 0x7B
+    Some("REVIVAL HERB"), // 0x7C
+    Some("HARD STONE"), // 0x7D
+    Some("LUCKY EGG"), // 0x7E
+    Some("CARD KEY"), // 0x7F
+    Some("MACHINE PART"), // 0x80
+    Some("EGG TICKET"), // 0x81
+    Some("LOST ITEM"), // 0x82
+    Some("STARDUST"), // 0x83
+    Some("STAR PIECE"), // 0x84
+    Some("BASEMENT KEY"), // 0x85
+    Some("PASS"), // 0x86
+    Some("TERU-SAMA"), // 0x87
+    Some("TERU-SAMA"), // 0x88
+    Some("TERU-SAMA"), // 0x89
+    Some("CHARCOAL"), // 0x8A
+    Some("BERRY JUICE"), // 0x8B
+    Some("SCOPE LENS"), // 0x8C
+    Some("TERU-SAMA"), // 0x8D
+    Some("TERU-SAMA"), // 0x8E
+    Some("METAL COAT"), // 0x8F
+    Some("DRAGON FANG"), // 0x90
+    Some("TERU-SAMA"), // 0x91
+    Some("LEFTOVERS"), // 0x92
+    Some("TERU-SAMA"), // 0x93
+    Some("TERU-SAMA"), // 0x94
+    Some("TERU-SAMA"), // 0x95
+    Some("MYSTERYBERRY"), // 0x96
+    Some("DRAGON SCALE"), // 0x97
+    Some("BERSERK GENE"), // 0x98
+    Some("TERU-SAMA"), // 0x99
+    Some("TERU-SAMA"), // 0x9A
+    Some("TERU-SAMA"), // 0x9B
+    Some("SACRED ASH"), // 0x9C
+    Some("HEAVY BALL"), // 0x9D
+    Some("FLOWER MAIL"), // 0x9E
+    Some("LEVEL BALL"), // 0x9F
+    Some("LURE BALL"), // 0xA0
+    Some("FAST BALL"), // 0xA1
+    Some("TERU-SAMA"), // 0xA2
+    Some("LIGHT BALL"), // 0xA3
+    Some("FRIEND BALL"), // 0xA4
+    Some("MOON BALL"), // 0xA5
+    Some("LOVE BALL"), // 0xA6
+    Some("NORMAL BOX"), // 0xA7
+    Some("GORGEOUS BOX"), // 0xA8
+    Some("SUN STONE"), // 0xA9
+    Some("POLKADOT BOW"), // 0xAA
+    Some("TERU-SAMA"), // 0xAB
+    Some("UP-GRADE"), // 0xAC
+    Some("BERRY"), // 0xAD
+    Some("GOLD BERRY"), // 0xAE
+    Some("SQUIRTBOTTLE"), // 0xAF
+    Some("TERU-SAMA"), // 0xB0
+    Some("PARK BALL"), // 0xB1
+    Some("RAINBOW WING"), // 0xB2
+    Some("TERU-SAMA"), // 0xB3
+    Some("BRICK PIECE"), // 0xB4
+    Some("SURF MAIL"), // 0xB5
+    Some("LITEBLUEMAIL"), // 0xB6
+    Some("PORTRAITMAIL"), // 0xB7
+    Some("LOVELY MAIL"), // 0xB8
+    Some("EON MAIL"), // 0xB9
+    Some("MORPH MAIL"), // 0xBA
+    Some("BLUESKY MAIL"), // 0xBB
+    Some("MUSIC MAIL"), // 0xBC
+    Some("MIRAGE MAIL"), // 0xBD
+    Some("TERU-SAMA"), // 0xBE
+    Some("TM01"), // 0xBF
+    Some("TM02"), // 0xC0
+    Some("TM03"), // 0xC1
+    Some("TM04"), // 0xC2
+    None, // 0xC3
+    Some("TM05"), // 0xC4
+    Some("TM06"), // 0xC5
+    Some("TM07"), // 0xC6
+    Some("TM08"), // 0xC7
+    Some("TM09"), // 0xC8
+    Some("TM10"), // 0xC9
+    Some("TM11"), // 0xCA
+    Some("TM12"), // 0xCB
+    Some("TM13"), // 0xCC
+    Some("TM14"), // 0xCD
+    Some("TM15"), // 0xCE
+    Some("TM16"), // 0xCF
+    Some("TM17"), // 0xD0
+    Some("TM18"), // 0xD1
+    Some("TM19"), // 0xD2
+    Some("TM20"), // 0xD3
+    Some("TM21"), // 0xD4
+    Some("TM22"), // 0xD5
+    Some("TM23"), // 0xD6
+    Some("TM24"), // 0xD7
+    Some("TM25"), // 0xD8
+    Some("TM26"), // 0xD9
+    Some("TM27"), // 0xDA
+    Some("TM28"), // 0xDB
+    None, // 0xDC
+    Some("TM29"), // 0xDD
+    Some("TM30"), // 0xDE
+    Some("TM31"), // 0xDF
+    Some("TM32"), // 0xE0
+    Some("TM33"), // 0xE1
+    Some("TM34"), // 0xE2
+    Some("TM35"), // 0xE3
+    Some("TM36"), // 0xE4
+    Some("TM37"), // 0xE5
+    Some("TM38"), // 0xE6
+    Some("TM39"), // 0xE7
+    Some("TM40"), // 0xE8
+    Some("TM41"), // 0xE9
+    Some("TM42"), // 0xEA
+    Some("TM43"), // 0xEB
+    Some("TM44"), // 0xEC
+    Some("TM45"), // 0xED
+    Some("TM46"), // 0xEE
+    Some("TM47"), // 0xEF
+    Some("TM48"), // 0xF0
+    Some("TM49"), // 0xF1
+    Some("TM50"), // 0xF2
+    Some("HM01"), // 0xF3
+    Some("HM02"), // 0xF4
+    Some("HM03"), // 0xF5
+    Some("HM04"), // 0xF6
+    Some("HM05"), // 0xF7
+    Some("HM06"), // 0xF8
+    Some("HM07"), // 0xF9
+];
+
+/// Get the English name of the item with the given index.
+///
+/// Returns `None` if there is no item at that index.
+pub const fn item_name(generation: Generation, index: u8) -> Option<&'static str> {
+    let index = index as usize;
+    match generation {
+        Generation::One => {
+            if index >= 0x01 && index < 0x01 + GEN1_ITEMS.len() {
+                GEN1_ITEMS[index - 0x01]
+            }
+            else if index >= 0xC4 && index < 0xC4 + GEN1_MACHINES.len() {
+                GEN1_MACHINES[index - 0xC4]
+            }
+            else {
+                None
+            }
+        },
+        Generation::Two => {
+            if index >= 0x01 && index < 0x01 + GEN2_ITEMS.len() {
+                GEN2_ITEMS[index - 0x01]
+            }
+            else {
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,1332 @@
+//! Move names in each language the games were released in, for when the ROM's own move name table can't be read.
+
+use super::{Language, MoveType};
+
+/// French move names, starting at 0x01.
+const FRENCH: [&str; 251] = [
+    "ECRAS'FACE", // 0x01
+    "POING-KARATE", // 0x02
+    "TORGNOLES", // 0x03
+    "POING COMETE", // 0x04
+    "ULTIMAPOING", // 0x05
+    "JACKPOT", // 0x06
+    "POING DE FEU", // 0x07
+    "POING-GLACE", // 0x08
+    "POING-ECLAIR", // 0x09
+    "GRIFFE", // 0x0A
+    "FORCE POIGNE", // 0x0B
+    "GUILLOTINE", // 0x0C
+    "COUPE-VENT", // 0x0D
+    "DANSE-LAMES", // 0x0E
+    "COUPE", // 0x0F
+    "TORNADE", // 0x10
+    "CRU-AILE", // 0x11
+    "CYCLONE", // 0x12
+    "VOL", // 0x13
+    "ETREINTE", // 0x14
+    "SOUPLESSE", // 0x15
+    "FOUET LIANES", // 0x16
+    "ECRASEMENT", // 0x17
+    "DOUBLE PIED", // 0x18
+    "ULTIMAWASHI", // 0x19
+    "PIED SAUTE", // 0x1A
+    "MAWASHI GERI", // 0x1B
+    "JET DE SABLE", // 0x1C
+    "COUD'BOULE", // 0x1D
+    "KONCHOC", // 0x1E
+    "FURIE", // 0x1F
+    "EMPAL'KORNE", // 0x20
+    "CHARGE", // 0x21
+    "PLAQUAGE", // 0x22
+    "LIGOTAGE", // 0x23
+    "BELIER", // 0x24
+    "MANIA", // 0x25
+    "DAMOCLES", // 0x26
+    "MIMI-QUEUE", // 0x27
+    "DARD-VENIN", // 0x28
+    "DOUBLE-DARD", // 0x29
+    "DARD-NUEE", // 0x2A
+    "GROZ'YEUX", // 0x2B
+    "MORSURE", // 0x2C
+    "RUGISSEMENT", // 0x2D
+    "HURLEMENT", // 0x2E
+    "BERCEUSE", // 0x2F
+    "ULTRASON", // 0x30
+    "SONICBOOM", // 0x31
+    "ENTRAVE", // 0x32
+    "ACIDE", // 0x33
+    "FLAMMECHE", // 0x34
+    "LANCE-FLAMME", // 0x35
+    "BRUME", // 0x36
+    "PISTOLET A O", // 0x37
+    "HYDROCANON", // 0x38
+    "SURF", // 0x39
+    "LASER GLACE", // 0x3A
+    "BLIZZARD", // 0x3B
+    "RAFALE PSY", // 0x3C
+    "BULLES D'O", // 0x3D
+    "ONDE BOREALE", // 0x3E
+    "ULTRALASER", // 0x3F
+    "PICPIC", // 0x40
+    "BEC VRILLE", // 0x41
+    "SACRIFICE", // 0x42
+    "BALAYAGE", // 0x43
+    "RIPOSTE", // 0x44
+    "FRAPPE ATLAS", // 0x45
+    "FORCE", // 0x46
+    "VOL-VIE", // 0x47
+    "MEGA-SANGSUE", // 0x48
+    "VAMPIGRAINE", // 0x49
+    "CROISSANCE", // 0x4A
+    "TRANCH'HERBE", // 0x4B
+    "LANCE-SOLEIL", // 0x4C
+    "POUDRE TOXIK", // 0x4D
+    "PARA-SPORE", // 0x4E
+    "POUDRE DODO", // 0x4F
+    "DANSE-FLEUR", // 0x50
+    "SECRETION", // 0x51
+    "DRACO-RAGE", // 0x52
+    "DANSEFLAMME", // 0x53
+    "ECLAIR", // 0x54
+    "TONNERRE", // 0x55
+    "CAGE-ECLAIR", // 0x56
+    "FATAL-FOUDRE", // 0x57
+    "JET-PIERRES", // 0x58
+    "SEISME", // 0x59
+    "ABIME", // 0x5A
+    "TUNNEL", // 0x5B
+    "TOXIK", // 0x5C
+    "CHOC MENTAL", // 0x5D
+    "PSYKO", // 0x5E
+    "HYPNOSE", // 0x5F
+    "YOGA", // 0x60
+    "HATE", // 0x61
+    "VIVE-ATTAQUE", // 0x62
+    "FRENESIE", // 0x63
+    "TELEPORT", // 0x64
+    "TENEBRES", // 0x65
+    "COPIE", // 0x66
+    "GRINCEMENT", // 0x67
+    "REFLET", // 0x68
+    "SOIN", // 0x69
+    "ARMURE", // 0x6A
+    "LILLIPUT", // 0x6B
+    "BROUILLARD", // 0x6C
+    "ONDE FOLIE", // 0x6D
+    "REPLI", // 0x6E
+    "BOUL'ARMURE", // 0x6F
+    "BOUCLIER", // 0x70
+    "MUR LUMIERE", // 0x71
+    "BUEE NOIRE", // 0x72
+    "PROTECTION", // 0x73
+    "PUISSANCE", // 0x74
+    "PATIENCE", // 0x75
+    "METRONOME", // 0x76
+    "MIMIQUE", // 0x77
+    "DESTRUCTION", // 0x78
+    "BOMB'OEUF", // 0x79
+    "LECHOUILLE", // 0x7A
+    "PUREDPOIS", // 0x7B
+    "DETRITUS", // 0x7C
+    "MASSD'OS", // 0x7D
+    "DEFLAGRATION", // 0x7E
+    "CASCADE", // 0x7F
+    "CLAQUOIR", // 0x80
+    "METEORES", // 0x81
+    "COUD'KRANE", // 0x82
+    "PICANON", // 0x83
+    "CONSTRICTION", // 0x84
+    "AMNESIE", // 0x85
+    "TELEKINESIE", // 0x86
+    "E-COQUE", // 0x87
+    "PIED VOLTIGE", // 0x88
+    "INTIMIDATION", // 0x89
+    "DEVOREVE", // 0x8A
+    "GAZ TOXIK", // 0x8B
+    "PILONNAGE", // 0x8C
+    "VAMPIRISME", // 0x8D
+    "GROBISOU", // 0x8E
+    "PIQUE", // 0x8F
+    "MORPHING", // 0x90
+    "ECUME", // 0x91
+    "UPPERCUT", // 0x92
+    "SPORE", // 0x93
+    "FLASH", // 0x94
+    "VAGUE PSY", // 0x95
+    "TREMPETTE", // 0x96
+    "ACIDARMURE", // 0x97
+    "PINCE-MASSE", // 0x98
+    "EXPLOSION", // 0x99
+    "COMBO-GRIFFE", // 0x9A
+    "OSMERANG", // 0x9B
+    "REPOS", // 0x9C
+    "EBOULEMENT", // 0x9D
+    "CROC DE MORT", // 0x9E
+    "AFFUTAGE", // 0x9F
+    "CONVERSION", // 0xA0
+    "TRIPLATTAQUE", // 0xA1
+    "CROC FATAL", // 0xA2
+    "TRANCHE", // 0xA3
+    "CLONAGE", // 0xA4
+    "LUTTE", // 0xA5
+    "GRIBOUILLE", // 0xA6
+    "TRIPLE PIED", // 0xA7
+    "LARCIN", // 0xA8
+    "TOILE", // 0xA9
+    "LIRE-ESPRIT", // 0xAA
+    "CAUCHEMAR", // 0xAB
+    "ROUE DE FEU", // 0xAC
+    "RONFLEMENT", // 0xAD
+    "MALEDICTION", // 0xAE
+    "FLEAU", // 0xAF
+    "CONVERSION2", // 0xB0
+    "AEROBLAST", // 0xB1
+    "SPORE COTON", // 0xB2
+    "CONTRE", // 0xB3
+    "DEPIT", // 0xB4
+    "POUDREUSE", // 0xB5
+    "ABRI", // 0xB6
+    "MACH PUNCH", // 0xB7
+    "GRIMACE", // 0xB8
+    "FEINTE", // 0xB9
+    "DOUX BAISER", // 0xBA
+    "COGNOBIDON", // 0xBB
+    "BOMB-BEURK", // 0xBC
+    "COUD'BOUE", // 0xBD
+    "OCTAZOOKA", // 0xBE
+    "PICOTS", // 0xBF
+    "ELECANON", // 0xC0
+    "CLAIRVOYANCE", // 0xC1
+    "PRLVT DESTIN", // 0xC2
+    "REQUIEM", // 0xC3
+    "VENT GLACE", // 0xC4
+    "DETECTION", // 0xC5
+    "CHARGE-OS", // 0xC6
+    "VERROUILLAGE", // 0xC7
+    "COLERE", // 0xC8
+    "TEMPETESABLE", // 0xC9
+    "GIGA-SANGSUE", // 0xCA
+    "TENACITE", // 0xCB
+    "CHARME", // 0xCC
+    "ROULADE", // 0xCD
+    "FAUX-CHAGE", // 0xCE
+    "VANTARDISE", // 0xCF
+    "LAIT A BOIRE", // 0xD0
+    "ETINCELLE", // 0xD1
+    "TAILLADE", // 0xD2
+    "AILE D'ACIER", // 0xD3
+    "REGARD NOIR", // 0xD4
+    "ATTRACTION", // 0xD5
+    "BLABLA DODO", // 0xD6
+    "GLAS DE SOIN", // 0xD7
+    "RETOUR", // 0xD8
+    "CADEAU", // 0xD9
+    "FRUSTRATION", // 0xDA
+    "RUNE PROTECT", // 0xDB
+    "BALANCE", // 0xDC
+    "FEU SACRE", // 0xDD
+    "AMPLEUR", // 0xDE
+    "DYNAMOPOING", // 0xDF
+    "MEGACORNE", // 0xE0
+    "DRACOSOUFFLE", // 0xE1
+    "RELAIS", // 0xE2
+    "ENCORE", // 0xE3
+    "POURSUITE", // 0xE4
+    "TOUR RAPIDE", // 0xE5
+    "DOUX PARFUM", // 0xE6
+    "QUEUE DE FER", // 0xE7
+    "GRIFFE ACIER", // 0xE8
+    "CORPS PERDU", // 0xE9
+    "AURORE", // 0xEA
+    "SYNTHESE", // 0xEB
+    "RAYON LUNE", // 0xEC
+    "PUIS.CACHEE", // 0xED
+    "COUP-CROIX", // 0xEE
+    "OURAGAN", // 0xEF
+    "DANSE PLUIE", // 0xF0
+    "ZENITH", // 0xF1
+    "MACHOUILLE", // 0xF2
+    "VOILE MIROIR", // 0xF3
+    "BOOST", // 0xF4
+    "VIT.EXTREME", // 0xF5
+    "POUV.ANTIQUE", // 0xF6
+    "BALL'OMBRE", // 0xF7
+    "PRESCIENCE", // 0xF8
+    "ECLATE-ROC", // 0xF9
+    "SIPHON", // 0xFA
+    "BASTON", // 0xFB
+];
+
+/// German move names, starting at 0x01.
+const GERMAN: [&str; 251] = [
+    "PFUND", // 0x01
+    "KARATESCHLAG", // 0x02
+    "DUPLEXHIEB", // 0x03
+    "KOMETENHIEB", // 0x04
+    "MEGAHIEB", // 0x05
+    "ZAHLTAG", // 0x06
+    "FEUERSCHLAG", // 0x07
+    "EISHIEB", // 0x08
+    "DONNERSCHLAG", // 0x09
+    "KRATZER", // 0x0A
+    "KLAMMER", // 0x0B
+    "GUILLOTINE", // 0x0C
+    "KLINGENSTURM", // 0x0D
+    "SCHWERTTANZ", // 0x0E
+    "ZERSCHNEIDER", // 0x0F
+    "WINDSTOSS", // 0x10
+    "FLÜGELSCHLAG", // 0x11
+    "WIRBELWIND", // 0x12
+    "FLIEGEN", // 0x13
+    "KLAMMERGRIFF", // 0x14
+    "SLAM", // 0x15
+    "RANKENHIEB", // 0x16
+    "STAMPFER", // 0x17
+    "DOPPELKICK", // 0x18
+    "MEGAKICK", // 0x19
+    "SPRUNGKICK", // 0x1A
+    "FEGEKICK", // 0x1B
+    "SANDWIRBEL", // 0x1C
+    "KOPFNUSS", // 0x1D
+    "HORNATTACKE", // 0x1E
+    "FURIENSCHLAG", // 0x1F
+    "HORNBOHRER", // 0x20
+    "TACKLE", // 0x21
+    "BODYSLAM", // 0x22
+    "WICKEL", // 0x23
+    "BODYCHECK", // 0x24
+    "FUCHTLER", // 0x25
+    "RISIKOTACKLE", // 0x26
+    "RUTENSCHLAG", // 0x27
+    "GIFTSTACHEL", // 0x28
+    "DUONADEL", // 0x29
+    "NADELRAKETE", // 0x2A
+    "SILBERBLICK", // 0x2B
+    "BISS", // 0x2C
+    "HEULER", // 0x2D
+    "BRÜLLER", // 0x2E
+    "GESANG", // 0x2F
+    "SUPERSCHALL", // 0x30
+    "ULTRASCHALL", // 0x31
+    "AUSSETZER", // 0x32
+    "SÄURE", // 0x33
+    "GLUT", // 0x34
+    "FLAMMENWURF", // 0x35
+    "WEISSNEBEL", // 0x36
+    "AQUAKNARRE", // 0x37
+    "HYDROPUMPE", // 0x38
+    "SURFER", // 0x39
+    "EISSTRAHL", // 0x3A
+    "BLIZZARD", // 0x3B
+    "PSYSTRAHL", // 0x3C
+    "BLUBBSTRAHL", // 0x3D
+    "AURORASTRAHL", // 0x3E
+    "HYPERSTRAHL", // 0x3F
+    "SCHNABEL", // 0x40
+    "BOHRSCHNABEL", // 0x41
+    "ÜBERROLLER", // 0x42
+    "FUSSKICK", // 0x43
+    "KONTER", // 0x44
+    "GEOWURF", // 0x45
+    "STÄRKE", // 0x46
+    "ABSORBER", // 0x47
+    "MEGASAUGER", // 0x48
+    "EGELSAMEN", // 0x49
+    "WACHSTUM", // 0x4A
+    "RASIERBLATT", // 0x4B
+    "SOLARSTRAHL", // 0x4C
+    "GIFTPUDER", // 0x4D
+    "STACHELSPORE", // 0x4E
+    "SCHLAFPUDER", // 0x4F
+    "BLÄTTERTANZ", // 0x50
+    "FADENSCHUSS", // 0x51
+    "DRACHENWUT", // 0x52
+    "FEUERWIRBEL", // 0x53
+    "DONNERSCHOCK", // 0x54
+    "DONNERBLITZ", // 0x55
+    "DONNERWELLE", // 0x56
+    "DONNER", // 0x57
+    "STEINWURF", // 0x58
+    "ERDBEBEN", // 0x59
+    "GEOFISSUR", // 0x5A
+    "SCHAUFLER", // 0x5B
+    "TOXIN", // 0x5C
+    "KONFUSION", // 0x5D
+    "PSYCHOKINESE", // 0x5E
+    "HYPNOSE", // 0x5F
+    "MEDITATION", // 0x60
+    "AGILITÄT", // 0x61
+    "RUCKZUCKHIEB", // 0x62
+    "RASEREI", // 0x63
+    "TELEPORT", // 0x64
+    "NACHTNEBEL", // 0x65
+    "MIMIKRY", // 0x66
+    "KREIDESCHREI", // 0x67
+    "DOPPELTEAM", // 0x68
+    "GENESUNG", // 0x69
+    "HÄRTNER", // 0x6A
+    "KOMPRIMATOR", // 0x6B
+    "RAUCHWOLKE", // 0x6C
+    "KONFUSTRAHL", // 0x6D
+    "PANZERSCHUTZ", // 0x6E
+    "EINIGLER", // 0x6F
+    "BARRIERE", // 0x70
+    "LICHTSCHILD", // 0x71
+    "DUNKELNEBEL", // 0x72
+    "REFLEKTOR", // 0x73
+    "ENERGIEFOKUS", // 0x74
+    "GEDULD", // 0x75
+    "METRONOM", // 0x76
+    "SPIEGELTRICK", // 0x77
+    "FINALE", // 0x78
+    "EIERBOMBE", // 0x79
+    "SCHLECKER", // 0x7A
+    "SMOG", // 0x7B
+    "SCHLAMMBAD", // 0x7C
+    "KNOCHENKEULE", // 0x7D
+    "FEUERSTURM", // 0x7E
+    "KASKADE", // 0x7F
+    "SCHNAPPER", // 0x80
+    "STERNSCHAUER", // 0x81
+    "SCHÄDELWUMME", // 0x82
+    "DORNKANONE", // 0x83
+    "UMKLAMMERUNG", // 0x84
+    "AMNESIE", // 0x85
+    "PSYKRAFT", // 0x86
+    "WEICHEI", // 0x87
+    "TURMKICK", // 0x88
+    "GIFTBLICK", // 0x89
+    "TRAUMFRESSER", // 0x8A
+    "GIFTWOLKE", // 0x8B
+    "STAKKATO", // 0x8C
+    "BLUTSAUGER", // 0x8D
+    "TODESKUSS", // 0x8E
+    "HIMMELSFEGER", // 0x8F
+    "WANDLER", // 0x90
+    "BLUBBER", // 0x91
+    "IRRSCHLAG", // 0x92
+    "PILZSPORE", // 0x93
+    "BLITZ", // 0x94
+    "PSYWELLE", // 0x95
+    "PLATSCHER", // 0x96
+    "SÄUREPANZER", // 0x97
+    "KRABBHAMMER", // 0x98
+    "EXPLOSION", // 0x99
+    "KRATZFURIE", // 0x9A
+    "KNOCHMERANG", // 0x9B
+    "ERHOLUNG", // 0x9C
+    "STEINHAGEL", // 0x9D
+    "HYPERZAHN", // 0x9E
+    "SCHÄRFER", // 0x9F
+    "UMWANDLUNG", // 0xA0
+    "TRIPLETTE", // 0xA1
+    "SUPERZAHN", // 0xA2
+    "SCHLITZER", // 0xA3
+    "DELEGATOR", // 0xA4
+    "VERZWEIFLER", // 0xA5
+    "NACHAHMER", // 0xA6
+    "DREIFACHKICK", // 0xA7
+    "RAUB", // 0xA8
+    "SPINNENNETZ", // 0xA9
+    "WILLENSLESER", // 0xAA
+    "NACHTMAHR", // 0xAB
+    "FLAMMENRAD", // 0xAC
+    "SCHNARCHER", // 0xAD
+    "FLUCH", // 0xAE
+    "DRESCHFLEGEL", // 0xAF
+    "UMWANDLUNG2", // 0xB0
+    "LUFTSTOSS", // 0xB1
+    "BAUMWOLLSAAT", // 0xB2
+    "GEGENSCHLAG", // 0xB3
+    "GROLL", // 0xB4
+    "PULVERSCHNEE", // 0xB5
+    "SCHUTZSCHILD", // 0xB6
+    "TEMPOHIEB", // 0xB7
+    "GRIMASSE", // 0xB8
+    "FINTE", // 0xB9
+    "BITTERKUSS", // 0xBA
+    "BAUCHTROMMEL", // 0xBB
+    "MATSCHBOMBE", // 0xBC
+    "LEHMSCHELLE", // 0xBD
+    "OCTAZOOKA", // 0xBE
+    "STACHLER", // 0xBF
+    "BLITZKANONE", // 0xC0
+    "GESICHTE", // 0xC1
+    "ABGANGSBUND", // 0xC2
+    "ABGESANG", // 0xC3
+    "EISESODEM", // 0xC4
+    "SCANNER", // 0xC5
+    "KNOCHENHATZ", // 0xC6
+    "ZIELSCHUSS", // 0xC7
+    "WUTANFALL", // 0xC8
+    "SANDSTURM", // 0xC9
+    "GIGASAUGER", // 0xCA
+    "AUSDAUER", // 0xCB
+    "CHARME", // 0xCC
+    "WALZER", // 0xCD
+    "TRUGSCHLAG", // 0xCE
+    "ANGEBEREI", // 0xCF
+    "MILCHGETRÄNK", // 0xD0
+    "FUNKENSPRUNG", // 0xD1
+    "ZORNKLINGE", // 0xD2
+    "STAHLFLÜGEL", // 0xD3
+    "HORRORBLICK", // 0xD4
+    "ANZIEHUNG", // 0xD5
+    "SCHLAFREDE", // 0xD6
+    "VITALGLOCKE", // 0xD7
+    "RÜCKKEHR", // 0xD8
+    "GESCHENK", // 0xD9
+    "FRUSTRATION", // 0xDA
+    "BODYGUARD", // 0xDB
+    "LEIDTEILER", // 0xDC
+    "LÄUTERFEUER", // 0xDD
+    "INTENSITÄT", // 0xDE
+    "WUCHTSCHLAG", // 0xDF
+    "VIELSCHLAG", // 0xE0
+    "FEUERODEM", // 0xE1
+    "STAFFETTE", // 0xE2
+    "ZUGABE", // 0xE3
+    "VERFOLGUNG", // 0xE4
+    "TURBODREHER", // 0xE5
+    "LOCKDUFT", // 0xE6
+    "EISENSCHWEIF", // 0xE7
+    "METALLKLAUE", // 0xE8
+    "ÜBERWURF", // 0xE9
+    "MORGENGRAUEN", // 0xEA
+    "SYNTHESE", // 0xEB
+    "MONDSCHEIN", // 0xEC
+    "KRAFTRESERVE", // 0xED
+    "KREUZHIEB", // 0xEE
+    "WINDHOSE", // 0xEF
+    "REGENTANZ", // 0xF0
+    "SONNENTAG", // 0xF1
+    "KNIRSCHER", // 0xF2
+    "SPIEGELCAPE", // 0xF3
+    "PSYCHO-PLUS", // 0xF4
+    "TURBOTEMPO", // 0xF5
+    "ANTIK-KRAFT", // 0xF6
+    "SPUKBALL", // 0xF7
+    "SEHER", // 0xF8
+    "ZERTRÜMMERER", // 0xF9
+    "WHIRLPOOL", // 0xFA
+    "PRÜGLER", // 0xFB
+];
+
+/// Spanish move names, starting at 0x01.
+const SPANISH: [&str; 251] = [
+    "DESTRUCTOR", // 0x01
+    "GOLPE KARATE", // 0x02
+    "DOBLEBOFETON", // 0x03
+    "PUÑO COMETA", // 0x04
+    "MEGAPUÑO", // 0x05
+    "DIA DE PAGO", // 0x06
+    "PUÑO FUEGO", // 0x07
+    "PUÑO HIELO", // 0x08
+    "PUÑO TRUENO", // 0x09
+    "ARAÑAZO", // 0x0A
+    "AGARRE", // 0x0B
+    "GUILLOTINA", // 0x0C
+    "VIENTO CORTE", // 0x0D
+    "DANZA ESPADA", // 0x0E
+    "CORTE", // 0x0F
+    "TORNADO", // 0x10
+    "ATAQUE ALA", // 0x11
+    "REMOLINO", // 0x12
+    "VUELO", // 0x13
+    "ATADURA", // 0x14
+    "ATIZAR", // 0x15
+    "LATIGO CEPA", // 0x16
+    "PISOTON", // 0x17
+    "DOBLE PATADA", // 0x18
+    "MEGAPATADA", // 0x19
+    "PATADA SALTO", // 0x1A
+    "PATADA GIRO", // 0x1B
+    "ATAQUE ARENA", // 0x1C
+    "GOLPE CABEZA", // 0x1D
+    "CORNADA", // 0x1E
+    "ATAQUE FURIA", // 0x1F
+    "PERFORADOR", // 0x20
+    "PLACAJE", // 0x21
+    "GOLPE CUERPO", // 0x22
+    "REPETICION", // 0x23
+    "DERRIBO", // 0x24
+    "GOLPE", // 0x25
+    "DOBLE FILO", // 0x26
+    "LATIGO", // 0x27
+    "PICOTAZO VEN", // 0x28
+    "DOBLE ATAQUE", // 0x29
+    "PIN MISIL", // 0x2A
+    "MALICIOSO", // 0x2B
+    "MORDISCO", // 0x2C
+    "GRUÑIDO", // 0x2D
+    "RUGIDO", // 0x2E
+    "CANTO", // 0x2F
+    "SUPERSONICO", // 0x30
+    "BOMBASONICA", // 0x31
+    "ANULACION", // 0x32
+    "ACIDO", // 0x33
+    "ASCUAS", // 0x34
+    "LANZALLAMAS", // 0x35
+    "NEBLINA", // 0x36
+    "PISTOLA AGUA", // 0x37
+    "HIDROBOMBA", // 0x38
+    "SURF", // 0x39
+    "RAYO HIELO", // 0x3A
+    "VENTISCA", // 0x3B
+    "PSICORRAYO", // 0x3C
+    "RAYO BURBUJA", // 0x3D
+    "RAYO AURORA", // 0x3E
+    "HIPERRAYO", // 0x3F
+    "PICOTAZO", // 0x40
+    "PICO TALADRO", // 0x41
+    "SUMISION", // 0x42
+    "PATADA BAJA", // 0x43
+    "CONTRAATAQUE", // 0x44
+    "MOV.SISMICO", // 0x45
+    "FUERZA", // 0x46
+    "ABSORBER", // 0x47
+    "MEGAAGOTAR", // 0x48
+    "DRENADORAS", // 0x49
+    "DESARROLLO", // 0x4A
+    "HOJA AFILADA", // 0x4B
+    "RAYO SOLAR", // 0x4C
+    "POLVO VENENO", // 0x4D
+    "PARALIZADOR", // 0x4E
+    "SOMNIFERO", // 0x4F
+    "DANZA PETALO", // 0x50
+    "DISP.DEMORA", // 0x51
+    "FURIA DRAGON", // 0x52
+    "GIRO FUEGO", // 0x53
+    "IMPACTRUENO", // 0x54
+    "RAYO", // 0x55
+    "ONDA TRUENO", // 0x56
+    "TRUENO", // 0x57
+    "LANZARROCAS", // 0x58
+    "TERREMOTO", // 0x59
+    "FISURA", // 0x5A
+    "EXCAVAR", // 0x5B
+    "TOXICO", // 0x5C
+    "CONFUSION", // 0x5D
+    "PSIQUICO", // 0x5E
+    "HIPNOSIS", // 0x5F
+    "MEDITACION", // 0x60
+    "AGILIDAD", // 0x61
+    "ATAQ.RAPIDO", // 0x62
+    "FURIA", // 0x63
+    "TELETRANSP.", // 0x64
+    "TINIEBLA", // 0x65
+    "MIMETISMO", // 0x66
+    "CHIRRIDO", // 0x67
+    "DOBLE EQUIPO", // 0x68
+    "RECUPERACION", // 0x69
+    "FORTALEZA", // 0x6A
+    "REDUCCION", // 0x6B
+    "PANTALLAHUMO", // 0x6C
+    "RAYO CONFUSO", // 0x6D
+    "REFUGIO", // 0x6E
+    "RIZO DEFENSA", // 0x6F
+    "BARRERA", // 0x70
+    "PANTALLA LUZ", // 0x71
+    "NIEBLA", // 0x72
+    "REFLEJO", // 0x73
+    "FOCO ENERGIA", // 0x74
+    "VENGANZA", // 0x75
+    "METRONOMO", // 0x76
+    "MOV.ESPEJO", // 0x77
+    "AUTODESTRUC.", // 0x78
+    "BOMBA HUEVO", // 0x79
+    "LENGÜETAZO", // 0x7A
+    "POLUCION", // 0x7B
+    "RESIDUOS", // 0x7C
+    "HUESO PALO", // 0x7D
+    "LLAMARADA", // 0x7E
+    "CASCADA", // 0x7F
+    "TENAZA", // 0x80
+    "METEOROS", // 0x81
+    "CABEZAZO", // 0x82
+    "CLAVO CAÑON", // 0x83
+    "RESTRICCION", // 0x84
+    "AMNESIA", // 0x85
+    "KINETICO", // 0x86
+    "AMORTIGUADOR", // 0x87
+    "PAT.S.ALTA", // 0x88
+    "DESLUMBRAR", // 0x89
+    "COME SUEÑOS", // 0x8A
+    "GAS VENENOSO", // 0x8B
+    "BOMBARDEO", // 0x8C
+    "CHUPAVIDAS", // 0x8D
+    "BESO AMOROSO", // 0x8E
+    "ATAQUE AEREO", // 0x8F
+    "TRANSFORM.", // 0x90
+    "BURBUJA", // 0x91
+    "PUÑO MAREO", // 0x92
+    "ESPORA", // 0x93
+    "DESTELLO", // 0x94
+    "PSICOONDA", // 0x95
+    "SALPICADURA", // 0x96
+    "ARMAD.ACIDA", // 0x97
+    "MARTILLAZO", // 0x98
+    "EXPLOSION", // 0x99
+    "GOLPES FURIA", // 0x9A
+    "HUESOMERANG", // 0x9B
+    "DESCANSO", // 0x9C
+    "AVALANCHA", // 0x9D
+    "HIPERCOLMILL", // 0x9E
+    "AFILAR", // 0x9F
+    "CONVERSION", // 0xA0
+    "TRIATAQUE", // 0xA1
+    "SUPERDIENTE", // 0xA2
+    "CUCHILLADA", // 0xA3
+    "SUSTITUTO", // 0xA4
+    "FORCEJEO", // 0xA5
+    "ESQUEMA", // 0xA6
+    "TRIPLEPATADA", // 0xA7
+    "LADRON", // 0xA8
+    "TELARAÑA", // 0xA9
+    "TELEPATIA", // 0xAA
+    "PESADILLA", // 0xAB
+    "RUEDA FUEGO", // 0xAC
+    "RONQUIDO", // 0xAD
+    "MALDICION", // 0xAE
+    "AZOTE", // 0xAF
+    "CONVERSION2", // 0xB0
+    "AEROCHORRO", // 0xB1
+    "ESPORAGODON", // 0xB2
+    "INVERSION", // 0xB3
+    "RENCOR", // 0xB4
+    "NIEVE POLVO", // 0xB5
+    "PROTECCION", // 0xB6
+    "ULTRAPUÑO", // 0xB7
+    "CARA SUSTO", // 0xB8
+    "FINTA", // 0xB9
+    "BESO DULCE", // 0xBA
+    "TAMBOR", // 0xBB
+    "BOMBA LODO", // 0xBC
+    "BOFETON LODO", // 0xBD
+    "PULPOCAÑON", // 0xBE
+    "PUAS", // 0xBF
+    "ELECTROCAÑON", // 0xC0
+    "PROFECIA", // 0xC1
+    "MISMODESTINO", // 0xC2
+    "CANTO MORTAL", // 0xC3
+    "VIENTO HIELO", // 0xC4
+    "DETECCION", // 0xC5
+    "ATAQUE OSEO", // 0xC6
+    "FIJAR BLANCO", // 0xC7
+    "ENFADO", // 0xC8
+    "TORM.ARENA", // 0xC9
+    "GIGADRENADO", // 0xCA
+    "AGUANTE", // 0xCB
+    "ENCANTO", // 0xCC
+    "DESENROLLAR", // 0xCD
+    "FALSOTORTAZO", // 0xCE
+    "CONTONEO", // 0xCF
+    "BATIDO", // 0xD0
+    "CHISPA", // 0xD1
+    "CORTE FURIA", // 0xD2
+    "ALA DE ACERO", // 0xD3
+    "MAL DE OJO", // 0xD4
+    "ATRACCION", // 0xD5
+    "SONAMBULO", // 0xD6
+    "CAMPANA CURA", // 0xD7
+    "RETRIBUCION", // 0xD8
+    "PRESENTE", // 0xD9
+    "FRUSTRACION", // 0xDA
+    "VELO SAGRADO", // 0xDB
+    "DIVIDE DOLOR", // 0xDC
+    "FUEGOSAGRADO", // 0xDD
+    "MAGNITUD", // 0xDE
+    "PUÑODINAMICO", // 0xDF
+    "MEGACUERNO", // 0xE0
+    "DRAGOALIENTO", // 0xE1
+    "RELEVO", // 0xE2
+    "OTRA VEZ", // 0xE3
+    "PERSECUCION", // 0xE4
+    "GIRO RAPIDO", // 0xE5
+    "DULCE AROMA", // 0xE6
+    "COLA FERREA", // 0xE7
+    "GARRA METAL", // 0xE8
+    "TIRO VITAL", // 0xE9
+    "SOL MATINAL", // 0xEA
+    "SINTESIS", // 0xEB
+    "LUZ LUNAR", // 0xEC
+    "PODER OCULTO", // 0xED
+    "TAJO CRUZADO", // 0xEE
+    "CICLON", // 0xEF
+    "DANZA LLUVIA", // 0xF0
+    "DIA SOLEADO", // 0xF1
+    "TRITURAR", // 0xF2
+    "MANTO ESPEJO", // 0xF3
+    "MAS PSIQUE", // 0xF4
+    "VEL.EXTREMA", // 0xF5
+    "PODER PASADO", // 0xF6
+    "BOLA SOMBRA", // 0xF7
+    "PREMONICION", // 0xF8
+    "GOLPE ROCA", // 0xF9
+    "TORBELLINO", // 0xFA
+    "PALIZA", // 0xFB
+];
+
+/// Italian move names, starting at 0x01.
+const ITALIAN: [&str; 251] = [
+    "BOTTA", // 0x01
+    "COLPO KARATE", // 0x02
+    "DOPPIASBERLA", // 0x03
+    "COMETAPUGNO", // 0x04
+    "MEGAPUGNO", // 0x05
+    "GIORNOPAGA", // 0x06
+    "FUOCOPUGNO", // 0x07
+    "GELOPUGNO", // 0x08
+    "TUONOPUGNO", // 0x09
+    "GRAFFIO", // 0x0A
+    "PRESA", // 0x0B
+    "GHIGLIOTTINA", // 0x0C
+    "VENTOTAGLIO", // 0x0D
+    "DANZASPADA", // 0x0E
+    "TAGLIO", // 0x0F
+    "RAFFICA", // 0x10
+    "ATTACCOD'ALA", // 0x11
+    "TURBINE", // 0x12
+    "VOLO", // 0x13
+    "LEGATUTTO", // 0x14
+    "SCHIANTO", // 0x15
+    "FRUSTATA", // 0x16
+    "PESTONE", // 0x17
+    "DOPPIOCALCIO", // 0x18
+    "MEGACALCIO", // 0x19
+    "CALCIOSALTO", // 0x1A
+    "CALCIORULLO", // 0x1B
+    "TURBOSABBIA", // 0x1C
+    "BOTTINTESTA", // 0x1D
+    "INCORNATA", // 0x1E
+    "FURIA", // 0x1F
+    "PERFORCORNO", // 0x20
+    "AZIONE", // 0x21
+    "CORPOSCONTRO", // 0x22
+    "AVVOLGIBOTTA", // 0x23
+    "RIDUTTORE", // 0x24
+    "COLPO", // 0x25
+    "SDOPPIATORE", // 0x26
+    "COLPOCODA", // 0x27
+    "VELENOSPINA", // 0x28
+    "DOPPIO AGO", // 0x29
+    "MISSILSPILLO", // 0x2A
+    "FULMISGUARDO", // 0x2B
+    "MORSO", // 0x2C
+    "RUGGITO", // 0x2D
+    "BOATO", // 0x2E
+    "CANTO", // 0x2F
+    "SUPERSUONO", // 0x30
+    "SONICBOOM", // 0x31
+    "INIBITORE", // 0x32
+    "ACIDO", // 0x33
+    "BRACE", // 0x34
+    "LANCIAFIAMME", // 0x35
+    "NEBBIA", // 0x36
+    "PISTOLACQUA", // 0x37
+    "IDROPOMPA", // 0x38
+    "SURF", // 0x39
+    "GELORAGGIO", // 0x3A
+    "BORA", // 0x3B
+    "PSICORAGGIO", // 0x3C
+    "BOLLARAGGIO", // 0x3D
+    "RAGGIAURORA", // 0x3E
+    "IPER RAGGIO", // 0x3F
+    "BECCATA", // 0x40
+    "PERFORBECCO", // 0x41
+    "SOTTOMISSION", // 0x42
+    "COLPO BASSO", // 0x43
+    "CONTATORE", // 0x44
+    "MOV.SISMICO", // 0x45
+    "FORZA", // 0x46
+    "ASSORBIMENTO", // 0x47
+    "MEGASSORBI", // 0x48
+    "PARASSISEME", // 0x49
+    "CRESCITA", // 0x4A
+    "FOGLIELAMA", // 0x4B
+    "SOLARRAGGIO", // 0x4C
+    "VELENPOLVERE", // 0x4D
+    "PARALIZZANTE", // 0x4E
+    "SONNIFERO", // 0x4F
+    "PETALODANZA", // 0x50
+    "MILLEBAVE", // 0x51
+    "IRA DI DRAGO", // 0x52
+    "TURBOFUOCO", // 0x53
+    "TUONOSHOCK", // 0x54
+    "FULMINE", // 0x55
+    "TUONONDA", // 0x56
+    "TUONO", // 0x57
+    "SASSATA", // 0x58
+    "TERREMOTO", // 0x59
+    "ABISSO", // 0x5A
+    "FOSSO", // 0x5B
+    "TOSSINA", // 0x5C
+    "CONFUSIONE", // 0x5D
+    "PSICHICO", // 0x5E
+    "IPNOSI", // 0x5F
+    "MEDITAZIONE", // 0x60
+    "AGILITÀ", // 0x61
+    "ATT.RAPIDO", // 0x62
+    "IRA", // 0x63
+    "TELETRASP.", // 0x64
+    "OMBRA NOTTE", // 0x65
+    "MIMICA", // 0x66
+    "STRIDIO", // 0x67
+    "DOPPIOTEAM", // 0x68
+    "RIPRESA", // 0x69
+    "RAFFORZATORE", // 0x6A
+    "MINIMIZZATO", // 0x6B
+    "MURO DI FUMO", // 0x6C
+    "STORDIRAGGIO", // 0x6D
+    "RITIRATA", // 0x6E
+    "RICCIOLSCUDO", // 0x6F
+    "BARRIERA", // 0x70
+    "SCHERMOLUCE", // 0x71
+    "NUBE", // 0x72
+    "RIFLESSO", // 0x73
+    "FOCALENERGIA", // 0x74
+    "PAZIENZA", // 0x75
+    "METRONOMO", // 0x76
+    "SPECCHIOMOSS", // 0x77
+    "AUTODISTRUZ.", // 0x78
+    "UOVOBOMBA", // 0x79
+    "LECCATA", // 0x7A
+    "SMOG", // 0x7B
+    "FANGO", // 0x7C
+    "OSSOCLAVA", // 0x7D
+    "FUOCOBOMBA", // 0x7E
+    "CASCATA", // 0x7F
+    "TENAGLIA", // 0x80
+    "COMETE", // 0x81
+    "CAPOCCIATA", // 0x82
+    "SPARALANCE", // 0x83
+    "LIMITAZIONE", // 0x84
+    "AMNESIA", // 0x85
+    "CINETECNICA", // 0x86
+    "COVAUOVA", // 0x87
+    "CALCINVOLO", // 0x88
+    "BAGLIORE", // 0x89
+    "MANGIASOGNI", // 0x8A
+    "VELENOGAS", // 0x8B
+    "ATT.PIOGGIA", // 0x8C
+    "SANGUISUGA", // 0x8D
+    "DEMONBACIO", // 0x8E
+    "AEROATTACCO", // 0x8F
+    "TRASFORMAZ.", // 0x90
+    "BOLLA", // 0x91
+    "STORDIPUGNO", // 0x92
+    "SPORA", // 0x93
+    "FLASH", // 0x94
+    "PSICONDA", // 0x95
+    "SPLASH", // 0x96
+    "SCUDO ACIDO", // 0x97
+    "MARTELLATA", // 0x98
+    "ESPLOSIONE", // 0x99
+    "SFURIATE", // 0x9A
+    "OSSOMERANG", // 0x9B
+    "RIPOSO", // 0x9C
+    "FRANA", // 0x9D
+    "IPERZANNA", // 0x9E
+    "AFFILATORE", // 0x9F
+    "CONVERSIONE", // 0xA0
+    "TRIPLETTA", // 0xA1
+    "SUPERZANNA", // 0xA2
+    "LACERAZIONE", // 0xA3
+    "SOSTITUTO", // 0xA4
+    "SCONTRO", // 0xA5
+    "SCHIZZO", // 0xA6
+    "TRIPLOCALCIO", // 0xA7
+    "FURTO", // 0xA8
+    "RAGNATELA", // 0xA9
+    "LEGGIMENTE", // 0xAA
+    "INCUBO", // 0xAB
+    "RUOTAFUOCO", // 0xAC
+    "RUSSARE", // 0xAD
+    "MALEDIZIONE", // 0xAE
+    "FLAGELLO", // 0xAF
+    "CONVERSIONE2", // 0xB0
+    "AEROCOLPO", // 0xB1
+    "COTONSPORA", // 0xB2
+    "CONTROPIEDE", // 0xB3
+    "DISPETTO", // 0xB4
+    "POLVERNEVE", // 0xB5
+    "PROTEZIONE", // 0xB6
+    "PUGNORAPIDO", // 0xB7
+    "VISOTETRO", // 0xB8
+    "FINTATTACCO", // 0xB9
+    "DOLCEBACIO", // 0xBA
+    "PANCIAMBURO", // 0xBB
+    "FANGOBOMBA", // 0xBC
+    "FANGOSBERLA", // 0xBD
+    "OCTAZOOKA", // 0xBE
+    "PUNTESPINE", // 0xBF
+    "FALCECANNONE", // 0xC0
+    "PREVEGGENZA", // 0xC1
+    "DESTINOBBL.", // 0xC2
+    "ULTIMOCANTO", // 0xC3
+    "VENTOGELATO", // 0xC4
+    "INDIVIDUA", // 0xC5
+    "OSSOMINA", // 0xC6
+    "LOCKON", // 0xC7
+    "OLTRAGGIO", // 0xC8
+    "TERREMPESTA", // 0xC9
+    "GIGASSORBIM.", // 0xCA
+    "RESISTENZA", // 0xCB
+    "FASCINO", // 0xCC
+    "ROTOLAMENTO", // 0xCD
+    "FALSOFINALE", // 0xCE
+    "BULLO", // 0xCF
+    "BEVILATTE", // 0xD0
+    "SCINTILLA", // 0xD1
+    "TAGLIOFURIA", // 0xD2
+    "ALACCIAIO", // 0xD3
+    "MALOSGUARDO", // 0xD4
+    "ATTRAZIONE", // 0xD5
+    "SONNOLALIA", // 0xD6
+    "RINTOCCASANA", // 0xD7
+    "RITORNO", // 0xD8
+    "REGALINO", // 0xD9
+    "FRUSTRAZIONE", // 0xDA
+    "SALVAGUARDIA", // 0xDB
+    "MALCOMUNE", // 0xDC
+    "MAGIFUOCO", // 0xDD
+    "MAGNITUDO", // 0xDE
+    "DINAMIPUGNO", // 0xDF
+    "MEGACORNO", // 0xE0
+    "DRAGOSPIRO", // 0xE1
+    "STAFFETTA", // 0xE2
+    "ANCORA", // 0xE3
+    "INSEGUIMENTO", // 0xE4
+    "RAPIGIRO", // 0xE5
+    "PROFUMINO", // 0xE6
+    "CODACCIAIO", // 0xE7
+    "FERROARTIGLI", // 0xE8
+    "VITALTIRO", // 0xE9
+    "MATTINDORO", // 0xEA
+    "SINTESI", // 0xEB
+    "LUCELUNARE", // 0xEC
+    "INTROFORZA", // 0xED
+    "INCROCOLPO", // 0xEE
+    "TORNADO", // 0xEF
+    "PIOGGIADANZA", // 0xF0
+    "GIORNODISOLE", // 0xF1
+    "SGRANOCCHIO", // 0xF2
+    "SPECCHIOVELO", // 0xF3
+    "PSICAMISÙ", // 0xF4
+    "EXTRARAPIDO", // 0xF5
+    "FORZANTICA", // 0xF6
+    "PALLAOMBRA", // 0xF7
+    "DIVINAZIONE", // 0xF8
+    "SPACCAROCCIA", // 0xF9
+    "MULINELLO", // 0xFA
+    "PICCHIADURO", // 0xFB
+];
+
+/// Japanese move names, starting at 0x01.
+///
+/// ヘ and リ are written as へ and り since the games use the same tiles for them (see `decode_char_japanese`).
+const JAPANESE: [&str; 251] = [
+    "はたく", // 0x01
+    "からてチョップ", // 0x02
+    "おうふくビンタ", // 0x03
+    "れんぞくパンチ", // 0x04
+    "メガトンパンチ", // 0x05
+    "ネコにこばん", // 0x06
+    "ほのおのパンチ", // 0x07
+    "れいとうパンチ", // 0x08
+    "かみなりパンチ", // 0x09
+    "ひっかく", // 0x0A
+    "はさむ", // 0x0B
+    "ハサミギロチン", // 0x0C
+    "かまいたち", // 0x0D
+    "つるぎのまい", // 0x0E
+    "いあいぎり", // 0x0F
+    "かぜおこし", // 0x10
+    "つばさでうつ", // 0x11
+    "ふきとばし", // 0x12
+    "そらをとぶ", // 0x13
+    "しめつける", // 0x14
+    "たたきつける", // 0x15
+    "つるのムチ", // 0x16
+    "ふみつけ", // 0x17
+    "にどげり", // 0x18
+    "メガトンキック", // 0x19
+    "とびげり", // 0x1A
+    "まわしげり", // 0x1B
+    "すなかけ", // 0x1C
+    "ずつき", // 0x1D
+    "つのでつく", // 0x1E
+    "みだれづき", // 0x1F
+    "つのドりル", // 0x20
+    "たいあたり", // 0x21
+    "のしかかり", // 0x22
+    "まきつく", // 0x23
+    "とっしん", // 0x24
+    "あばれる", // 0x25
+    "すてみタックル", // 0x26
+    "しっぽをふる", // 0x27
+    "どくばり", // 0x28
+    "ダブルニードル", // 0x29
+    "ミサイルばり", // 0x2A
+    "にらみつける", // 0x2B
+    "かみつく", // 0x2C
+    "なきごえ", // 0x2D
+    "ほえる", // 0x2E
+    "うたう", // 0x2F
+    "ちょうおんぱ", // 0x30
+    "ソニックブーム", // 0x31
+    "かなしばり", // 0x32
+    "ようかいえき", // 0x33
+    "ひのこ", // 0x34
+    "かえんほうしゃ", // 0x35
+    "しろいきり", // 0x36
+    "みずでっぽう", // 0x37
+    "ハイドロポンプ", // 0x38
+    "なみのり", // 0x39
+    "れいとうビーム", // 0x3A
+    "ふぶき", // 0x3B
+    "サイケこうせん", // 0x3C
+    "バブルこうせん", // 0x3D
+    "オーロラビーム", // 0x3E
+    "はかいこうせん", // 0x3F
+    "つつく", // 0x40
+    "ドりルくちばし", // 0x41
+    "じごくぐるま", // 0x42
+    "けたぐり", // 0x43
+    "カウンター", // 0x44
+    "ちきゅうなげ", // 0x45
+    "かいりき", // 0x46
+    "すいとる", // 0x47
+    "メガドレイン", // 0x48
+    "やどりぎのタネ", // 0x49
+    "せいちょう", // 0x4A
+    "はっぱカッター", // 0x4B
+    "ソーラービーム", // 0x4C
+    "どくのこな", // 0x4D
+    "しびれごな", // 0x4E
+    "ねむりごな", // 0x4F
+    "はなびらのまい", // 0x50
+    "いとをはく", // 0x51
+    "りゅうのいかり", // 0x52
+    "ほのおのうず", // 0x53
+    "でんきショック", // 0x54
+    "10まんボルト", // 0x55
+    "でんじは", // 0x56
+    "かみなり", // 0x57
+    "いわおとし", // 0x58
+    "じしん", // 0x59
+    "じわれ", // 0x5A
+    "あなをほる", // 0x5B
+    "どくどく", // 0x5C
+    "ねんりき", // 0x5D
+    "サイコキネシス", // 0x5E
+    "さいみんじゅつ", // 0x5F
+    "ヨガのポーズ", // 0x60
+    "こうそくいどう", // 0x61
+    "でんこうせっか", // 0x62
+    "いかり", // 0x63
+    "テレポート", // 0x64
+    "ナイトへッド", // 0x65
+    "ものまね", // 0x66
+    "いやなおと", // 0x67
+    "かげぶんしん", // 0x68
+    "じこさいせい", // 0x69
+    "かたくなる", // 0x6A
+    "ちいさくなる", // 0x6B
+    "えんまく", // 0x6C
+    "あやしいひかり", // 0x6D
+    "からにこもる", // 0x6E
+    "まるくなる", // 0x6F
+    "バりアー", // 0x70
+    "ひかりのかべ", // 0x71
+    "くろいきり", // 0x72
+    "りフレクター", // 0x73
+    "きあいだめ", // 0x74
+    "がまん", // 0x75
+    "ゆびをふる", // 0x76
+    "オウムがえし", // 0x77
+    "じばく", // 0x78
+    "タマゴばくだん", // 0x79
+    "したでなめる", // 0x7A
+    "スモッグ", // 0x7B
+    "へドロこうげき", // 0x7C
+    "ホネこんぼう", // 0x7D
+    "だいもんじ", // 0x7E
+    "たきのぼり", // 0x7F
+    "からではさむ", // 0x80
+    "スピードスター", // 0x81
+    "ロケットずつき", // 0x82
+    "とげキャノン", // 0x83
+    "からみつく", // 0x84
+    "ドわすれ", // 0x85
+    "スプーンまげ", // 0x86
+    "タマゴうみ", // 0x87
+    "とびひざげり", // 0x88
+    "へびにらみ", // 0x89
+    "ゆめくい", // 0x8A
+    "どくガス", // 0x8B
+    "たまなげ", // 0x8C
+    "きゅうけつ", // 0x8D
+    "あくまのキッス", // 0x8E
+    "ゴッドバード", // 0x8F
+    "へんしん", // 0x90
+    "あわ", // 0x91
+    "ピヨピヨパンチ", // 0x92
+    "キノコのほうし", // 0x93
+    "フラッシュ", // 0x94
+    "サイコウエーブ", // 0x95 (the character map here has no small ェ)
+    "はねる", // 0x96
+    "とける", // 0x97
+    "クラブハンマー", // 0x98
+    "だいばくはつ", // 0x99
+    "みだれひっかき", // 0x9A
+    "ホネブーメラン", // 0x9B
+    "ねむる", // 0x9C
+    "いわなだれ", // 0x9D
+    "ひっさつまえば", // 0x9E
+    "かくばる", // 0x9F
+    "テクスチャー", // 0xA0
+    "トライアタック", // 0xA1
+    "いかりのまえば", // 0xA2
+    "きりさく", // 0xA3
+    "みがわり", // 0xA4
+    "わるあがき", // 0xA5
+    "スケッチ", // 0xA6
+    "トりプルキック", // 0xA7
+    "どろぼう", // 0xA8
+    "クモのす", // 0xA9
+    "こころのめ", // 0xAA
+    "あくむ", // 0xAB
+    "かえんぐるま", // 0xAC
+    "いびき", // 0xAD
+    "のろい", // 0xAE
+    "じたばた", // 0xAF
+    "テクスチャー2", // 0xB0
+    "エアロブラスト", // 0xB1
+    "わたほうし", // 0xB2
+    "きしかいせい", // 0xB3
+    "うらみ", // 0xB4
+    "こなゆき", // 0xB5
+    "まもる", // 0xB6
+    "マッハパンチ", // 0xB7
+    "こわいかお", // 0xB8
+    "だましうち", // 0xB9
+    "てんしのキッス", // 0xBA
+    "はらだいこ", // 0xBB
+    "へドロばくだん", // 0xBC
+    "どろかけ", // 0xBD
+    "オクタンほう", // 0xBE
+    "まきびし", // 0xBF
+    "でんじほう", // 0xC0
+    "みやぶる", // 0xC1
+    "みちづれ", // 0xC2
+    "ほろびのうた", // 0xC3
+    "こごえるかぜ", // 0xC4
+    "みきり", // 0xC5
+    "ボーンラッシュ", // 0xC6
+    "ロックオン", // 0xC7
+    "げきりん", // 0xC8
+    "すなあらし", // 0xC9
+    "ギガドレイン", // 0xCA
+    "こらえる", // 0xCB
+    "あまえる", // 0xCC
+    "ころがる", // 0xCD
+    "みねうち", // 0xCE
+    "いばる", // 0xCF
+    "ミルクのみ", // 0xD0
+    "スパーク", // 0xD1
+    "れんぞくぎり", // 0xD2
+    "はがねのつばさ", // 0xD3
+    "くろいまなざし", // 0xD4
+    "メロメロ", // 0xD5
+    "ねごと", // 0xD6
+    "いやしのすず", // 0xD7
+    "おんがえし", // 0xD8
+    "プレゼント", // 0xD9
+    "やつあたり", // 0xDA
+    "しんぴのまもり", // 0xDB
+    "いたみわけ", // 0xDC
+    "せいなるほのお", // 0xDD
+    "マグニチュード", // 0xDE
+    "ばくれつパンチ", // 0xDF
+    "メガホーン", // 0xE0
+    "りゅうのいぶき", // 0xE1
+    "バトンタッチ", // 0xE2
+    "アンコール", // 0xE3
+    "おいうち", // 0xE4
+    "こうそくスピン", // 0xE5
+    "あまいかおり", // 0xE6
+    "アイアンテール", // 0xE7
+    "メタルクロー", // 0xE8
+    "あてみなげ", // 0xE9
+    "あさのひざし", // 0xEA
+    "こうごうせい", // 0xEB
+    "つきのひかり", // 0xEC
+    "めざめるパワー", // 0xED
+    "クロスチョップ", // 0xEE
+    "たつまき", // 0xEF
+    "あまごい", // 0xF0
+    "にほんばれ", // 0xF1
+    "かみくだく", // 0xF2
+    "ミラーコート", // 0xF3
+    "じこあんじ", // 0xF4
+    "しんそく", // 0xF5
+    "げんしのちから", // 0xF6
+    "シャドーボール", // 0xF7
+    "みらいよち", // 0xF8
+    "いわくだき", // 0xF9
+    "うずしお", // 0xFA
+    "ふくろだたき", // 0xFB
+];
+
+/// Get the name of a move in the given language, or `None` if the index is unknown.
+pub const fn localized_move_name(language: Language, index: u8) -> Option<&'static str> {
+    let table = match language {
+        Language::English => return match MoveType::from_u8(index) {
+            None | Some(MoveType::None) => None,
+            Some(m) => Some(m.name())
+        },
+        Language::French => &FRENCH,
+        Language::German => &GERMAN,
+        Language::Spanish => &SPANISH,
+        Language::Italian => &ITALIAN,
+        Language::Japanese => &JAPANESE
+    };
+    match index {
+        0x01..=0xFB => Some(table[index as usize - 1]),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{decode_text, encode_text};
+
+    #[test]
+    fn names_fit_the_move_menu() {
+        for language in Language::ALL {
+            for index in 0x01..=0xFB {
+                let name = localized_move_name(language, index).unwrap();
+                let encoded = encode_text(language, name).unwrap_or_else(|| panic!("{name} can't be encoded in {}", language.name()));
+                assert_eq!(decode_text(language, &encoded).as_deref(), Some(name));
+                assert!(name.chars().count() <= crate::rom::MAX_MOVE_NAME_LENGTH, "{name} is too long");
+            }
+        }
+    }
+
+    #[test]
+    fn tables_start_with_the_rom_signature() {
+        for language in Language::ALL {
+            assert_eq!([localized_move_name(language, 1), localized_move_name(language, 2)], language.first_move_names().map(Some));
+        }
+    }
+
+    #[test]
+    fn unknown_indices() {
+        for language in Language::ALL {
+            assert_eq!(localized_move_name(language, 0x00), None);
+            assert_eq!(localized_move_name(language, 0xFC), None);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use super::{Generation, MoveType, Type};
+use Type::*;
+
+/// A move's type, power, accuracy, and PP, as shown in the games.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MoveInfo {
+    pub move_type: Type,
+
+    /// Base power, or `None` for moves that don't do damage or work it out some other way (e.g. Seismic Toss)
+    pub power: Option<u8>,
+
+    /// Accuracy as a percentage, or `None` for moves that can't miss
+    pub accuracy: Option<u8>,
+
+    /// PP without any PP Ups
+    pub pp: u8
+}
+
+/// Generation 2 move data, starting at 0x01.
+const MOVES: [MoveInfo; 251] = [
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 35 }, // 0x01 POUND
+    MoveInfo { move_type: Fighting, power: Some(50), accuracy: Some(100), pp: 25 }, // 0x02 KARATE CHOP
+    MoveInfo { move_type: Normal, power: Some(15), accuracy: Some(85), pp: 10 }, // 0x03 DOUBLESLAP
+    MoveInfo { move_type: Normal, power: Some(18), accuracy: Some(85), pp: 15 }, // 0x04 COMET PUNCH
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(85), pp: 20 }, // 0x05 MEGA PUNCH
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 20 }, // 0x06 PAY DAY
+    MoveInfo { move_type: Fire, power: Some(75), accuracy: Some(100), pp: 15 }, // 0x07 FIRE PUNCH
+    MoveInfo { move_type: Ice, power: Some(75), accuracy: Some(100), pp: 15 }, // 0x08 ICE PUNCH
+    MoveInfo { move_type: Electric, power: Some(75), accuracy: Some(100), pp: 15 }, // 0x09 THUNDERPUNCH
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 35 }, // 0x0A SCRATCH
+    MoveInfo { move_type: Normal, power: Some(55), accuracy: Some(100), pp: 30 }, // 0x0B VICEGRIP
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(30), pp: 5 }, // 0x0C GUILLOTINE
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(100), pp: 10 }, // 0x0D RAZOR WIND
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 30 }, // 0x0E SWORDS DANCE
+    MoveInfo { move_type: Normal, power: Some(50), accuracy: Some(95), pp: 30 }, // 0x0F CUT
+    MoveInfo { move_type: Flying, power: Some(40), accuracy: Some(100), pp: 35 }, // 0x10 GUST
+    MoveInfo { move_type: Flying, power: Some(60), accuracy: Some(100), pp: 35 }, // 0x11 WING ATTACK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0x12 WHIRLWIND
+    MoveInfo { move_type: Flying, power: Some(70), accuracy: Some(95), pp: 15 }, // 0x13 FLY
+    MoveInfo { move_type: Normal, power: Some(15), accuracy: Some(75), pp: 20 }, // 0x14 BIND
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(75), pp: 20 }, // 0x15 SLAM
+    MoveInfo { move_type: Grass, power: Some(35), accuracy: Some(100), pp: 10 }, // 0x16 VINE WHIP
+    MoveInfo { move_type: Normal, power: Some(65), accuracy: Some(100), pp: 20 }, // 0x17 STOMP
+    MoveInfo { move_type: Fighting, power: Some(30), accuracy: Some(100), pp: 30 }, // 0x18 DOUBLE KICK
+    MoveInfo { move_type: Normal, power: Some(120), accuracy: Some(75), pp: 5 }, // 0x19 MEGA KICK
+    MoveInfo { move_type: Fighting, power: Some(70), accuracy: Some(95), pp: 25 }, // 0x1A JUMP KICK
+    MoveInfo { move_type: Fighting, power: Some(60), accuracy: Some(85), pp: 15 }, // 0x1B ROLLING KICK
+    MoveInfo { move_type: Ground, power: None, accuracy: Some(100), pp: 15 }, // 0x1C SAND-ATTACK
+    MoveInfo { move_type: Normal, power: Some(70), accuracy: Some(100), pp: 15 }, // 0x1D HEADBUTT
+    MoveInfo { move_type: Normal, power: Some(65), accuracy: Some(100), pp: 25 }, // 0x1E HORN ATTACK
+    MoveInfo { move_type: Normal, power: Some(15), accuracy: Some(85), pp: 20 }, // 0x1F FURY ATTACK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(30), pp: 5 }, // 0x20 HORN DRILL
+    MoveInfo { move_type: Normal, power: Some(35), accuracy: Some(95), pp: 35 }, // 0x21 TACKLE
+    MoveInfo { move_type: Normal, power: Some(85), accuracy: Some(100), pp: 15 }, // 0x22 BODY SLAM
+    MoveInfo { move_type: Normal, power: Some(15), accuracy: Some(85), pp: 20 }, // 0x23 WRAP
+    MoveInfo { move_type: Normal, power: Some(90), accuracy: Some(85), pp: 20 }, // 0x24 TAKE DOWN
+    MoveInfo { move_type: Normal, power: Some(90), accuracy: Some(100), pp: 20 }, // 0x25 THRASH
+    MoveInfo { move_type: Normal, power: Some(120), accuracy: Some(100), pp: 15 }, // 0x26 DOUBLE-EDGE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 30 }, // 0x27 TAIL WHIP
+    MoveInfo { move_type: Poison, power: Some(15), accuracy: Some(100), pp: 35 }, // 0x28 POISON STING
+    MoveInfo { move_type: Bug, power: Some(25), accuracy: Some(100), pp: 20 }, // 0x29 TWINEEDLE
+    MoveInfo { move_type: Bug, power: Some(14), accuracy: Some(85), pp: 20 }, // 0x2A PIN MISSILE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 30 }, // 0x2B LEER
+    MoveInfo { move_type: Dark, power: Some(60), accuracy: Some(100), pp: 25 }, // 0x2C BITE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 40 }, // 0x2D GROWL
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0x2E ROAR
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(55), pp: 15 }, // 0x2F SING
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(55), pp: 20 }, // 0x30 SUPERSONIC
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(90), pp: 20 }, // 0x31 SONICBOOM
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(55), pp: 20 }, // 0x32 DISABLE
+    MoveInfo { move_type: Poison, power: Some(40), accuracy: Some(100), pp: 30 }, // 0x33 ACID
+    MoveInfo { move_type: Fire, power: Some(40), accuracy: Some(100), pp: 25 }, // 0x34 EMBER
+    MoveInfo { move_type: Fire, power: Some(95), accuracy: Some(100), pp: 15 }, // 0x35 FLAMETHROWER
+    MoveInfo { move_type: Ice, power: None, accuracy: None, pp: 30 }, // 0x36 MIST
+    MoveInfo { move_type: Water, power: Some(40), accuracy: Some(100), pp: 25 }, // 0x37 WATER GUN
+    MoveInfo { move_type: Water, power: Some(120), accuracy: Some(80), pp: 5 }, // 0x38 HYDRO PUMP
+    MoveInfo { move_type: Water, power: Some(95), accuracy: Some(100), pp: 15 }, // 0x39 SURF
+    MoveInfo { move_type: Ice, power: Some(95), accuracy: Some(100), pp: 10 }, // 0x3A ICE BEAM
+    MoveInfo { move_type: Ice, power: Some(120), accuracy: Some(70), pp: 5 }, // 0x3B BLIZZARD
+    MoveInfo { move_type: Psychic, power: Some(65), accuracy: Some(100), pp: 20 }, // 0x3C PSYBEAM
+    MoveInfo { move_type: Water, power: Some(65), accuracy: Some(100), pp: 20 }, // 0x3D BUBBLEBEAM
+    MoveInfo { move_type: Ice, power: Some(65), accuracy: Some(100), pp: 20 }, // 0x3E AURORA BEAM
+    MoveInfo { move_type: Normal, power: Some(150), accuracy: Some(90), pp: 5 }, // 0x3F HYPER BEAM
+    MoveInfo { move_type: Flying, power: Some(35), accuracy: Some(100), pp: 35 }, // 0x40 PECK
+    MoveInfo { move_type: Flying, power: Some(80), accuracy: Some(100), pp: 20 }, // 0x41 DRILL PECK
+    MoveInfo { move_type: Fighting, power: Some(80), accuracy: Some(80), pp: 25 }, // 0x42 SUBMISSION
+    MoveInfo { move_type: Fighting, power: Some(50), accuracy: Some(90), pp: 20 }, // 0x43 LOW KICK
+    MoveInfo { move_type: Fighting, power: None, accuracy: Some(100), pp: 20 }, // 0x44 COUNTER
+    MoveInfo { move_type: Fighting, power: None, accuracy: Some(100), pp: 20 }, // 0x45 SEISMIC TOSS
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(100), pp: 15 }, // 0x46 STRENGTH
+    MoveInfo { move_type: Grass, power: Some(20), accuracy: Some(100), pp: 20 }, // 0x47 ABSORB
+    MoveInfo { move_type: Grass, power: Some(40), accuracy: Some(100), pp: 10 }, // 0x48 MEGA DRAIN
+    MoveInfo { move_type: Grass, power: None, accuracy: Some(90), pp: 10 }, // 0x49 LEECH SEED
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 40 }, // 0x4A GROWTH
+    MoveInfo { move_type: Grass, power: Some(55), accuracy: Some(95), pp: 25 }, // 0x4B RAZOR LEAF
+    MoveInfo { move_type: Grass, power: Some(120), accuracy: Some(100), pp: 10 }, // 0x4C SOLARBEAM
+    MoveInfo { move_type: Poison, power: None, accuracy: Some(75), pp: 35 }, // 0x4D POISONPOWDER
+    MoveInfo { move_type: Grass, power: None, accuracy: Some(75), pp: 30 }, // 0x4E STUN SPORE
+    MoveInfo { move_type: Grass, power: None, accuracy: Some(75), pp: 15 }, // 0x4F SLEEP POWDER
+    MoveInfo { move_type: Grass, power: Some(70), accuracy: Some(100), pp: 20 }, // 0x50 PETAL DANCE
+    MoveInfo { move_type: Bug, power: None, accuracy: Some(95), pp: 40 }, // 0x51 STRING SHOT
+    MoveInfo { move_type: Dragon, power: None, accuracy: Some(100), pp: 10 }, // 0x52 DRAGON RAGE
+    MoveInfo { move_type: Fire, power: Some(15), accuracy: Some(70), pp: 15 }, // 0x53 FIRE SPIN
+    MoveInfo { move_type: Electric, power: Some(40), accuracy: Some(100), pp: 30 }, // 0x54 THUNDERSHOCK
+    MoveInfo { move_type: Electric, power: Some(95), accuracy: Some(100), pp: 15 }, // 0x55 THUNDERBOLT
+    MoveInfo { move_type: Electric, power: None, accuracy: Some(100), pp: 20 }, // 0x56 THUNDER WAVE
+    MoveInfo { move_type: Electric, power: Some(120), accuracy: Some(70), pp: 10 }, // 0x57 THUNDER
+    MoveInfo { move_type: Rock, power: Some(50), accuracy: Some(90), pp: 15 }, // 0x58 ROCK THROW
+    MoveInfo { move_type: Ground, power: Some(100), accuracy: Some(100), pp: 10 }, // 0x59 EARTHQUAKE
+    MoveInfo { move_type: Ground, power: None, accuracy: Some(30), pp: 5 }, // 0x5A FISSURE
+    MoveInfo { move_type: Ground, power: Some(60), accuracy: Some(100), pp: 10 }, // 0x5B DIG
+    MoveInfo { move_type: Poison, power: None, accuracy: Some(85), pp: 10 }, // 0x5C TOXIC
+    MoveInfo { move_type: Psychic, power: Some(50), accuracy: Some(100), pp: 25 }, // 0x5D CONFUSION
+    MoveInfo { move_type: Psychic, power: Some(90), accuracy: Some(100), pp: 10 }, // 0x5E PSYCHIC
+    MoveInfo { move_type: Psychic, power: None, accuracy: Some(60), pp: 20 }, // 0x5F HYPNOSIS
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 40 }, // 0x60 MEDITATE
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 30 }, // 0x61 AGILITY
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 30 }, // 0x62 QUICK ATTACK
+    MoveInfo { move_type: Normal, power: Some(20), accuracy: Some(100), pp: 20 }, // 0x63 RAGE
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 20 }, // 0x64 TELEPORT
+    MoveInfo { move_type: Ghost, power: None, accuracy: Some(100), pp: 15 }, // 0x65 NIGHT SHADE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 10 }, // 0x66 MIMIC
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(85), pp: 40 }, // 0x67 SCREECH
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 15 }, // 0x68 DOUBLE TEAM
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 20 }, // 0x69 RECOVER
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 30 }, // 0x6A HARDEN
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 20 }, // 0x6B MINIMIZE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0x6C SMOKESCREEN
+    MoveInfo { move_type: Ghost, power: None, accuracy: Some(100), pp: 10 }, // 0x6D CONFUSE RAY
+    MoveInfo { move_type: Water, power: None, accuracy: None, pp: 40 }, // 0x6E WITHDRAW
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 40 }, // 0x6F DEFENSE CURL
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 30 }, // 0x70 BARRIER
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 30 }, // 0x71 LIGHT SCREEN
+    MoveInfo { move_type: Ice, power: None, accuracy: None, pp: 30 }, // 0x72 HAZE
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 20 }, // 0x73 REFLECT
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 30 }, // 0x74 FOCUS ENERGY
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0x75 BIDE
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0x76 METRONOME
+    MoveInfo { move_type: Flying, power: None, accuracy: None, pp: 20 }, // 0x77 MIRROR MOVE
+    MoveInfo { move_type: Normal, power: Some(200), accuracy: Some(100), pp: 5 }, // 0x78 SELFDESTRUCT
+    MoveInfo { move_type: Normal, power: Some(100), accuracy: Some(75), pp: 10 }, // 0x79 EGG BOMB
+    MoveInfo { move_type: Ghost, power: Some(20), accuracy: Some(100), pp: 30 }, // 0x7A LICK
+    MoveInfo { move_type: Poison, power: Some(20), accuracy: Some(70), pp: 20 }, // 0x7B SMOG
+    MoveInfo { move_type: Poison, power: Some(65), accuracy: Some(100), pp: 20 }, // 0x7C SLUDGE
+    MoveInfo { move_type: Ground, power: Some(65), accuracy: Some(85), pp: 20 }, // 0x7D BONE CLUB
+    MoveInfo { move_type: Fire, power: Some(120), accuracy: Some(85), pp: 5 }, // 0x7E FIRE BLAST
+    MoveInfo { move_type: Water, power: Some(80), accuracy: Some(100), pp: 15 }, // 0x7F WATERFALL
+    MoveInfo { move_type: Water, power: Some(35), accuracy: Some(75), pp: 10 }, // 0x80 CLAMP
+    MoveInfo { move_type: Normal, power: Some(60), accuracy: None, pp: 20 }, // 0x81 SWIFT
+    MoveInfo { move_type: Normal, power: Some(100), accuracy: Some(100), pp: 15 }, // 0x82 SKULL BASH
+    MoveInfo { move_type: Normal, power: Some(20), accuracy: Some(100), pp: 15 }, // 0x83 SPIKE CANNON
+    MoveInfo { move_type: Normal, power: Some(10), accuracy: Some(100), pp: 35 }, // 0x84 CONSTRICT
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 20 }, // 0x85 AMNESIA
+    MoveInfo { move_type: Psychic, power: None, accuracy: Some(80), pp: 15 }, // 0x86 KINESIS
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0x87 SOFTBOILED
+    MoveInfo { move_type: Fighting, power: Some(85), accuracy: Some(90), pp: 20 }, // 0x88 HI JUMP KICK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(75), pp: 30 }, // 0x89 GLARE
+    MoveInfo { move_type: Psychic, power: Some(100), accuracy: Some(100), pp: 15 }, // 0x8A DREAM EATER
+    MoveInfo { move_type: Poison, power: None, accuracy: Some(55), pp: 40 }, // 0x8B POISON GAS
+    MoveInfo { move_type: Normal, power: Some(15), accuracy: Some(85), pp: 20 }, // 0x8C BARRAGE
+    MoveInfo { move_type: Bug, power: Some(20), accuracy: Some(100), pp: 15 }, // 0x8D LEECH LIFE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(75), pp: 10 }, // 0x8E LOVELY KISS
+    MoveInfo { move_type: Flying, power: Some(140), accuracy: Some(90), pp: 5 }, // 0x8F SKY ATTACK
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0x90 TRANSFORM
+    MoveInfo { move_type: Water, power: Some(20), accuracy: Some(100), pp: 30 }, // 0x91 BUBBLE
+    MoveInfo { move_type: Normal, power: Some(70), accuracy: Some(100), pp: 10 }, // 0x92 DIZZY PUNCH
+    MoveInfo { move_type: Grass, power: None, accuracy: Some(100), pp: 15 }, // 0x93 SPORE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(70), pp: 20 }, // 0x94 FLASH
+    MoveInfo { move_type: Psychic, power: None, accuracy: Some(80), pp: 15 }, // 0x95 PSYWAVE
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 40 }, // 0x96 SPLASH
+    MoveInfo { move_type: Poison, power: None, accuracy: None, pp: 40 }, // 0x97 ACID ARMOR
+    MoveInfo { move_type: Water, power: Some(90), accuracy: Some(85), pp: 10 }, // 0x98 CRABHAMMER
+    MoveInfo { move_type: Normal, power: Some(250), accuracy: Some(100), pp: 5 }, // 0x99 EXPLOSION
+    MoveInfo { move_type: Normal, power: Some(18), accuracy: Some(80), pp: 15 }, // 0x9A FURY SWIPES
+    MoveInfo { move_type: Ground, power: Some(50), accuracy: Some(90), pp: 10 }, // 0x9B BONEMERANG
+    MoveInfo { move_type: Psychic, power: None, accuracy: None, pp: 10 }, // 0x9C REST
+    MoveInfo { move_type: Rock, power: Some(75), accuracy: Some(90), pp: 10 }, // 0x9D ROCK SLIDE
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(90), pp: 15 }, // 0x9E HYPER FANG
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 30 }, // 0x9F SHARPEN
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 30 }, // 0xA0 CONVERSION
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(100), pp: 10 }, // 0xA1 TRI ATTACK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(90), pp: 10 }, // 0xA2 SUPER FANG
+    MoveInfo { move_type: Normal, power: Some(70), accuracy: Some(100), pp: 20 }, // 0xA3 SLASH
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xA4 SUBSTITUTE
+    MoveInfo { move_type: Normal, power: Some(50), accuracy: Some(100), pp: 1 }, // 0xA5 STRUGGLE
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 1 }, // 0xA6 SKETCH
+    MoveInfo { move_type: Fighting, power: Some(10), accuracy: Some(90), pp: 10 }, // 0xA7 TRIPLE KICK
+    MoveInfo { move_type: Dark, power: Some(40), accuracy: Some(100), pp: 10 }, // 0xA8 THIEF
+    MoveInfo { move_type: Bug, power: None, accuracy: None, pp: 10 }, // 0xA9 SPIDER WEB
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 5 }, // 0xAA MIND READER
+    MoveInfo { move_type: Ghost, power: None, accuracy: Some(100), pp: 15 }, // 0xAB NIGHTMARE
+    MoveInfo { move_type: Fire, power: Some(60), accuracy: Some(100), pp: 25 }, // 0xAC FLAME WHEEL
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 15 }, // 0xAD SNORE
+    MoveInfo { move_type: CurseType, power: None, accuracy: None, pp: 10 }, // 0xAE CURSE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 15 }, // 0xAF FLAIL
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 30 }, // 0xB0 CONVERSION2
+    MoveInfo { move_type: Flying, power: Some(100), accuracy: Some(95), pp: 5 }, // 0xB1 AEROBLAST
+    MoveInfo { move_type: Grass, power: None, accuracy: Some(85), pp: 40 }, // 0xB2 COTTON SPORE
+    MoveInfo { move_type: Fighting, power: None, accuracy: Some(100), pp: 15 }, // 0xB3 REVERSAL
+    MoveInfo { move_type: Ghost, power: None, accuracy: Some(100), pp: 10 }, // 0xB4 SPITE
+    MoveInfo { move_type: Ice, power: Some(40), accuracy: Some(100), pp: 25 }, // 0xB5 POWDER SNOW
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xB6 PROTECT
+    MoveInfo { move_type: Fighting, power: Some(40), accuracy: Some(100), pp: 30 }, // 0xB7 MACH PUNCH
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(90), pp: 10 }, // 0xB8 SCARY FACE
+    MoveInfo { move_type: Dark, power: Some(60), accuracy: None, pp: 20 }, // 0xB9 FAINT ATTACK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(75), pp: 10 }, // 0xBA SWEET KISS
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xBB BELLY DRUM
+    MoveInfo { move_type: Poison, power: Some(90), accuracy: Some(100), pp: 10 }, // 0xBC SLUDGE BOMB
+    MoveInfo { move_type: Ground, power: Some(20), accuracy: Some(100), pp: 10 }, // 0xBD MUD-SLAP
+    MoveInfo { move_type: Water, power: Some(65), accuracy: Some(85), pp: 10 }, // 0xBE OCTAZOOKA
+    MoveInfo { move_type: Ground, power: None, accuracy: None, pp: 20 }, // 0xBF SPIKES
+    MoveInfo { move_type: Electric, power: Some(100), accuracy: Some(50), pp: 5 }, // 0xC0 ZAP CANNON
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 40 }, // 0xC1 FORESIGHT
+    MoveInfo { move_type: Ghost, power: None, accuracy: None, pp: 5 }, // 0xC2 DESTINY BOND
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 5 }, // 0xC3 PERISH SONG
+    MoveInfo { move_type: Ice, power: Some(55), accuracy: Some(95), pp: 15 }, // 0xC4 ICY WIND
+    MoveInfo { move_type: Fighting, power: None, accuracy: None, pp: 5 }, // 0xC5 DETECT
+    MoveInfo { move_type: Ground, power: Some(25), accuracy: Some(80), pp: 10 }, // 0xC6 BONE RUSH
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 5 }, // 0xC7 LOCK-ON
+    MoveInfo { move_type: Dragon, power: Some(90), accuracy: Some(100), pp: 15 }, // 0xC8 OUTRAGE
+    MoveInfo { move_type: Rock, power: None, accuracy: None, pp: 10 }, // 0xC9 SANDSTORM
+    MoveInfo { move_type: Grass, power: Some(60), accuracy: Some(100), pp: 5 }, // 0xCA GIGA DRAIN
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xCB ENDURE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0xCC CHARM
+    MoveInfo { move_type: Rock, power: Some(30), accuracy: Some(90), pp: 20 }, // 0xCD ROLLOUT
+    MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 40 }, // 0xCE FALSE SWIPE
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(90), pp: 15 }, // 0xCF SWAGGER
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xD0 MILK DRINK
+    MoveInfo { move_type: Electric, power: Some(65), accuracy: Some(100), pp: 20 }, // 0xD1 SPARK
+    MoveInfo { move_type: Bug, power: Some(10), accuracy: Some(95), pp: 20 }, // 0xD2 FURY CUTTER
+    MoveInfo { move_type: Steel, power: Some(70), accuracy: Some(90), pp: 25 }, // 0xD3 STEEL WING
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 5 }, // 0xD4 MEAN LOOK
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 15 }, // 0xD5 ATTRACT
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xD6 SLEEP TALK
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 5 }, // 0xD7 HEAL BELL
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0xD8 RETURN
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(90), pp: 15 }, // 0xD9 PRESENT
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0xDA FRUSTRATION
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 25 }, // 0xDB SAFEGUARD
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0xDC PAIN SPLIT
+    MoveInfo { move_type: Fire, power: Some(100), accuracy: Some(95), pp: 5 }, // 0xDD SACRED FIRE
+    MoveInfo { move_type: Ground, power: None, accuracy: Some(100), pp: 30 }, // 0xDE MAGNITUDE
+    MoveInfo { move_type: Fighting, power: Some(100), accuracy: Some(50), pp: 5 }, // 0xDF DYNAMICPUNCH
+    MoveInfo { move_type: Bug, power: Some(120), accuracy: Some(85), pp: 10 }, // 0xE0 MEGAHORN
+    MoveInfo { move_type: Dragon, power: Some(60), accuracy: Some(100), pp: 20 }, // 0xE1 DRAGONBREATH
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 40 }, // 0xE2 BATON PASS
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 5 }, // 0xE3 ENCORE
+    MoveInfo { move_type: Dark, power: Some(40), accuracy: Some(100), pp: 20 }, // 0xE4 PURSUIT
+    MoveInfo { move_type: Normal, power: Some(20), accuracy: Some(100), pp: 40 }, // 0xE5 RAPID SPIN
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 20 }, // 0xE6 SWEET SCENT
+    MoveInfo { move_type: Steel, power: Some(100), accuracy: Some(75), pp: 15 }, // 0xE7 IRON TAIL
+    MoveInfo { move_type: Steel, power: Some(50), accuracy: Some(95), pp: 35 }, // 0xE8 METAL CLAW
+    MoveInfo { move_type: Fighting, power: Some(70), accuracy: None, pp: 10 }, // 0xE9 VITAL THROW
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 5 }, // 0xEA MORNING SUN
+    MoveInfo { move_type: Grass, power: None, accuracy: None, pp: 5 }, // 0xEB SYNTHESIS
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 5 }, // 0xEC MOONLIGHT
+    MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 15 }, // 0xED HIDDEN POWER
+    MoveInfo { move_type: Fighting, power: Some(100), accuracy: Some(80), pp: 5 }, // 0xEE CROSS CHOP
+    MoveInfo { move_type: Dragon, power: Some(40), accuracy: Some(100), pp: 20 }, // 0xEF TWISTER
+    MoveInfo { move_type: Water, power: None, accuracy: None, pp: 5 }, // 0xF0 RAIN DANCE
+    MoveInfo { move_type: Fire, power: None, accuracy: None, pp: 5 }, // 0xF1 SUNNY DAY
+    MoveInfo { move_type: Dark, power: Some(80), accuracy: Some(100), pp: 15 }, // 0xF2 CRUNCH
+    MoveInfo { move_type: Psychic, power: None, accuracy: Some(100), pp: 20 }, // 0xF3 MIRROR COAT
+    MoveInfo { move_type: Normal, power: None, accuracy: None, pp: 10 }, // 0xF4 PSYCH UP
+    MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(100), pp: 5 }, // 0xF5 EXTREMESPEED
+    MoveInfo { move_type: Rock, power: Some(60), accuracy: Some(100), pp: 5 }, // 0xF6 ANCIENTPOWER
+    MoveInfo { move_type: Ghost, power: Some(80), accuracy: Some(100), pp: 15 }, // 0xF7 SHADOW BALL
+    MoveInfo { move_type: Psychic, power: Some(80), accuracy: Some(90), pp: 15 }, // 0xF8 FUTURE SIGHT
+    MoveInfo { move_type: Fighting, power: Some(20), accuracy: Some(100), pp: 15 }, // 0xF9 ROCK SMASH
+    MoveInfo { move_type: Water, power: Some(15), accuracy: Some(70), pp: 15 }, // 0xFA WHIRLPOOL
+    MoveInfo { move_type: Dark, power: Some(10), accuracy: Some(100), pp: 10 }, // 0xFB BEAT UP
+];
+
+/// Generation 1 moves that were changed in Generation 2.
+const GEN1_CHANGES: [(u8, MoveInfo); 14] = [
+    (0x02, MoveInfo { move_type: Normal, power: Some(50), accuracy: Some(100), pp: 25 }), // KARATE CHOP
+    (0x0D, MoveInfo { move_type: Normal, power: Some(80), accuracy: Some(75), pp: 10 }), // RAZOR WIND
+    (0x10, MoveInfo { move_type: Normal, power: Some(40), accuracy: Some(100), pp: 35 }), // GUST
+    (0x11, MoveInfo { move_type: Flying, power: Some(35), accuracy: Some(100), pp: 35 }), // WING ATTACK
+    (0x12, MoveInfo { move_type: Normal, power: None, accuracy: Some(85), pp: 20 }), // WHIRLWIND
+    (0x1C, MoveInfo { move_type: Normal, power: None, accuracy: Some(100), pp: 15 }), // SAND-ATTACK
+    (0x26, MoveInfo { move_type: Normal, power: Some(100), accuracy: Some(100), pp: 15 }), // DOUBLE-EDGE
+    (0x2C, MoveInfo { move_type: Normal, power: Some(60), accuracy: Some(100), pp: 25 }), // BITE
+    (0x3B, MoveInfo { move_type: Ice, power: Some(120), accuracy: Some(90), pp: 5 }), // BLIZZARD
+    (0x58, MoveInfo { move_type: Rock, power: Some(50), accuracy: Some(65), pp: 15 }), // ROCK THROW
+    (0x5B, MoveInfo { move_type: Ground, power: Some(100), accuracy: Some(100), pp: 10 }), // DIG
+    (0x78, MoveInfo { move_type: Normal, power: Some(130), accuracy: Some(100), pp: 5 }), // SELFDESTRUCT
+    (0x99, MoveInfo { move_type: Normal, power: Some(170), accuracy: Some(100), pp: 5 }), // EXPLOSION
+    (0xA5, MoveInfo { move_type: Normal, power: Some(50), accuracy: Some(100), pp: 10 }), // STRUGGLE
+];
+
+/// Get the data for the move with the given index in the given generation.
+pub fn move_info(generation: Generation, move_index: u8) -> Option<MoveInfo> {
+    let last = match generation {
+        Generation::One => MoveType::Struggle as u8,
+        Generation::Two => MOVES.len() as u8
+    };
+    if move_index == 0 || move_index > last {
+        return None;
+    }
+    if generation == Generation::One {
+        if let Some((_, info)) = GEN1_CHANGES.iter().find(|(index, _)| *index == move_index) {
+            return Some(*info);
+        }
+    }
+    Some(MOVES[move_index as usize - 1])
+}
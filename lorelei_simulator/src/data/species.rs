@@ -0,0 +1,834 @@
+use super::Generation;
+
+/// Corresponds to all of the Pokémon species in Generation 1 and 2, numbered by their Pokédex index.
+///
+/// Generation 2 stores species by Pokédex index, while Generation 1 uses its own internal ordering; see
+/// [`Species::from_gen1_index`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(u8)]
+pub enum Species {
+    Bulbasaur = 0x01,
+    Ivysaur = 0x02,
+    Venusaur = 0x03,
+    Charmander = 0x04,
+    Charmeleon = 0x05,
+    Charizard = 0x06,
+    Squirtle = 0x07,
+    Wartortle = 0x08,
+    Blastoise = 0x09,
+    Caterpie = 0x0A,
+    Metapod = 0x0B,
+    Butterfree = 0x0C,
+    Weedle = 0x0D,
+    Kakuna = 0x0E,
+    Beedrill = 0x0F,
+    Pidgey = 0x10,
+    Pidgeotto = 0x11,
+    Pidgeot = 0x12,
+    Rattata = 0x13,
+    Raticate = 0x14,
+    Spearow = 0x15,
+    Fearow = 0x16,
+    Ekans = 0x17,
+    Arbok = 0x18,
+    Pikachu = 0x19,
+    Raichu = 0x1A,
+    Sandshrew = 0x1B,
+    Sandslash = 0x1C,
+    NidoranF = 0x1D,
+    Nidorina = 0x1E,
+    Nidoqueen = 0x1F,
+    NidoranM = 0x20,
+    Nidorino = 0x21,
+    Nidoking = 0x22,
+    Clefairy = 0x23,
+    Clefable = 0x24,
+    Vulpix = 0x25,
+    Ninetales = 0x26,
+    Jigglypuff = 0x27,
+    Wigglytuff = 0x28,
+    Zubat = 0x29,
+    Golbat = 0x2A,
+    Oddish = 0x2B,
+    Gloom = 0x2C,
+    Vileplume = 0x2D,
+    Paras = 0x2E,
+    Parasect = 0x2F,
+    Venonat = 0x30,
+    Venomoth = 0x31,
+    Diglett = 0x32,
+    Dugtrio = 0x33,
+    Meowth = 0x34,
+    Persian = 0x35,
+    Psyduck = 0x36,
+    Golduck = 0x37,
+    Mankey = 0x38,
+    Primeape = 0x39,
+    Growlithe = 0x3A,
+    Arcanine = 0x3B,
+    Poliwag = 0x3C,
+    Poliwhirl = 0x3D,
+    Poliwrath = 0x3E,
+    Abra = 0x3F,
+    Kadabra = 0x40,
+    Alakazam = 0x41,
+    Machop = 0x42,
+    Machoke = 0x43,
+    Machamp = 0x44,
+    Bellsprout = 0x45,
+    Weepinbell = 0x46,
+    Victreebel = 0x47,
+    Tentacool = 0x48,
+    Tentacruel = 0x49,
+    Geodude = 0x4A,
+    Graveler = 0x4B,
+    Golem = 0x4C,
+    Ponyta = 0x4D,
+    Rapidash = 0x4E,
+    Slowpoke = 0x4F,
+    Slowbro = 0x50,
+    Magnemite = 0x51,
+    Magneton = 0x52,
+    Farfetchd = 0x53,
+    Doduo = 0x54,
+    Dodrio = 0x55,
+    Seel = 0x56,
+    Dewgong = 0x57,
+    Grimer = 0x58,
+    Muk = 0x59,
+    Shellder = 0x5A,
+    Cloyster = 0x5B,
+    Gastly = 0x5C,
+    Haunter = 0x5D,
+    Gengar = 0x5E,
+    Onix = 0x5F,
+    Drowzee = 0x60,
+    Hypno = 0x61,
+    Krabby = 0x62,
+    Kingler = 0x63,
+    Voltorb = 0x64,
+    Electrode = 0x65,
+    Exeggcute = 0x66,
+    Exeggutor = 0x67,
+    Cubone = 0x68,
+    Marowak = 0x69,
+    Hitmonlee = 0x6A,
+    Hitmonchan = 0x6B,
+    Lickitung = 0x6C,
+    Koffing = 0x6D,
+    Weezing = 0x6E,
+    Rhyhorn = 0x6F,
+    Rhydon = 0x70,
+    Chansey = 0x71,
+    Tangela = 0x72,
+    Kangaskhan = 0x73,
+    Horsea = 0x74,
+    Seadra = 0x75,
+    Goldeen = 0x76,
+    Seaking = 0x77,
+    Staryu = 0x78,
+    Starmie = 0x79,
+    MrMime = 0x7A,
+    Scyther = 0x7B,
+    Jynx = 0x7C,
+    Electabuzz = 0x7D,
+    Magmar = 0x7E,
+    Pinsir = 0x7F,
+    Tauros = 0x80,
+    Magikarp = 0x81,
+    Gyarados = 0x82,
+    Lapras = 0x83,
+    Ditto = 0x84,
+    Eevee = 0x85,
+    Vaporeon = 0x86,
+    Jolteon = 0x87,
+    Flareon = 0x88,
+    Porygon = 0x89,
+    Omanyte = 0x8A,
+    Omastar = 0x8B,
+    Kabuto = 0x8C,
+    Kabutops = 0x8D,
+    Aerodactyl = 0x8E,
+    Snorlax = 0x8F,
+    Articuno = 0x90,
+    Zapdos = 0x91,
+    Moltres = 0x92,
+    Dratini = 0x93,
+    Dragonair = 0x94,
+    Dragonite = 0x95,
+    Mewtwo = 0x96,
+    Mew = 0x97,
+    Chikorita = 0x98,
+    Bayleef = 0x99,
+    Meganium = 0x9A,
+    Cyndaquil = 0x9B,
+    Quilava = 0x9C,
+    Typhlosion = 0x9D,
+    Totodile = 0x9E,
+    Croconaw = 0x9F,
+    Feraligatr = 0xA0,
+    Sentret = 0xA1,
+    Furret = 0xA2,
+    Hoothoot = 0xA3,
+    Noctowl = 0xA4,
+    Ledyba = 0xA5,
+    Ledian = 0xA6,
+    Spinarak = 0xA7,
+    Ariados = 0xA8,
+    Crobat = 0xA9,
+    Chinchou = 0xAA,
+    Lanturn = 0xAB,
+    Pichu = 0xAC,
+    Cleffa = 0xAD,
+    Igglybuff = 0xAE,
+    Togepi = 0xAF,
+    Togetic = 0xB0,
+    Natu = 0xB1,
+    Xatu = 0xB2,
+    Mareep = 0xB3,
+    Flaaffy = 0xB4,
+    Ampharos = 0xB5,
+    Bellossom = 0xB6,
+    Marill = 0xB7,
+    Azumarill = 0xB8,
+    Sudowoodo = 0xB9,
+    Politoed = 0xBA,
+    Hoppip = 0xBB,
+    Skiploom = 0xBC,
+    Jumpluff = 0xBD,
+    Aipom = 0xBE,
+    Sunkern = 0xBF,
+    Sunflora = 0xC0,
+    Yanma = 0xC1,
+    Wooper = 0xC2,
+    Quagsire = 0xC3,
+    Espeon = 0xC4,
+    Umbreon = 0xC5,
+    Murkrow = 0xC6,
+    Slowking = 0xC7,
+    Misdreavus = 0xC8,
+    Unown = 0xC9,
+    Wobbuffet = 0xCA,
+    Girafarig = 0xCB,
+    Pineco = 0xCC,
+    Forretress = 0xCD,
+    Dunsparce = 0xCE,
+    Gligar = 0xCF,
+    Steelix = 0xD0,
+    Snubbull = 0xD1,
+    Granbull = 0xD2,
+    Qwilfish = 0xD3,
+    Scizor = 0xD4,
+    Shuckle = 0xD5,
+    Heracross = 0xD6,
+    Sneasel = 0xD7,
+    Teddiursa = 0xD8,
+    Ursaring = 0xD9,
+    Slugma = 0xDA,
+    Magcargo = 0xDB,
+    Swinub = 0xDC,
+    Piloswine = 0xDD,
+    Corsola = 0xDE,
+    Remoraid = 0xDF,
+    Octillery = 0xE0,
+    Delibird = 0xE1,
+    Mantine = 0xE2,
+    Skarmory = 0xE3,
+    Houndour = 0xE4,
+    Houndoom = 0xE5,
+    Kingdra = 0xE6,
+    Phanpy = 0xE7,
+    Donphan = 0xE8,
+    Porygon2 = 0xE9,
+    Stantler = 0xEA,
+    Smeargle = 0xEB,
+    Tyrogue = 0xEC,
+    Hitmontop = 0xED,
+    Smoochum = 0xEE,
+    Elekid = 0xEF,
+    Magby = 0xF0,
+    Miltank = 0xF1,
+    Blissey = 0xF2,
+    Raikou = 0xF3,
+    Entei = 0xF4,
+    Suicune = 0xF5,
+    Larvitar = 0xF6,
+    Pupitar = 0xF7,
+    Tyranitar = 0xF8,
+    Lugia = 0xF9,
+    HoOh = 0xFA,
+    Celebi = 0xFB,
+}
+
+/// Pokédex index of each Generation 1 internal species index, starting at 0x01. MissingNo. entries are 0.
+const GEN1_INDEX_TO_DEX: [u8; 0xBE] = [
+    112, 115, 32, 35, 21, 100, 34, 80, 2, 103, 108, 102, 88, 94, 29, 31,
+    104, 111, 131, 59, 151, 130, 90, 72, 92, 123, 120, 9, 127, 114, 0, 0,
+    58, 95, 22, 16, 79, 64, 75, 113, 67, 122, 106, 107, 24, 47, 54, 96,
+    76, 0, 126, 0, 125, 82, 109, 0, 56, 86, 50, 128, 0, 0, 0, 83,
+    48, 149, 0, 0, 0, 84, 60, 124, 146, 144, 145, 132, 52, 98, 0, 0,
+    0, 37, 38, 25, 26, 0, 0, 147, 148, 140, 141, 116, 117, 0, 0, 27,
+    28, 138, 139, 39, 40, 133, 136, 135, 134, 66, 41, 23, 46, 61, 62, 13,
+    14, 15, 0, 85, 57, 51, 49, 87, 0, 0, 10, 11, 12, 68, 0, 55,
+    97, 42, 150, 143, 129, 0, 0, 89, 0, 99, 91, 0, 101, 36, 110, 53,
+    105, 0, 93, 63, 65, 17, 18, 121, 1, 3, 73, 0, 118, 119, 0, 0,
+    0, 0, 77, 78, 19, 20, 33, 30, 74, 137, 142, 0, 81, 0, 0, 4,
+    7, 5, 8, 6, 0, 0, 0, 0, 43, 44, 45, 69, 70, 71,
+];
+
+impl Species {
+    /// Convert a Pokédex index to its equivalent `Species`.
+    ///
+    /// This is also the species index used in Generation 2.
+    ///
+    /// Returns `None` if there is no `Species` value.
+    pub const fn from_dex(value: u8) -> Option<Self> {
+        let species = match value {
+            0x01 => Self::Bulbasaur,
+            0x02 => Self::Ivysaur,
+            0x03 => Self::Venusaur,
+            0x04 => Self::Charmander,
+            0x05 => Self::Charmeleon,
+            0x06 => Self::Charizard,
+            0x07 => Self::Squirtle,
+            0x08 => Self::Wartortle,
+            0x09 => Self::Blastoise,
+            0x0A => Self::Caterpie,
+            0x0B => Self::Metapod,
+            0x0C => Self::Butterfree,
+            0x0D => Self::Weedle,
+            0x0E => Self::Kakuna,
+            0x0F => Self::Beedrill,
+            0x10 => Self::Pidgey,
+            0x11 => Self::Pidgeotto,
+            0x12 => Self::Pidgeot,
+            0x13 => Self::Rattata,
+            0x14 => Self::Raticate,
+            0x15 => Self::Spearow,
+            0x16 => Self::Fearow,
+            0x17 => Self::Ekans,
+            0x18 => Self::Arbok,
+            0x19 => Self::Pikachu,
+            0x1A => Self::Raichu,
+            0x1B => Self::Sandshrew,
+            0x1C => Self::Sandslash,
+            0x1D => Self::NidoranF,
+            0x1E => Self::Nidorina,
+            0x1F => Self::Nidoqueen,
+            0x20 => Self::NidoranM,
+            0x21 => Self::Nidorino,
+            0x22 => Self::Nidoking,
+            0x23 => Self::Clefairy,
+            0x24 => Self::Clefable,
+            0x25 => Self::Vulpix,
+            0x26 => Self::Ninetales,
+            0x27 => Self::Jigglypuff,
+            0x28 => Self::Wigglytuff,
+            0x29 => Self::Zubat,
+            0x2A => Self::Golbat,
+            0x2B => Self::Oddish,
+            0x2C => Self::Gloom,
+            0x2D => Self::Vileplume,
+            0x2E => Self::Paras,
+            0x2F => Self::Parasect,
+            0x30 => Self::Venonat,
+            0x31 => Self::Venomoth,
+            0x32 => Self::Diglett,
+            0x33 => Self::Dugtrio,
+            0x34 => Self::Meowth,
+            0x35 => Self::Persian,
+            0x36 => Self::Psyduck,
+            0x37 => Self::Golduck,
+            0x38 => Self::Mankey,
+            0x39 => Self::Primeape,
+            0x3A => Self::Growlithe,
+            0x3B => Self::Arcanine,
+            0x3C => Self::Poliwag,
+            0x3D => Self::Poliwhirl,
+            0x3E => Self::Poliwrath,
+            0x3F => Self::Abra,
+            0x40 => Self::Kadabra,
+            0x41 => Self::Alakazam,
+            0x42 => Self::Machop,
+            0x43 => Self::Machoke,
+            0x44 => Self::Machamp,
+            0x45 => Self::Bellsprout,
+            0x46 => Self::Weepinbell,
+            0x47 => Self::Victreebel,
+            0x48 => Self::Tentacool,
+            0x49 => Self::Tentacruel,
+            0x4A => Self::Geodude,
+            0x4B => Self::Graveler,
+            0x4C => Self::Golem,
+            0x4D => Self::Ponyta,
+            0x4E => Self::Rapidash,
+            0x4F => Self::Slowpoke,
+            0x50 => Self::Slowbro,
+            0x51 => Self::Magnemite,
+            0x52 => Self::Magneton,
+            0x53 => Self::Farfetchd,
+            0x54 => Self::Doduo,
+            0x55 => Self::Dodrio,
+            0x56 => Self::Seel,
+            0x57 => Self::Dewgong,
+            0x58 => Self::Grimer,
+            0x59 => Self::Muk,
+            0x5A => Self::Shellder,
+            0x5B => Self::Cloyster,
+            0x5C => Self::Gastly,
+            0x5D => Self::Haunter,
+            0x5E => Self::Gengar,
+            0x5F => Self::Onix,
+            0x60 => Self::Drowzee,
+            0x61 => Self::Hypno,
+            0x62 => Self::Krabby,
+            0x63 => Self::Kingler,
+            0x64 => Self::Voltorb,
+            0x65 => Self::Electrode,
+            0x66 => Self::Exeggcute,
+            0x67 => Self::Exeggutor,
+            0x68 => Self::Cubone,
+            0x69 => Self::Marowak,
+            0x6A => Self::Hitmonlee,
+            0x6B => Self::Hitmonchan,
+            0x6C => Self::Lickitung,
+            0x6D => Self::Koffing,
+            0x6E => Self::Weezing,
+            0x6F => Self::Rhyhorn,
+            0x70 => Self::Rhydon,
+            0x71 => Self::Chansey,
+            0x72 => Self::Tangela,
+            0x73 => Self::Kangaskhan,
+            0x74 => Self::Horsea,
+            0x75 => Self::Seadra,
+            0x76 => Self::Goldeen,
+            0x77 => Self::Seaking,
+            0x78 => Self::Staryu,
+            0x79 => Self::Starmie,
+            0x7A => Self::MrMime,
+            0x7B => Self::Scyther,
+            0x7C => Self::Jynx,
+            0x7D => Self::Electabuzz,
+            0x7E => Self::Magmar,
+            0x7F => Self::Pinsir,
+            0x80 => Self::Tauros,
+            0x81 => Self::Magikarp,
+            0x82 => Self::Gyarados,
+            0x83 => Self::Lapras,
+            0x84 => Self::Ditto,
+            0x85 => Self::Eevee,
+            0x86 => Self::Vaporeon,
+            0x87 => Self::Jolteon,
+            0x88 => Self::Flareon,
+            0x89 => Self::Porygon,
+            0x8A => Self::Omanyte,
+            0x8B => Self::Omastar,
+            0x8C => Self::Kabuto,
+            0x8D => Self::Kabutops,
+            0x8E => Self::Aerodactyl,
+            0x8F => Self::Snorlax,
+            0x90 => Self::Articuno,
+            0x91 => Self::Zapdos,
+            0x92 => Self::Moltres,
+            0x93 => Self::Dratini,
+            0x94 => Self::Dragonair,
+            0x95 => Self::Dragonite,
+            0x96 => Self::Mewtwo,
+            0x97 => Self::Mew,
+            0x98 => Self::Chikorita,
+            0x99 => Self::Bayleef,
+            0x9A => Self::Meganium,
+            0x9B => Self::Cyndaquil,
+            0x9C => Self::Quilava,
+            0x9D => Self::Typhlosion,
+            0x9E => Self::Totodile,
+            0x9F => Self::Croconaw,
+            0xA0 => Self::Feraligatr,
+            0xA1 => Self::Sentret,
+            0xA2 => Self::Furret,
+            0xA3 => Self::Hoothoot,
+            0xA4 => Self::Noctowl,
+            0xA5 => Self::Ledyba,
+            0xA6 => Self::Ledian,
+            0xA7 => Self::Spinarak,
+            0xA8 => Self::Ariados,
+            0xA9 => Self::Crobat,
+            0xAA => Self::Chinchou,
+            0xAB => Self::Lanturn,
+            0xAC => Self::Pichu,
+            0xAD => Self::Cleffa,
+            0xAE => Self::Igglybuff,
+            0xAF => Self::Togepi,
+            0xB0 => Self::Togetic,
+            0xB1 => Self::Natu,
+            0xB2 => Self::Xatu,
+            0xB3 => Self::Mareep,
+            0xB4 => Self::Flaaffy,
+            0xB5 => Self::Ampharos,
+            0xB6 => Self::Bellossom,
+            0xB7 => Self::Marill,
+            0xB8 => Self::Azumarill,
+            0xB9 => Self::Sudowoodo,
+            0xBA => Self::Politoed,
+            0xBB => Self::Hoppip,
+            0xBC => Self::Skiploom,
+            0xBD => Self::Jumpluff,
+            0xBE => Self::Aipom,
+            0xBF => Self::Sunkern,
+            0xC0 => Self::Sunflora,
+            0xC1 => Self::Yanma,
+            0xC2 => Self::Wooper,
+            0xC3 => Self::Quagsire,
+            0xC4 => Self::Espeon,
+            0xC5 => Self::Umbreon,
+            0xC6 => Self::Murkrow,
+            0xC7 => Self::Slowking,
+            0xC8 => Self::Misdreavus,
+            0xC9 => Self::Unown,
+            0xCA => Self::Wobbuffet,
+            0xCB => Self::Girafarig,
+            0xCC => Self::Pineco,
+            0xCD => Self::Forretress,
+            0xCE => Self::Dunsparce,
+            0xCF => Self::Gligar,
+            0xD0 => Self::Steelix,
+            0xD1 => Self::Snubbull,
+            0xD2 => Self::Granbull,
+            0xD3 => Self::Qwilfish,
+            0xD4 => Self::Scizor,
+            0xD5 => Self::Shuckle,
+            0xD6 => Self::Heracross,
+            0xD7 => Self::Sneasel,
+            0xD8 => Self::Teddiursa,
+            0xD9 => Self::Ursaring,
+            0xDA => Self::Slugma,
+            0xDB => Self::Magcargo,
+            0xDC => Self::Swinub,
+            0xDD => Self::Piloswine,
+            0xDE => Self::Corsola,
+            0xDF => Self::Remoraid,
+            0xE0 => Self::Octillery,
+            0xE1 => Self::Delibird,
+            0xE2 => Self::Mantine,
+            0xE3 => Self::Skarmory,
+            0xE4 => Self::Houndour,
+            0xE5 => Self::Houndoom,
+            0xE6 => Self::Kingdra,
+            0xE7 => Self::Phanpy,
+            0xE8 => Self::Donphan,
+            0xE9 => Self::Porygon2,
+            0xEA => Self::Stantler,
+            0xEB => Self::Smeargle,
+            0xEC => Self::Tyrogue,
+            0xED => Self::Hitmontop,
+            0xEE => Self::Smoochum,
+            0xEF => Self::Elekid,
+            0xF0 => Self::Magby,
+            0xF1 => Self::Miltank,
+            0xF2 => Self::Blissey,
+            0xF3 => Self::Raikou,
+            0xF4 => Self::Entei,
+            0xF5 => Self::Suicune,
+            0xF6 => Self::Larvitar,
+            0xF7 => Self::Pupitar,
+            0xF8 => Self::Tyranitar,
+            0xF9 => Self::Lugia,
+            0xFA => Self::HoOh,
+            0xFB => Self::Celebi,
+            _ => return None
+        };
+        Some(species)
+    }
+
+    /// Convert a Generation 1 internal species index to its equivalent `Species`.
+    ///
+    /// Returns `None` if the index is unused (i.e. MissingNo.).
+    pub const fn from_gen1_index(value: u8) -> Option<Self> {
+        if value == 0 || value as usize > GEN1_INDEX_TO_DEX.len() {
+            return None
+        }
+        Self::from_dex(GEN1_INDEX_TO_DEX[value as usize - 1])
+    }
+
+    /// Convert a species index for the given generation to its equivalent `Species`.
+    pub const fn from_index(generation: Generation, value: u8) -> Option<Self> {
+        match generation {
+            Generation::One => Self::from_gen1_index(value),
+            Generation::Two => Self::from_dex(value)
+        }
+    }
+
+    /// Get the Pokédex index of the species.
+    pub const fn dex(self) -> u8 {
+        self as u8
+    }
+
+    /// Get the Generation 1 internal species index, or `None` if the species is not in Generation 1.
+    pub const fn gen1_index(self) -> Option<u8> {
+        let mut i = 0;
+        while i < GEN1_INDEX_TO_DEX.len() {
+            if GEN1_INDEX_TO_DEX[i] == self.dex() {
+                return Some(i as u8 + 1)
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Get the English name of the species.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bulbasaur => "BULBASAUR",
+            Self::Ivysaur => "IVYSAUR",
+            Self::Venusaur => "VENUSAUR",
+            Self::Charmander => "CHARMANDER",
+            Self::Charmeleon => "CHARMELEON",
+            Self::Charizard => "CHARIZARD",
+            Self::Squirtle => "SQUIRTLE",
+            Self::Wartortle => "WARTORTLE",
+            Self::Blastoise => "BLASTOISE",
+            Self::Caterpie => "CATERPIE",
+            Self::Metapod => "METAPOD",
+            Self::Butterfree => "BUTTERFREE",
+            Self::Weedle => "WEEDLE",
+            Self::Kakuna => "KAKUNA",
+            Self::Beedrill => "BEEDRILL",
+            Self::Pidgey => "PIDGEY",
+            Self::Pidgeotto => "PIDGEOTTO",
+            Self::Pidgeot => "PIDGEOT",
+            Self::Rattata => "RATTATA",
+            Self::Raticate => "RATICATE",
+            Self::Spearow => "SPEAROW",
+            Self::Fearow => "FEAROW",
+            Self::Ekans => "EKANS",
+            Self::Arbok => "ARBOK",
+            Self::Pikachu => "PIKACHU",
+            Self::Raichu => "RAICHU",
+            Self::Sandshrew => "SANDSHREW",
+            Self::Sandslash => "SANDSLASH",
+            Self::NidoranF => "NIDORAN♀",
+            Self::Nidorina => "NIDORINA",
+            Self::Nidoqueen => "NIDOQUEEN",
+            Self::NidoranM => "NIDORAN♂",
+            Self::Nidorino => "NIDORINO",
+            Self::Nidoking => "NIDOKING",
+            Self::Clefairy => "CLEFAIRY",
+            Self::Clefable => "CLEFABLE",
+            Self::Vulpix => "VULPIX",
+            Self::Ninetales => "NINETALES",
+            Self::Jigglypuff => "JIGGLYPUFF",
+            Self::Wigglytuff => "WIGGLYTUFF",
+            Self::Zubat => "ZUBAT",
+            Self::Golbat => "GOLBAT",
+            Self::Oddish => "ODDISH",
+            Self::Gloom => "GLOOM",
+            Self::Vileplume => "VILEPLUME",
+            Self::Paras => "PARAS",
+            Self::Parasect => "PARASECT",
+            Self::Venonat => "VENONAT",
+            Self::Venomoth => "VENOMOTH",
+            Self::Diglett => "DIGLETT",
+            Self::Dugtrio => "DUGTRIO",
+            Self::Meowth => "MEOWTH",
+            Self::Persian => "PERSIAN",
+            Self::Psyduck => "PSYDUCK",
+            Self::Golduck => "GOLDUCK",
+            Self::Mankey => "MANKEY",
+            Self::Primeape => "PRIMEAPE",
+            Self::Growlithe => "GROWLITHE",
+            Self::Arcanine => "ARCANINE",
+            Self::Poliwag => "POLIWAG",
+            Self::Poliwhirl => "POLIWHIRL",
+            Self::Poliwrath => "POLIWRATH",
+            Self::Abra => "ABRA",
+            Self::Kadabra => "KADABRA",
+            Self::Alakazam => "ALAKAZAM",
+            Self::Machop => "MACHOP",
+            Self::Machoke => "MACHOKE",
+            Self::Machamp => "MACHAMP",
+            Self::Bellsprout => "BELLSPROUT",
+            Self::Weepinbell => "WEEPINBELL",
+            Self::Victreebel => "VICTREEBEL",
+            Self::Tentacool => "TENTACOOL",
+            Self::Tentacruel => "TENTACRUEL",
+            Self::Geodude => "GEODUDE",
+            Self::Graveler => "GRAVELER",
+            Self::Golem => "GOLEM",
+            Self::Ponyta => "PONYTA",
+            Self::Rapidash => "RAPIDASH",
+            Self::Slowpoke => "SLOWPOKE",
+            Self::Slowbro => "SLOWBRO",
+            Self::Magnemite => "MAGNEMITE",
+            Self::Magneton => "MAGNETON",
+            Self::Farfetchd => "FARFETCH'D",
+            Self::Doduo => "DODUO",
+            Self::Dodrio => "DODRIO",
+            Self::Seel => "SEEL",
+            Self::Dewgong => "DEWGONG",
+            Self::Grimer => "GRIMER",
+            Self::Muk => "MUK",
+            Self::Shellder => "SHELLDER",
+            Self::Cloyster => "CLOYSTER",
+            Self::Gastly => "GASTLY",
+            Self::Haunter => "HAUNTER",
+            Self::Gengar => "GENGAR",
+            Self::Onix => "ONIX",
+            Self::Drowzee => "DROWZEE",
+            Self::Hypno => "HYPNO",
+            Self::Krabby => "KRABBY",
+            Self::Kingler => "KINGLER",
+            Self::Voltorb => "VOLTORB",
+            Self::Electrode => "ELECTRODE",
+            Self::Exeggcute => "EXEGGCUTE",
+            Self::Exeggutor => "EXEGGUTOR",
+            Self::Cubone => "CUBONE",
+            Self::Marowak => "MAROWAK",
+            Self::Hitmonlee => "HITMONLEE",
+            Self::Hitmonchan => "HITMONCHAN",
+            Self::Lickitung => "LICKITUNG",
+            Self::Koffing => "KOFFING",
+            Self::Weezing => "WEEZING",
+            Self::Rhyhorn => "RHYHORN",
+            Self::Rhydon => "RHYDON",
+            Self::Chansey => "CHANSEY",
+            Self::Tangela => "TANGELA",
+            Self::Kangaskhan => "KANGASKHAN",
+            Self::Horsea => "HORSEA",
+            Self::Seadra => "SEADRA",
+            Self::Goldeen => "GOLDEEN",
+            Self::Seaking => "SEAKING",
+            Self::Staryu => "STARYU",
+            Self::Starmie => "STARMIE",
+            Self::MrMime => "MR.MIME",
+            Self::Scyther => "SCYTHER",
+            Self::Jynx => "JYNX",
+            Self::Electabuzz => "ELECTABUZZ",
+            Self::Magmar => "MAGMAR",
+            Self::Pinsir => "PINSIR",
+            Self::Tauros => "TAUROS",
+            Self::Magikarp => "MAGIKARP",
+            Self::Gyarados => "GYARADOS",
+            Self::Lapras => "LAPRAS",
+            Self::Ditto => "DITTO",
+            Self::Eevee => "EEVEE",
+            Self::Vaporeon => "VAPOREON",
+            Self::Jolteon => "JOLTEON",
+            Self::Flareon => "FLAREON",
+            Self::Porygon => "PORYGON",
+            Self::Omanyte => "OMANYTE",
+            Self::Omastar => "OMASTAR",
+            Self::Kabuto => "KABUTO",
+            Self::Kabutops => "KABUTOPS",
+            Self::Aerodactyl => "AERODACTYL",
+            Self::Snorlax => "SNORLAX",
+            Self::Articuno => "ARTICUNO",
+            Self::Zapdos => "ZAPDOS",
+            Self::Moltres => "MOLTRES",
+            Self::Dratini => "DRATINI",
+            Self::Dragonair => "DRAGONAIR",
+            Self::Dragonite => "DRAGONITE",
+            Self::Mewtwo => "MEWTWO",
+            Self::Mew => "MEW",
+            Self::Chikorita => "CHIKORITA",
+            Self::Bayleef => "BAYLEEF",
+            Self::Meganium => "MEGANIUM",
+            Self::Cyndaquil => "CYNDAQUIL",
+            Self::Quilava => "QUILAVA",
+            Self::Typhlosion => "TYPHLOSION",
+            Self::Totodile => "TOTODILE",
+            Self::Croconaw => "CROCONAW",
+            Self::Feraligatr => "FERALIGATR",
+            Self::Sentret => "SENTRET",
+            Self::Furret => "FURRET",
+            Self::Hoothoot => "HOOTHOOT",
+            Self::Noctowl => "NOCTOWL",
+            Self::Ledyba => "LEDYBA",
+            Self::Ledian => "LEDIAN",
+            Self::Spinarak => "SPINARAK",
+            Self::Ariados => "ARIADOS",
+            Self::Crobat => "CROBAT",
+            Self::Chinchou => "CHINCHOU",
+            Self::Lanturn => "LANTURN",
+            Self::Pichu => "PICHU",
+            Self::Cleffa => "CLEFFA",
+            Self::Igglybuff => "IGGLYBUFF",
+            Self::Togepi => "TOGEPI",
+            Self::Togetic => "TOGETIC",
+            Self::Natu => "NATU",
+            Self::Xatu => "XATU",
+            Self::Mareep => "MAREEP",
+            Self::Flaaffy => "FLAAFFY",
+            Self::Ampharos => "AMPHAROS",
+            Self::Bellossom => "BELLOSSOM",
+            Self::Marill => "MARILL",
+            Self::Azumarill => "AZUMARILL",
+            Self::Sudowoodo => "SUDOWOODO",
+            Self::Politoed => "POLITOED",
+            Self::Hoppip => "HOPPIP",
+            Self::Skiploom => "SKIPLOOM",
+            Self::Jumpluff => "JUMPLUFF",
+            Self::Aipom => "AIPOM",
+            Self::Sunkern => "SUNKERN",
+            Self::Sunflora => "SUNFLORA",
+            Self::Yanma => "YANMA",
+            Self::Wooper => "WOOPER",
+            Self::Quagsire => "QUAGSIRE",
+            Self::Espeon => "ESPEON",
+            Self::Umbreon => "UMBREON",
+            Self::Murkrow => "MURKROW",
+            Self::Slowking => "SLOWKING",
+            Self::Misdreavus => "MISDREAVUS",
+            Self::Unown => "UNOWN",
+            Self::Wobbuffet => "WOBBUFFET",
+            Self::Girafarig => "GIRAFARIG",
+            Self::Pineco => "PINECO",
+            Self::Forretress => "FORRETRESS",
+            Self::Dunsparce => "DUNSPARCE",
+            Self::Gligar => "GLIGAR",
+            Self::Steelix => "STEELIX",
+            Self::Snubbull => "SNUBBULL",
+            Self::Granbull => "GRANBULL",
+            Self::Qwilfish => "QWILFISH",
+            Self::Scizor => "SCIZOR",
+            Self::Shuckle => "SHUCKLE",
+            Self::Heracross => "HERACROSS",
+            Self::Sneasel => "SNEASEL",
+            Self::Teddiursa => "TEDDIURSA",
+            Self::Ursaring => "URSARING",
+            Self::Slugma => "SLUGMA",
+            Self::Magcargo => "MAGCARGO",
+            Self::Swinub => "SWINUB",
+            Self::Piloswine => "PILOSWINE",
+            Self::Corsola => "CORSOLA",
+            Self::Remoraid => "REMORAID",
+            Self::Octillery => "OCTILLERY",
+            Self::Delibird => "DELIBIRD",
+            Self::Mantine => "MANTINE",
+            Self::Skarmory => "SKARMORY",
+            Self::Houndour => "HOUNDOUR",
+            Self::Houndoom => "HOUNDOOM",
+            Self::Kingdra => "KINGDRA",
+            Self::Phanpy => "PHANPY",
+            Self::Donphan => "DONPHAN",
+            Self::Porygon2 => "PORYGON2",
+            Self::Stantler => "STANTLER",
+            Self::Smeargle => "SMEARGLE",
+            Self::Tyrogue => "TYROGUE",
+            Self::Hitmontop => "HITMONTOP",
+            Self::Smoochum => "SMOOCHUM",
+            Self::Elekid => "ELEKID",
+            Self::Magby => "MAGBY",
+            Self::Miltank => "MILTANK",
+            Self::Blissey => "BLISSEY",
+            Self::Raikou => "RAIKOU",
+            Self::Entei => "ENTEI",
+            Self::Suicune => "SUICUNE",
+            Self::Larvitar => "LARVITAR",
+            Self::Pupitar => "PUPITAR",
+            Self::Tyranitar => "TYRANITAR",
+            Self::Lugia => "LUGIA",
+            Self::HoOh => "HO-OH",
+            Self::Celebi => "CELEBI",
+        }
+    }
+}
@@ -0,0 +1,296 @@
+/// Terminates strings in the games' text encoding.
+pub const TEXT_TERMINATOR: u8 = 0x50;
+
+/// Language of a Generation 1 or 2 game.
+///
+/// Each language has its own character map.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Japanese
+}
+
+impl Language {
+    pub const ALL: [Language; 6] = [
+        Self::English,
+        Self::French,
+        Self::German,
+        Self::Spanish,
+        Self::Italian,
+        Self::Japanese
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::French => "French",
+            Self::German => "German",
+            Self::Spanish => "Spanish",
+            Self::Italian => "Italian",
+            Self::Japanese => "Japanese"
+        }
+    }
+
+    /// Get the in-game names of moves 1 and 2 (Pound and Karate Chop) in this language.
+    ///
+    /// These start the move name table, so they are used to find it in the ROM.
+    pub const fn first_move_names(self) -> [&'static str; 2] {
+        match self {
+            Self::English => ["POUND", "KARATE CHOP"],
+            Self::French => ["ECRAS'FACE", "POING-KARATE"],
+            Self::German => ["PFUND", "KARATESCHLAG"],
+            Self::Spanish => ["DESTRUCTOR", "GOLPE KARATE"],
+            Self::Italian => ["BOTTA", "COLPO KARATE"],
+            Self::Japanese => ["はたく", "からてチョップ"]
+        }
+    }
+}
+
+/// Get the character for a byte in the games' text encoding for the given language.
+///
+/// Returns `None` for control codes and unmapped characters.
+const fn decode_char(language: Language, value: u8) -> Option<&'static str> {
+    match language {
+        Language::English => decode_char_english(value),
+        Language::French | Language::German => match decode_char_french_german(value) {
+            Some(c) => Some(c),
+            None => decode_char_western(value)
+        },
+        Language::Spanish | Language::Italian => match decode_char_spanish_italian(value) {
+            Some(c) => Some(c),
+            None => decode_char_western(value)
+        },
+        Language::Japanese => decode_char_japanese(value)
+    }
+}
+
+/// Characters shared by all of the non-Japanese releases.
+const fn decode_char_western(value: u8) -> Option<&'static str> {
+    const LETTERS: [&str; 26] = [
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M",
+        "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z"
+    ];
+    const LOWERCASE: [&str; 26] = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m",
+        "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z"
+    ];
+
+    let c = match value {
+        0x7F => " ",
+        0x80..=0x99 => LETTERS[(value - 0x80) as usize],
+        0x9A => "(",
+        0x9B => ")",
+        0x9C => ":",
+        0x9D => ";",
+        0x9E => "[",
+        0x9F => "]",
+        0xA0..=0xB9 => LOWERCASE[(value - 0xA0) as usize],
+        0xE0 => "'",
+        0xE1 => "PK",
+        0xE2 => "MN",
+        0xE3 => "-",
+        0xE6 => "?",
+        0xE7 => "!",
+        0xE8 => ".",
+        0xEF => "♂",
+        0xF1 => "×",
+        0xF3 => "/",
+        0xF4 => ",",
+        0xF5 => "♀",
+        0xF6..=0xFF => decode_digit(value),
+        _ => return None
+    };
+    Some(c)
+}
+
+const fn decode_digit(value: u8) -> &'static str {
+    const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    DIGITS[(value - 0xF6) as usize]
+}
+
+const fn decode_char_english(value: u8) -> Option<&'static str> {
+    let c = match value {
+        0xBA => "é",
+        0xBB => "'d",
+        0xBC => "'l",
+        0xBD => "'s",
+        0xBE => "'t",
+        0xBF => "'v",
+        0xE4 => "'r",
+        0xE5 => "'m",
+        _ => return decode_char_western(value)
+    };
+    Some(c)
+}
+
+const fn decode_char_french_german(value: u8) -> Option<&'static str> {
+    let c = match value {
+        0xBA => "à",
+        0xBB => "è",
+        0xBC => "é",
+        0xBD => "ù",
+        0xBE => "ß",
+        0xBF => "ç",
+        0xC0 => "Ä",
+        0xC1 => "Ö",
+        0xC2 => "Ü",
+        0xC3 => "ä",
+        0xC4 => "ö",
+        0xC5 => "ü",
+        0xC6 => "ë",
+        0xC7 => "ï",
+        0xC8 => "â",
+        0xC9 => "ô",
+        0xCA => "û",
+        0xCB => "ê",
+        0xCC => "î",
+        0xD4 => "c'",
+        0xD5 => "d'",
+        0xD6 => "j'",
+        0xD7 => "l'",
+        0xD8 => "m'",
+        0xD9 => "n'",
+        0xDA => "p'",
+        0xDB => "s'",
+        0xDC => "'s",
+        0xDD => "t'",
+        0xDE => "u'",
+        0xDF => "y'",
+        _ => return None
+    };
+    Some(c)
+}
+
+const fn decode_char_spanish_italian(value: u8) -> Option<&'static str> {
+    let c = match value {
+        0xBA => "à",
+        0xBB => "è",
+        0xBC => "é",
+        0xBD => "ù",
+        0xBE => "À",
+        0xBF => "á",
+        0xC0 => "Ä",
+        0xC1 => "Ö",
+        0xC2 => "Ü",
+        0xC3 => "ä",
+        0xC4 => "ö",
+        0xC5 => "ü",
+        0xC6 => "È",
+        0xC7 => "É",
+        0xC8 => "Ì",
+        0xC9 => "Í",
+        0xCA => "Ñ",
+        0xCB => "Ò",
+        0xCC => "Ó",
+        0xCD => "Ù",
+        0xCE => "Ú",
+        0xCF => "á",
+        0xD0 => "ì",
+        0xD1 => "í",
+        0xD2 => "ñ",
+        0xD3 => "ò",
+        0xD4 => "ó",
+        0xD5 => "ú",
+        0xD6 => "º",
+        0xD7 => "&",
+        0xD8 => "'d",
+        0xD9 => "'l",
+        0xDA => "'m",
+        0xDB => "'r",
+        0xDC => "'s",
+        0xDD => "'t",
+        0xDE => "'v",
+        0xE9 => "¿",
+        0xEA => "¡",
+        _ => return None
+    };
+    Some(c)
+}
+
+const fn decode_char_japanese(value: u8) -> Option<&'static str> {
+    // ヘ and リ use the same tiles as へ and り, so they only appear in the hiragana block.
+    const KATAKANA: [&str; 49] = [
+        "ア", "イ", "ウ", "エ", "オ", "カ", "キ", "ク", "ケ", "コ", "サ", "シ", "ス", "セ", "ソ", "タ",
+        "チ", "ツ", "テ", "ト", "ナ", "ニ", "ヌ", "ネ", "ノ", "ハ", "ヒ", "フ", "ホ", "マ", "ミ", "ム",
+        "メ", "モ", "ヤ", "ユ", "ヨ", "ラ", "ル", "レ", "ロ", "ワ", "ヲ", "ン", "ッ", "ャ", "ュ", "ョ",
+        "ィ"
+    ];
+    const HIRAGANA: [&str; 50] = [
+        "あ", "い", "う", "え", "お", "か", "き", "く", "け", "こ", "さ", "し", "す", "せ", "そ", "た",
+        "ち", "つ", "て", "と", "な", "に", "ぬ", "ね", "の", "は", "ひ", "ふ", "へ", "ほ", "ま", "み",
+        "む", "め", "も", "や", "ゆ", "よ", "ら", "り", "る", "れ", "ろ", "わ", "を", "ん", "っ", "ゃ",
+        "ゅ", "ょ"
+    ];
+    const KATAKANA_DAKUTEN: [&str; 15] = [
+        "ガ", "ギ", "グ", "ゲ", "ゴ", "ザ", "ジ", "ズ", "ゼ", "ゾ", "ダ", "ヂ", "ヅ", "デ", "ド"
+    ];
+    const HIRAGANA_DAKUTEN: [&str; 15] = [
+        "が", "ぎ", "ぐ", "げ", "ご", "ざ", "じ", "ず", "ぜ", "ぞ", "だ", "ぢ", "づ", "で", "ど"
+    ];
+
+    let c = match value {
+        0x05..=0x13 => KATAKANA_DAKUTEN[(value - 0x05) as usize],
+        0x19 => "バ",
+        0x1A => "ビ",
+        0x1B => "ブ",
+        0x1C => "ボ",
+        0x26..=0x34 => HIRAGANA_DAKUTEN[(value - 0x26) as usize],
+        0x3A => "ば",
+        0x3B => "び",
+        0x3C => "ぶ",
+        0x3D => "べ",
+        0x3E => "ぼ",
+        0x40 => "パ",
+        0x41 => "ピ",
+        0x42 => "プ",
+        0x43 => "ポ",
+        0x44 => "ぱ",
+        0x45 => "ぴ",
+        0x46 => "ぷ",
+        0x47 => "ぺ",
+        0x48 => "ぽ",
+        0x7F => " ",
+        0x80..=0xB0 => KATAKANA[(value - 0x80) as usize],
+        0xB1..=0xE2 => HIRAGANA[(value - 0xB1) as usize],
+        0xE3 => "ー",
+        0xE6 => "？",
+        0xE7 => "！",
+        0xE8 => "。",
+        0xEF => "♂",
+        0xF5 => "♀",
+        0xF6..=0xFF => decode_digit(value),
+        _ => return None
+    };
+    Some(c)
+}
+
+/// Decode a string in the games' text encoding, stopping at the terminator if there is one.
+///
+/// Returns `None` if it contains any control codes or unmapped characters.
+pub fn decode_text(language: Language, data: &[u8]) -> Option<String> {
+    data.iter()
+        .take_while(|&&b| b != TEXT_TERMINATOR)
+        .map(|&b| decode_char(language, b))
+        .collect()
+}
+
+/// Encode a string into the games' text encoding, without a terminator.
+///
+/// Returns `None` if it contains characters that cannot be encoded.
+pub fn encode_text(language: Language, text: &str) -> Option<Vec<u8>> {
+    let mut data = Vec::with_capacity(text.len());
+    let mut remaining = text;
+    while !remaining.is_empty() {
+        // Prefer the longest match so ligatures like 'd are used where the games would use them.
+        let (byte, len) = (0x00..=u8::MAX)
+            .filter_map(|b| decode_char(language, b).filter(|c| remaining.starts_with(c)).map(|c| (b, c.len())))
+            .max_by_key(|&(_, len)| len)?;
+        data.push(byte);
+        remaining = &remaining[len..];
+    }
+    Some(data)
+}
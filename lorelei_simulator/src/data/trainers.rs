@@ -0,0 +1,646 @@
+use std::fmt::{Display, Formatter};
+use super::{item_name, Generation};
+
+const FULL_RESTORE: u8 = 0x10;
+const HYPER_POTION: u8 = 0x12;
+const SUPER_POTION: u8 = 0x13;
+const POTION: u8 = 0x14;
+const FULL_HEAL: u8 = 0x34;
+const GUARD_SPEC: u8 = 0x37;
+const X_ATTACK: u8 = 0x41;
+const X_DEFEND: u8 = 0x42;
+const X_SPEED: u8 = 0x43;
+
+/// What a Gen 1 trainer's AI does when it acts instead of attacking.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TrainerAiActionKind {
+    /// Use the item with the given index.
+    Item(u8),
+
+    /// Switch to another Pokémon if there is one left.
+    Switch
+}
+
+/// Something a Gen 1 trainer's AI may do instead of attacking.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TrainerAiAction {
+    pub kind: TrainerAiActionKind,
+
+    /// Approximate chance (in percent) of acting on a given turn when the condition is met
+    pub chance: u8,
+
+    /// Only act when the current HP is below `1 / hp_below` of max HP
+    pub hp_below: Option<u8>,
+
+    /// Only act when the Pokémon has a non-volatile status condition
+    pub requires_status: bool
+}
+
+const fn item(item: u8, chance: u8, hp_below: Option<u8>) -> TrainerAiAction {
+    TrainerAiAction { kind: TrainerAiActionKind::Item(item), chance, hp_below, requires_status: false }
+}
+
+const fn switch(chance: u8, hp_below: Option<u8>) -> TrainerAiAction {
+    TrainerAiAction { kind: TrainerAiActionKind::Switch, chance, hp_below, requires_status: false }
+}
+
+/// AI behavior of a Gen 1 trainer class.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Gen1TrainerClass {
+    pub index: u8,
+    pub name: &'static str,
+
+    /// Which move choice modification layers (1-3) the AI applies when picking its move
+    pub move_choice_layers: &'static [u8],
+
+    /// How many times the AI can act instead of attacking
+    pub ai_uses: u8,
+
+    /// Actions the AI checks for, in order
+    pub ai_actions: &'static [TrainerAiAction]
+}
+
+impl Gen1TrainerClass {
+    /// Get the trainer class with the given index.
+    ///
+    /// Returns `None` if there is no trainer class at that index.
+    pub const fn from_u8(index: u8) -> Option<&'static Self> {
+        if index == 0 || index as usize > GEN1_TRAINER_CLASSES.len() {
+            return None
+        }
+        Some(&GEN1_TRAINER_CLASSES[index as usize - 1])
+    }
+
+    /// Whether or not the AI ever uses items.
+    pub fn uses_items(&self) -> bool {
+        self.ai_actions.iter().any(|a| matches!(a.kind, TrainerAiActionKind::Item(_)))
+    }
+}
+
+impl Display for TrainerAiAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            TrainerAiActionKind::Item(item) => f.write_str(item_name(Generation::One, item).unwrap_or("???"))?,
+            TrainerAiActionKind::Switch => f.write_str("switch")?
+        }
+
+        let mut conditions = Vec::new();
+        if self.chance < 100 {
+            conditions.push(format!("{}%", self.chance));
+        }
+        if let Some(fraction) = self.hp_below {
+            conditions.push(format!("HP below 1/{fraction}"));
+        }
+        if self.requires_status {
+            conditions.push("when statused".to_owned());
+        }
+        if !conditions.is_empty() {
+            write!(f, " ({})", conditions.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Gen1TrainerClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.name)?;
+
+        match self.move_choice_layers {
+            [] => f.write_str("no move choice layers")?,
+            [layer] => write!(f, "uses layer {layer}")?,
+            [first, .., last] if (last - first) as usize + 1 == self.move_choice_layers.len() => {
+                write!(f, "uses layers {first}-{last}")?
+            },
+            layers => {
+                f.write_str("uses layers ")?;
+                for (i, layer) in layers.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{layer}")?;
+                }
+            }
+        }
+
+        if !self.uses_items() {
+            f.write_str(", no items")?;
+        }
+
+        if !self.ai_actions.is_empty() {
+            write!(f, ", up to {}x ", self.ai_uses)?;
+            for (i, action) in self.ai_actions.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" or ")?;
+                }
+                write!(f, "{action}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What kind of opponent a trainer class is.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TrainerCategory {
+    Trainer,
+    GymLeader,
+    EliteFour,
+    Champion,
+    Rival
+}
+
+impl TrainerCategory {
+    /// Get the name of the category, or `None` for regular trainers.
+    pub const fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Trainer => None,
+            Self::GymLeader => Some("Gym Leader"),
+            Self::EliteFour => Some("Elite Four"),
+            Self::Champion => Some("Champion"),
+            Self::Rival => Some("Rival")
+        }
+    }
+}
+
+/// Get the name and category of the trainer class with the given index.
+///
+/// Gen 2 trainers that are the only member of their class are named after themselves rather than the in-game class
+/// name (e.g. FALKNER instead of LEADER). Returns `None` if there is no trainer class at that index.
+pub const fn trainer_class(generation: Generation, index: u8) -> Option<(&'static str, TrainerCategory)> {
+    match generation {
+        Generation::One => {
+            let Some(class) = Gen1TrainerClass::from_u8(index) else {
+                return None
+            };
+            let category = match index {
+                0x1D | 0x22..=0x28 => TrainerCategory::GymLeader,
+                0x21 | 0x2C | 0x2E | 0x2F => TrainerCategory::EliteFour,
+                0x2B => TrainerCategory::Champion,
+                0x19 | 0x2A => TrainerCategory::Rival,
+                _ => TrainerCategory::Trainer
+            };
+            Some((class.name, category))
+        },
+        Generation::Two => {
+            if index == 0 || index as usize > GEN2_TRAINER_CLASSES.len() {
+                return None
+            }
+            Some(GEN2_TRAINER_CLASSES[index as usize - 1])
+        }
+    }
+}
+
+/// Gen 2 trainer class names, starting at class 0x01, following the class constants in the pokecrystal
+/// disassembly.
+const GEN2_TRAINER_CLASSES: [(&str, TrainerCategory); 67] = [
+    ("FALKNER", TrainerCategory::GymLeader),
+    ("WHITNEY", TrainerCategory::GymLeader),
+    ("BUGSY", TrainerCategory::GymLeader),
+    ("MORTY", TrainerCategory::GymLeader),
+    ("PRYCE", TrainerCategory::GymLeader),
+    ("JASMINE", TrainerCategory::GymLeader),
+    ("CHUCK", TrainerCategory::GymLeader),
+    ("CLAIR", TrainerCategory::GymLeader),
+    ("RIVAL", TrainerCategory::Rival),
+    ("PROF.OAK", TrainerCategory::Trainer),
+    ("WILL", TrainerCategory::EliteFour),
+    ("CAL", TrainerCategory::Trainer),
+    ("BRUNO", TrainerCategory::EliteFour),
+    ("KAREN", TrainerCategory::EliteFour),
+    ("KOGA", TrainerCategory::EliteFour),
+    ("LANCE", TrainerCategory::Champion),
+    ("BROCK", TrainerCategory::GymLeader),
+    ("MISTY", TrainerCategory::GymLeader),
+    ("LT.SURGE", TrainerCategory::GymLeader),
+    ("SCIENTIST", TrainerCategory::Trainer),
+    ("ERIKA", TrainerCategory::GymLeader),
+    ("YOUNGSTER", TrainerCategory::Trainer),
+    ("SCHOOLBOY", TrainerCategory::Trainer),
+    ("BIRD KEEPER", TrainerCategory::Trainer),
+    ("LASS", TrainerCategory::Trainer),
+    ("JANINE", TrainerCategory::GymLeader),
+    ("COOLTRAINER♂", TrainerCategory::Trainer),
+    ("COOLTRAINER♀", TrainerCategory::Trainer),
+    ("BEAUTY", TrainerCategory::Trainer),
+    ("POKéMANIAC", TrainerCategory::Trainer),
+    ("ROCKET", TrainerCategory::Trainer),
+    ("GENTLEMAN", TrainerCategory::Trainer),
+    ("SKIER", TrainerCategory::Trainer),
+    ("TEACHER", TrainerCategory::Trainer),
+    ("SABRINA", TrainerCategory::GymLeader),
+    ("BUG CATCHER", TrainerCategory::Trainer),
+    ("FISHER", TrainerCategory::Trainer),
+    ("SWIMMER♂", TrainerCategory::Trainer),
+    ("SWIMMER♀", TrainerCategory::Trainer),
+    ("SAILOR", TrainerCategory::Trainer),
+    ("SUPER NERD", TrainerCategory::Trainer),
+    ("RIVAL", TrainerCategory::Rival),
+    ("GUITARIST", TrainerCategory::Trainer),
+    ("HIKER", TrainerCategory::Trainer),
+    ("BIKER", TrainerCategory::Trainer),
+    ("BLAINE", TrainerCategory::GymLeader),
+    ("BURGLAR", TrainerCategory::Trainer),
+    ("FIREBREATHER", TrainerCategory::Trainer),
+    ("JUGGLER", TrainerCategory::Trainer),
+    ("BLACKBELT", TrainerCategory::Trainer),
+    ("EXECUTIVE", TrainerCategory::Trainer),
+    ("PSYCHIC", TrainerCategory::Trainer),
+    ("PICNICKER", TrainerCategory::Trainer),
+    ("CAMPER", TrainerCategory::Trainer),
+    ("EXECUTIVE", TrainerCategory::Trainer),
+    ("SAGE", TrainerCategory::Trainer),
+    ("MEDIUM", TrainerCategory::Trainer),
+    ("BOARDER", TrainerCategory::Trainer),
+    ("POKéFAN", TrainerCategory::Trainer),
+    ("KIMONO GIRL", TrainerCategory::Trainer),
+    ("TWINS", TrainerCategory::Trainer),
+    ("POKéFAN", TrainerCategory::Trainer),
+    ("RED", TrainerCategory::Trainer),
+    ("BLUE", TrainerCategory::GymLeader),
+    ("OFFICER", TrainerCategory::Trainer),
+    ("ROCKET", TrainerCategory::Trainer),
+    ("MYSTICALMAN", TrainerCategory::Trainer)
+];
+
+/// Gen 1 trainer class data, starting at class 0x01.
+///
+/// Move choice layers come from `TrainerClassMoveChoiceModifications` and AI actions from `TrainerAIPointers`
+/// in the pokered disassembly.
+const GEN1_TRAINER_CLASSES: [Gen1TrainerClass; 47] = [
+    Gen1TrainerClass {
+        index: 0x01,
+        name: "YOUNGSTER",
+        move_choice_layers: &[],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x02,
+        name: "BUG CATCHER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x03,
+        name: "LASS",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x04,
+        name: "SAILOR",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x05,
+        name: "JR.TRAINER♂",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x06,
+        name: "JR.TRAINER♀",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x07,
+        name: "POKéMANIAC",
+        move_choice_layers: &[1, 2, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x08,
+        name: "SUPER NERD",
+        move_choice_layers: &[1, 2],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x09,
+        name: "HIKER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x0A,
+        name: "BIKER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x0B,
+        name: "BURGLAR",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x0C,
+        name: "ENGINEER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x0D,
+        name: "JUGGLER",
+        move_choice_layers: &[1, 2],
+        ai_uses: 3,
+        ai_actions: &[
+            switch(25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x0E,
+        name: "FISHERMAN",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x0F,
+        name: "SWIMMER",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x10,
+        name: "CUE BALL",
+        move_choice_layers: &[],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x11,
+        name: "GAMBLER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x12,
+        name: "BEAUTY",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x13,
+        name: "PSYCHIC",
+        move_choice_layers: &[1, 2],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x14,
+        name: "ROCKER",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x15,
+        name: "JUGGLER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[
+            switch(25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x16,
+        name: "TAMER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x17,
+        name: "BIRD KEEPER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x18,
+        name: "BLACKBELT",
+        move_choice_layers: &[1],
+        ai_uses: 2,
+        ai_actions: &[
+            item(X_ATTACK, 13, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x19,
+        name: "RIVAL1",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x1A,
+        name: "PROF.OAK",
+        move_choice_layers: &[1, 3],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x1B,
+        name: "CHIEF",
+        move_choice_layers: &[1, 2],
+        ai_uses: 1,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x1C,
+        name: "SCIENTIST",
+        move_choice_layers: &[1, 2],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x1D,
+        name: "GIOVANNI",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(GUARD_SPEC, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x1E,
+        name: "ROCKET",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x1F,
+        name: "COOLTRAINER♂",
+        move_choice_layers: &[1, 3],
+        ai_uses: 2,
+        ai_actions: &[
+            item(X_ATTACK, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x20,
+        name: "COOLTRAINER♀",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(HYPER_POTION, 25, Some(10)),
+            switch(25, Some(5)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x21,
+        name: "BRUNO",
+        move_choice_layers: &[1],
+        ai_uses: 2,
+        ai_actions: &[
+            item(X_DEFEND, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x22,
+        name: "BROCK",
+        move_choice_layers: &[1],
+        ai_uses: 5,
+        ai_actions: &[
+            TrainerAiAction {
+                kind: TrainerAiActionKind::Item(FULL_HEAL),
+                chance: 100,
+                hp_below: None,
+                requires_status: true
+            },
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x23,
+        name: "MISTY",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(X_DEFEND, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x24,
+        name: "LT.SURGE",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(X_SPEED, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x25,
+        name: "ERIKA",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(SUPER_POTION, 50, Some(10)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x26,
+        name: "KOGA",
+        move_choice_layers: &[1, 3],
+        ai_uses: 2,
+        ai_actions: &[
+            item(X_ATTACK, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x27,
+        name: "BLAINE",
+        move_choice_layers: &[1, 3],
+        ai_uses: 2,
+        ai_actions: &[
+            item(SUPER_POTION, 25, None),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x28,
+        name: "SABRINA",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(HYPER_POTION, 25, Some(10)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x29,
+        name: "GENTLEMAN",
+        move_choice_layers: &[1, 2],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x2A,
+        name: "RIVAL2",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(POTION, 13, Some(5)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x2B,
+        name: "RIVAL3",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(FULL_RESTORE, 13, Some(5)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x2C,
+        name: "LORELEI",
+        move_choice_layers: &[1, 2, 3],
+        ai_uses: 2,
+        ai_actions: &[
+            item(SUPER_POTION, 50, Some(5)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x2D,
+        name: "CHANNELER",
+        move_choice_layers: &[1],
+        ai_uses: 3,
+        ai_actions: &[]
+    },
+    Gen1TrainerClass {
+        index: 0x2E,
+        name: "AGATHA",
+        move_choice_layers: &[1],
+        ai_uses: 2,
+        ai_actions: &[
+            switch(8, None),
+            item(SUPER_POTION, 50, Some(4)),
+        ]
+    },
+    Gen1TrainerClass {
+        index: 0x2F,
+        name: "LANCE",
+        move_choice_layers: &[1, 3],
+        ai_uses: 1,
+        ai_actions: &[
+            item(HYPER_POTION, 50, Some(5)),
+        ]
+    },
+];
@@ -0,0 +1,326 @@
+use super::Generation;
+
+/// Corresponds to the types in Generation 1 and 2, using the games' own indices.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum Type {
+    Normal = 0x00,
+    Fighting = 0x01,
+    Flying = 0x02,
+    Poison = 0x03,
+    Ground = 0x04,
+    Rock = 0x05,
+    Bug = 0x07,
+    Ghost = 0x08,
+
+    /// Gen 2 only
+    Steel = 0x09,
+
+    /// The type of Curse in Gen 2 (displayed as ???)
+    CurseType = 0x13,
+    Fire = 0x14,
+    Water = 0x15,
+    Grass = 0x16,
+    Electric = 0x17,
+    Psychic = 0x18,
+    Ice = 0x19,
+    Dragon = 0x1A,
+
+    /// Gen 2 only
+    Dark = 0x1B
+}
+
+/// Damage multiplier of a single type matchup.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Effectiveness {
+    NoEffect = 0,
+    NotVeryEffective = 5,
+    Normal = 10,
+    SuperEffective = 20
+}
+
+impl Effectiveness {
+    /// Get the damage multiplier.
+    pub const fn multiplier(self) -> f64 {
+        self as u8 as f64 / 10.0
+    }
+}
+
+impl Type {
+    /// Convert a byte to its equivalent `Type` for the given generation.
+    ///
+    /// Returns `None` if there is no `Type` value in that generation.
+    pub const fn from_u8(generation: Generation, value: u8) -> Option<Self> {
+        let t = match value {
+            0x00 => Self::Normal,
+            0x01 => Self::Fighting,
+            0x02 => Self::Flying,
+            0x03 => Self::Poison,
+            0x04 => Self::Ground,
+            0x05 => Self::Rock,
+            0x07 => Self::Bug,
+            0x08 => Self::Ghost,
+            0x09 if matches!(generation, Generation::Two) => Self::Steel,
+            0x13 if matches!(generation, Generation::Two) => Self::CurseType,
+            0x14 => Self::Fire,
+            0x15 => Self::Water,
+            0x16 => Self::Grass,
+            0x17 => Self::Electric,
+            0x18 => Self::Psychic,
+            0x19 => Self::Ice,
+            0x1A => Self::Dragon,
+            0x1B if matches!(generation, Generation::Two) => Self::Dark,
+            _ => return None
+        };
+        Some(t)
+    }
+
+    /// Get the English name of the type.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Fighting => "FIGHTING",
+            Self::Flying => "FLYING",
+            Self::Poison => "POISON",
+            Self::Ground => "GROUND",
+            Self::Rock => "ROCK",
+            Self::Bug => "BUG",
+            Self::Ghost => "GHOST",
+            Self::Steel => "STEEL",
+            Self::CurseType => "???",
+            Self::Fire => "FIRE",
+            Self::Water => "WATER",
+            Self::Grass => "GRASS",
+            Self::Electric => "ELECTRIC",
+            Self::Psychic => "PSYCHIC",
+            Self::Ice => "ICE",
+            Self::Dragon => "DRAGON",
+            Self::Dark => "DARK",
+        }
+    }
+
+    /// Get the effectiveness of an attack of this type against a single defending type.
+    pub fn effectiveness(self, generation: Generation, defending: Type) -> Effectiveness {
+        let chart: &[(Type, Type, Effectiveness)] = match generation {
+            Generation::One => &GEN1_MATCHUPS,
+            Generation::Two => &GEN2_MATCHUPS
+        };
+        chart
+            .iter()
+            .find(|(a, d, _)| *a == self && *d == defending)
+            .map(|(_, _, e)| *e)
+            .unwrap_or(Effectiveness::Normal)
+    }
+
+    /// Get the total damage multiplier of an attack of this type against a Pokémon with the given types.
+    ///
+    /// Like in the games, a Pokémon whose two types are the same only counts that type once.
+    pub fn multiplier_against(self, generation: Generation, defending: [Type; 2]) -> f64 {
+        let mut multiplier = self.effectiveness(generation, defending[0]).multiplier();
+        if defending[1] != defending[0] {
+            multiplier *= self.effectiveness(generation, defending[1]).multiplier();
+        }
+        multiplier
+    }
+}
+
+/// Gen 1 type matchups that aren't neutral, in the order of the game's table.
+const GEN1_MATCHUPS: [(Type, Type, Effectiveness); 82] = [
+    (Type::Water, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Water, Effectiveness::SuperEffective),
+    (Type::Electric, Type::Water, Effectiveness::SuperEffective),
+    (Type::Water, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Flying, Effectiveness::NoEffect),
+    (Type::Water, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Electric, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Ice, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Psychic, Type::Psychic, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Water, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Normal, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Normal, Type::Ghost, Effectiveness::NoEffect),
+    (Type::Ghost, Type::Ghost, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Water, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Electric, Type::Ground, Effectiveness::NoEffect),
+    (Type::Electric, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Normal, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Psychic, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Ghost, Effectiveness::NoEffect),
+    (Type::Poison, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Poison, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Ground, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Poison, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Ghost, Effectiveness::NotVeryEffective),
+    (Type::Ground, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Electric, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Ground, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Ground, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Poison, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Electric, Effectiveness::NotVeryEffective),
+    (Type::Flying, Type::Fighting, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Psychic, Type::Fighting, Effectiveness::SuperEffective),
+    (Type::Psychic, Type::Poison, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Fighting, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Psychic, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Ghost, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Poison, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Fighting, Effectiveness::NotVeryEffective),
+    (Type::Rock, Type::Ground, Effectiveness::NotVeryEffective),
+    (Type::Rock, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Ghost, Type::Normal, Effectiveness::NoEffect),
+    (Type::Ghost, Type::Psychic, Effectiveness::NoEffect),
+    (Type::Fire, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Water, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Dragon, Effectiveness::SuperEffective),
+    (Type::Dragon, Type::Dragon, Effectiveness::SuperEffective),
+];
+
+/// Gen 2 type matchups that aren't neutral.
+const GEN2_MATCHUPS: [(Type, Type, Effectiveness); 110] = [
+    (Type::Normal, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Normal, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Fire, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Fire, Type::Steel, Effectiveness::SuperEffective),
+    (Type::Water, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Water, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Water, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Water, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Water, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Water, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Water, Effectiveness::SuperEffective),
+    (Type::Electric, Type::Electric, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Electric, Type::Ground, Effectiveness::NoEffect),
+    (Type::Electric, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Electric, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Water, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Grass, Type::Dragon, Effectiveness::NotVeryEffective),
+    (Type::Grass, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Ice, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Ground, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Dragon, Effectiveness::SuperEffective),
+    (Type::Ice, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Ice, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Normal, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Psychic, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Fighting, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Dark, Effectiveness::SuperEffective),
+    (Type::Fighting, Type::Steel, Effectiveness::SuperEffective),
+    (Type::Poison, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Poison, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Ground, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Ghost, Effectiveness::NotVeryEffective),
+    (Type::Poison, Type::Steel, Effectiveness::NoEffect),
+    (Type::Ground, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Electric, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Grass, Effectiveness::NotVeryEffective),
+    (Type::Ground, Type::Poison, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Flying, Effectiveness::NoEffect),
+    (Type::Ground, Type::Bug, Effectiveness::NotVeryEffective),
+    (Type::Ground, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Ground, Type::Steel, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Electric, Effectiveness::NotVeryEffective),
+    (Type::Flying, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Fighting, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Flying, Type::Rock, Effectiveness::NotVeryEffective),
+    (Type::Flying, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Psychic, Type::Fighting, Effectiveness::SuperEffective),
+    (Type::Psychic, Type::Poison, Effectiveness::SuperEffective),
+    (Type::Psychic, Type::Psychic, Effectiveness::NotVeryEffective),
+    (Type::Psychic, Type::Dark, Effectiveness::NoEffect),
+    (Type::Psychic, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Grass, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Fighting, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Poison, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Flying, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Psychic, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Ghost, Effectiveness::NotVeryEffective),
+    (Type::Bug, Type::Dark, Effectiveness::SuperEffective),
+    (Type::Bug, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Rock, Type::Fire, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Fighting, Effectiveness::NotVeryEffective),
+    (Type::Rock, Type::Ground, Effectiveness::NotVeryEffective),
+    (Type::Rock, Type::Flying, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Bug, Effectiveness::SuperEffective),
+    (Type::Rock, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Ghost, Type::Normal, Effectiveness::NoEffect),
+    (Type::Ghost, Type::Psychic, Effectiveness::SuperEffective),
+    (Type::Ghost, Type::Dark, Effectiveness::NotVeryEffective),
+    (Type::Ghost, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Ghost, Type::Ghost, Effectiveness::SuperEffective),
+    (Type::Dragon, Type::Dragon, Effectiveness::SuperEffective),
+    (Type::Dragon, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Dark, Type::Fighting, Effectiveness::NotVeryEffective),
+    (Type::Dark, Type::Psychic, Effectiveness::SuperEffective),
+    (Type::Dark, Type::Ghost, Effectiveness::SuperEffective),
+    (Type::Dark, Type::Dark, Effectiveness::NotVeryEffective),
+    (Type::Dark, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Steel, Type::Fire, Effectiveness::NotVeryEffective),
+    (Type::Steel, Type::Water, Effectiveness::NotVeryEffective),
+    (Type::Steel, Type::Electric, Effectiveness::NotVeryEffective),
+    (Type::Steel, Type::Ice, Effectiveness::SuperEffective),
+    (Type::Steel, Type::Rock, Effectiveness::SuperEffective),
+    (Type::Steel, Type::Steel, Effectiveness::NotVeryEffective),
+    (Type::Normal, Type::Ghost, Effectiveness::NoEffect),
+    (Type::Fighting, Type::Ghost, Effectiveness::NoEffect),
+];
@@ -0,0 +1,299 @@
+use std::fmt::{Display, Formatter};
+use crate::data::Generation;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Game {
+    Yellow,
+    Red,
+    Blue,
+
+    Gold,
+    Silver,
+    Crystal
+}
+
+impl Game {
+    pub const ALL: [Self; 6] = [Self::Red, Self::Blue, Self::Yellow, Self::Gold, Self::Silver, Self::Crystal];
+
+    /// Identify the game from the title in the ROM header.
+    pub fn from_rom_title(title: &str) -> Option<Self> {
+        let game = match title {
+            "POKEMON YELLOW" => Self::Yellow,
+            "POKEMON RED" => Self::Red,
+            "POKEMON BLUE" => Self::Blue,
+            "POKEMON_GLDAAUE" => Self::Gold,
+            "POKEMON_SLVAAXE" => Self::Silver,
+            "PM_CRYSTAL" => Self::Crystal,
+            _ => return None
+        };
+        Some(game)
+    }
+
+    /// Get a game from its short name (e.g. `crystal`), ignoring case.
+    pub fn from_short_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|game| game.short_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get a short, lowercase name for the game (e.g. `crystal`).
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Self::Yellow => "yellow",
+            Self::Red => "red",
+            Self::Blue => "blue",
+            Self::Gold => "gold",
+            Self::Silver => "silver",
+            Self::Crystal => "crystal"
+        }
+    }
+
+    pub const fn generation(self) -> Generation {
+        match self {
+            Self::Yellow | Self::Red | Self::Blue => Generation::One,
+            Self::Gold | Self::Silver | Self::Crystal => Generation::Two
+        }
+    }
+
+    /// Whether or not item use can be detected for this game.
+    pub const fn supports_item_detection(self) -> bool {
+        matches!(self.generation(), Generation::One)
+    }
+
+    /// Get the WRAM addresses used to read the battle for this game.
+    pub const fn addresses(self) -> &'static Addresses {
+        match self {
+            Self::Red | Self::Blue => &RED_BLUE,
+            Self::Yellow => &YELLOW,
+            Self::Gold | Self::Silver => &GOLD_SILVER,
+            Self::Crystal => &CRYSTAL
+        }
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Game {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Gold => "Pokémon: Gold Version",
+            Self::Silver => "Pokémon: Silver Version",
+            Self::Crystal => "Pokémon: Crystal Version",
+            Self::Yellow => "Pokémon Yellow Version: Special Pikachu Edition",
+            Self::Red => "Pokémon: Red Version",
+            Self::Blue => "Pokémon: Blue Version",
+        }
+    }
+}
+
+/// Memory addresses of the battle state for a game.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Addresses {
+    /// Where the game writes the move the player selected
+    pub player_selected_move: u16,
+
+    /// Where the AI writes the move it selected
+    pub enemy_selected_move: u16,
+
+    /// Where the game writes the enemy's move when it executes it (Gen 1), or where the move number is stored
+    /// (Gen 2)
+    pub enemy_move_num: u16,
+
+    /// Where the AI writes the item it used (Gen 1 only)
+    pub ai_item: Option<u16>,
+
+    /// HRAM bytes the game's random number generator reads
+    pub random_low: u16,
+    pub random_high: u16,
+
+    /// The player's active Pokémon's battle struct
+    pub player_battle_mon: u16,
+
+    /// The enemy's active Pokémon's battle struct
+    pub enemy_battle_mon: u16,
+
+    /// The player's stat stages, starting with attack
+    pub player_stat_stages: u16,
+
+    /// The enemy's stat stages, starting with attack
+    pub enemy_stat_stages: u16,
+
+    /// The enemy's battle status flags (wEnemyBattleStatus1-3 in Gen 1, wEnemySubStatus1-5 in Gen 2)
+    pub enemy_battle_status: u16,
+
+    /// Number of Pokémon in the trainer's party, followed by their species
+    pub enemy_party_count: u16,
+
+    /// The trainer's party structs
+    pub enemy_party_mons: u16,
+
+    /// The opposing trainer's class
+    pub trainer_class: u16,
+
+    /// Which of its class's parties the opposing trainer uses
+    pub trainer_id: u16,
+
+    /// 0 if not in battle, 1 for wild battles, and 2 for trainer battles
+    pub battle_mode: u16,
+
+    /// Obtained badges; in Generation 2, the Johto badges are followed by the Kanto badges
+    pub badges: u16,
+
+    /// Where the game keeps a copy of the ROM bank it last switched to (in HRAM)
+    pub loaded_rom_bank: u16,
+
+    /// The menu cursor's top item's Y coordinate, followed by its X coordinate, the selected item, the tile
+    /// behind the cursor, and the last item (wTopMenuItemY in Gen 1; not used in Gen 2)
+    pub menu_cursor: Option<u16>
+}
+
+impl Addresses {
+    /// Names accepted by [`Addresses::set`], which are the same as the fields.
+    pub const NAMES: [&'static str; 19] = [
+        "player_selected_move",
+        "enemy_selected_move",
+        "enemy_move_num",
+        "ai_item",
+        "random_low",
+        "random_high",
+        "player_battle_mon",
+        "enemy_battle_mon",
+        "player_stat_stages",
+        "enemy_stat_stages",
+        "enemy_battle_status",
+        "enemy_party_count",
+        "enemy_party_mons",
+        "trainer_class",
+        "trainer_id",
+        "battle_mode",
+        "badges",
+        "loaded_rom_bank",
+        "menu_cursor"
+    ];
+
+    /// Change an address by the name of its field, or `decision` for `enemy_selected_move`.
+    ///
+    /// Returns `false` if there is no address with that name.
+    pub fn set(&mut self, name: &str, address: u16) -> bool {
+        let field = match name {
+            "player_selected_move" => &mut self.player_selected_move,
+            "enemy_selected_move" | "decision" => &mut self.enemy_selected_move,
+            "enemy_move_num" => &mut self.enemy_move_num,
+            "random_low" => &mut self.random_low,
+            "random_high" => &mut self.random_high,
+            "player_battle_mon" => &mut self.player_battle_mon,
+            "enemy_battle_mon" => &mut self.enemy_battle_mon,
+            "player_stat_stages" => &mut self.player_stat_stages,
+            "enemy_stat_stages" => &mut self.enemy_stat_stages,
+            "enemy_battle_status" => &mut self.enemy_battle_status,
+            "enemy_party_count" => &mut self.enemy_party_count,
+            "enemy_party_mons" => &mut self.enemy_party_mons,
+            "trainer_class" => &mut self.trainer_class,
+            "trainer_id" => &mut self.trainer_id,
+            "battle_mode" => &mut self.battle_mode,
+            "badges" => &mut self.badges,
+            "loaded_rom_bank" => &mut self.loaded_rom_bank,
+            "ai_item" => {
+                self.ai_item = Some(address);
+                return true;
+            },
+            "menu_cursor" => {
+                self.menu_cursor = Some(address);
+                return true;
+            },
+            _ => return false
+        };
+        *field = address;
+        true
+    }
+}
+
+pub const RED_BLUE: Addresses = Addresses {
+    player_selected_move: 0xCCDC,
+    enemy_selected_move: 0xCCDD,
+    enemy_move_num: 0xCFCC,
+    ai_item: Some(0xCF05),
+    random_low: 0xFFD3,
+    random_high: 0xFFD4,
+    player_battle_mon: 0xD014,
+    enemy_battle_mon: 0xCFE5,
+    player_stat_stages: 0xCD1A,
+    enemy_stat_stages: 0xCD2E,
+    enemy_battle_status: 0xD067,
+    enemy_party_count: 0xD89C,
+    enemy_party_mons: 0xD8A4,
+    trainer_class: 0xD031,
+    trainer_id: 0xD05D,
+    battle_mode: 0xD057,
+    badges: 0xD356,
+    loaded_rom_bank: 0xFFB8,
+    menu_cursor: Some(0xCC24)
+};
+
+pub const YELLOW: Addresses = Addresses {
+    player_selected_move: 0xCCDC,
+    enemy_selected_move: 0xCCDD,
+    enemy_move_num: 0xCFCB,
+    ai_item: Some(0xCF04),
+    random_low: 0xFFD3,
+    random_high: 0xFFD4,
+    player_battle_mon: 0xD013,
+    enemy_battle_mon: 0xCFE4,
+    player_stat_stages: 0xCD1A,
+    enemy_stat_stages: 0xCD2E,
+    enemy_battle_status: 0xD066,
+    enemy_party_count: 0xD89B,
+    enemy_party_mons: 0xD8A3,
+    trainer_class: 0xD030,
+    trainer_id: 0xD05C,
+    battle_mode: 0xD056,
+    badges: 0xD355,
+    loaded_rom_bank: 0xFFB8,
+    menu_cursor: Some(0xCC24)
+};
+
+pub const GOLD_SILVER: Addresses = Addresses {
+    player_selected_move: 0xCBC1,
+    enemy_selected_move: 0xCBC2,
+    enemy_move_num: 0xCBC7,
+    ai_item: None,
+    random_low: 0xFFE3,
+    random_high: 0xFFE4,
+    player_battle_mon: 0xCB0A,
+    enemy_battle_mon: 0xD0EF,
+    player_stat_stages: 0xCBAA,
+    enemy_stat_stages: 0xCBB2,
+    enemy_battle_status: 0xCB4B,
+    enemy_party_count: 0xD169,
+    enemy_party_mons: 0xD171,
+    trainer_class: 0xD118,
+    trainer_id: 0xD11A,
+    battle_mode: 0xD116,
+    badges: 0xD57C,
+    loaded_rom_bank: 0xFF9D,
+    menu_cursor: None
+};
+
+pub const CRYSTAL: Addresses = Addresses {
+    player_selected_move: 0xC6E3,
+    enemy_selected_move: 0xC6E4,
+    enemy_move_num: 0xC6E9,
+    ai_item: None,
+    random_low: 0xFFE1,
+    random_high: 0xFFE2,
+    player_battle_mon: 0xC62C,
+    enemy_battle_mon: 0xD206,
+    player_stat_stages: 0xC6CC,
+    enemy_stat_stages: 0xC6D4,
+    enemy_battle_status: 0xC66D,
+    enemy_party_count: 0xD280,
+    enemy_party_mons: 0xD288,
+    trainer_class: 0xD22F,
+    trainer_id: 0xD231,
+    battle_mode: 0xD22D,
+    badges: 0xD857,
+    loaded_rom_bank: 0xFF9D,
+    menu_cursor: None
+};
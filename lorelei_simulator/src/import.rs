@@ -0,0 +1,132 @@
+//! Converting save states from other emulators into BESS save states the simulator can load.
+
+use std::fmt::{Display, Formatter};
+use crate::game::Game;
+
+mod bess;
+mod bgb;
+mod bizhawk;
+mod gambatte;
+mod inflate;
+mod mgba;
+mod retroarch;
+
+pub(crate) use retroarch::unwrap as unwrap_retroarch;
+
+/// Emulator a save state was made with.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SaveStateFormat {
+    /// SameBoy or any emulator that writes BESS save states
+    Bess,
+
+    /// BGB
+    Bgb,
+
+    /// Gambatte or gambatte-speedrun
+    Gambatte,
+
+    /// mGBA
+    Mgba,
+
+    /// Memory sent by the BizHawk Lua script
+    BizHawk
+}
+
+impl SaveStateFormat {
+    /// Detect the format of a save state.
+    ///
+    /// Returns `None` if it isn't in a known format.
+    pub fn detect(save_state: &[u8]) -> Option<Self> {
+        if bess::is_bess(save_state) {
+            Some(Self::Bess)
+        }
+        else if bgb::is_bgb(save_state) {
+            Some(Self::Bgb)
+        }
+        else if gambatte::is_gambatte(save_state) {
+            Some(Self::Gambatte)
+        }
+        else if mgba::is_mgba(save_state) {
+            Some(Self::Mgba)
+        }
+        else if bizhawk::is_bizhawk(save_state) {
+            Some(Self::BizHawk)
+        }
+        else {
+            None
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bess => "SameBoy/BESS",
+            Self::Bgb => "BGB",
+            Self::Gambatte => "Gambatte",
+            Self::Mgba => "mGBA",
+            Self::BizHawk => "BizHawk"
+        }
+    }
+}
+
+impl Display for SaveStateFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when a save state can't be converted.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ImportError {
+    /// The save state ends partway through
+    Truncated,
+
+    /// The save state is missing a field the simulator needs
+    MissingField(&'static str),
+
+    /// The save state was made with a different game
+    WrongRom,
+
+    /// The ROM uses a cartridge type (0x0147 in the header) that can't be converted
+    UnsupportedCartridge(u8),
+
+    /// The save state says it's bigger than any save state the simulator could load
+    TooBig
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("the save state is truncated"),
+            Self::MissingField(field) => write!(f, "the save state has no {field} field"),
+            Self::WrongRom => f.write_str("the save state was made with a different game"),
+            Self::UnsupportedCartridge(cartridge_type) => write!(f, "unsupported cartridge type 0x{cartridge_type:02X}"),
+            Self::TooBig => f.write_str("the save state is too big")
+        }
+    }
+}
+
+/// Convert a save state for the given ROM into a BESS save state.
+///
+/// Save states that are already BESS are returned as-is.
+pub fn convert(rom: &[u8], save_state: &[u8], format: SaveStateFormat) -> Result<Vec<u8>, ImportError> {
+    match format {
+        SaveStateFormat::Bess => Ok(save_state.to_vec()),
+        SaveStateFormat::Bgb => bgb::read(save_state)?.to_bess(rom),
+        SaveStateFormat::Gambatte => gambatte::read(save_state, rom)?.to_bess(rom),
+        SaveStateFormat::Mgba => mgba::read(save_state, rom)?.to_bess(rom),
+        SaveStateFormat::BizHawk => bizhawk::read(save_state, rom)?.to_bess(rom)
+    }
+}
+
+/// Get the ROM bank the game says it has loaded, for save states that don't have the mapper state.
+///
+/// Returns 1 for unknown games.
+fn loaded_rom_bank(rom: &[u8], hram: &[u8]) -> u16 {
+    let title = rom.get(0x134..0x143).unwrap_or_default();
+    let title_len = title.iter().position(|&b| b == 0).unwrap_or(title.len());
+    std::str::from_utf8(&title[..title_len])
+        .ok()
+        .and_then(Game::from_rom_title)
+        .and_then(|game| hram.get((game.addresses().loaded_rom_bank - 0xFF80) as usize).copied())
+        .map_or(1, u16::from)
+}
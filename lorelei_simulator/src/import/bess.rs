@@ -0,0 +1,198 @@
+//! Writing BESS save states (see SameBoy's BESS.md).
+
+use super::ImportError;
+
+const WRAM_SIZE_DMG: usize = 0x2000;
+const WRAM_SIZE_CGB: usize = 0x8000;
+const VRAM_SIZE_DMG: usize = 0x2000;
+const VRAM_SIZE_CGB: usize = 0x4000;
+const OAM_SIZE: usize = 0xA0;
+const HRAM_SIZE: usize = 0x7F;
+const PALETTE_SIZE: usize = 0x40;
+
+/// The machine state needed to write a BESS save state.
+///
+/// Buffers that are too short are padded with zeroes, and ones that are too long are truncated.
+#[derive(Clone, Default)]
+pub struct CoreState {
+    /// Whether the state was made on a Game Boy Color
+    pub cgb: bool,
+
+    pub pc: u16,
+    pub af: u16,
+    pub bc: u16,
+    pub de: u16,
+    pub hl: u16,
+    pub sp: u16,
+    pub ime: bool,
+    pub ie: u8,
+    pub halted: bool,
+
+    /// I/O registers at 0xFF00-0xFF7F
+    pub io: Vec<u8>,
+
+    pub wram: Vec<u8>,
+    pub vram: Vec<u8>,
+    pub oam: Vec<u8>,
+    pub hram: Vec<u8>,
+    pub sram: Vec<u8>,
+
+    /// CGB background and object palette memory
+    pub bg_palettes: Vec<u8>,
+    pub obj_palettes: Vec<u8>,
+
+    /// Mapper state
+    pub rom_bank: u16,
+    pub sram_bank: u8,
+    pub sram_enabled: bool,
+    pub mbc1_mode: u8
+}
+
+impl CoreState {
+    /// Write the state as a BESS save state for the given ROM.
+    pub fn to_bess(&self, rom: &[u8]) -> Result<Vec<u8>, ImportError> {
+        let cartridge_type = rom.get(0x147).copied().ok_or(ImportError::Truncated)?;
+        let mbc_writes = self.mbc_writes(cartridge_type)?;
+        let sram_size = match rom.get(0x149) {
+            Some(0x02) => 0x2000,
+            Some(0x03) => 0x8000,
+            Some(0x04) => 0x20000,
+            Some(0x05) => 0x10000,
+            _ => 0
+        };
+
+        let mut io = self.io.clone();
+        io.resize(0x80, 0);
+        io[0x50] = 0x01; // boot ROM is finished
+        if self.cgb && rom.get(0x143).is_some_and(|flags| flags & 0x80 == 0) {
+            io[0x4C] = 0x04; // DMG compatibility mode
+        }
+
+        let (wram_size, vram_size, palette_size) = if self.cgb {
+            (WRAM_SIZE_CGB, VRAM_SIZE_CGB, PALETTE_SIZE)
+        }
+        else {
+            (WRAM_SIZE_DMG, VRAM_SIZE_DMG, 0)
+        };
+
+        // The memory comes first, then the BESS blocks, which point back at it.
+        let mut data = Vec::new();
+        let mut buffer = |source: &[u8], size: usize| {
+            let offset = data.len() as u32;
+            data.extend(source.iter().copied().chain(std::iter::repeat(0)).take(size));
+            (size as u32, offset)
+        };
+        let buffers = [
+            buffer(&self.wram, wram_size),
+            buffer(&self.vram, vram_size),
+            buffer(&self.sram, sram_size),
+            buffer(&self.oam, OAM_SIZE),
+            buffer(&self.hram, HRAM_SIZE),
+            buffer(&self.bg_palettes, palette_size),
+            buffer(&self.obj_palettes, palette_size)
+        ];
+
+        let first_block = data.len() as u32;
+
+        let mut core = Vec::with_capacity(0xD0);
+        core.extend_from_slice(&1u16.to_le_bytes()); // major version
+        core.extend_from_slice(&1u16.to_le_bytes()); // minor version
+        // the models the simulator loads converted states with (see Simulator::new_from_vec)
+        core.extend_from_slice(if self.cgb { b"CCE " } else { b"GDB " });
+        for register in [self.pc, self.af, self.bc, self.de, self.hl, self.sp] {
+            core.extend_from_slice(&register.to_le_bytes());
+        }
+        core.push(self.ime as u8);
+        core.push(self.ie);
+        core.push(self.halted as u8);
+        core.push(0);
+        core.extend_from_slice(&io);
+        for (size, offset) in buffers {
+            core.extend_from_slice(&size.to_le_bytes());
+            core.extend_from_slice(&offset.to_le_bytes());
+        }
+        write_block(&mut data, b"CORE", &core);
+
+        let mbc: Vec<u8> = mbc_writes.iter().flat_map(|&(address, value)| {
+            let [low, high] = address.to_le_bytes();
+            [low, high, value]
+        }).collect();
+        write_block(&mut data, b"MBC ", &mbc);
+        write_block(&mut data, b"END ", &[]);
+
+        data.extend_from_slice(&first_block.to_le_bytes());
+        data.extend_from_slice(b"BESS");
+        Ok(data)
+    }
+
+    /// Get the writes that put the cartridge's mapper into this state.
+    fn mbc_writes(&self, cartridge_type: u8) -> Result<Vec<(u16, u8)>, ImportError> {
+        let ram_enable = (0x0000, if self.sram_enabled { 0x0A } else { 0x00 });
+        let bank = self.rom_bank;
+        match cartridge_type {
+            0x00 | 0x08 | 0x09 => Ok(Vec::new()),
+
+            // MBC1
+            0x01..=0x03 => Ok(vec![
+                ram_enable,
+                (0x6000, self.mbc1_mode),
+                (0x2000, (bank & 0x1F) as u8),
+                (0x4000, if self.mbc1_mode == 0 { (bank >> 5) as u8 & 0x03 } else { self.sram_bank })
+            ]),
+
+            // MBC3
+            0x0F..=0x13 => Ok(vec![ram_enable, (0x2000, bank as u8), (0x4000, self.sram_bank)]),
+
+            // MBC5
+            0x19..=0x1E => Ok(vec![
+                ram_enable,
+                (0x2000, bank as u8),
+                (0x3000, (bank >> 8) as u8),
+                (0x4000, self.sram_bank)
+            ]),
+
+            n => Err(ImportError::UnsupportedCartridge(n))
+        }
+    }
+}
+
+fn write_block(data: &mut Vec<u8>, id: &[u8; 4], block: &[u8]) {
+    data.extend_from_slice(id);
+    data.extend_from_slice(&(block.len() as u32).to_le_bytes());
+    data.extend_from_slice(block);
+}
+
+/// Check if the save state is already a BESS save state.
+pub fn is_bess(save_state: &[u8]) -> bool {
+    save_state.ends_with(b"BESS")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use safeboy::types::Model;
+
+    /// Make a ROM header with the given cartridge type and SRAM size.
+    fn rom(cartridge_type: u8, sram_size: u8) -> Vec<u8> {
+        let mut rom = vec![0; 0x8000];
+        rom[0x147] = cartridge_type;
+        rom[0x149] = sram_size;
+        rom
+    }
+
+    #[test]
+    fn sameboy_reads_the_model() {
+        let dmg = CoreState::default().to_bess(&rom(0x13, 0x03)).unwrap();
+        assert!(is_bess(&dmg));
+        assert!(matches!(safeboy::Gameboy::model_for_save_state(&dmg), Ok(Model::DMGB)));
+
+        let cgb = CoreState { cgb: true, ..Default::default() }.to_bess(&rom(0x1B, 0x04)).unwrap();
+        assert!(matches!(safeboy::Gameboy::model_for_save_state(&cgb), Ok(Model::CGBE)));
+    }
+
+    #[test]
+    fn unsupported_cartridge() {
+        assert_eq!(CoreState::default().to_bess(&rom(0xFC, 0)).unwrap_err(), ImportError::UnsupportedCartridge(0xFC));
+        assert_eq!(CoreState::default().to_bess(&[0; 0x100]).unwrap_err(), ImportError::Truncated);
+    }
+}
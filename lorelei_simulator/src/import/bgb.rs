@@ -0,0 +1,159 @@
+//! Reading BGB save states (`.sna` and `.sn0`-`.sn9`).
+//!
+//! BGB's format isn't documented. It's a list of named fields, each stored as a NUL-terminated name, a 32-bit
+//! little endian size, and that many bytes of data, starting with a `header` field holding the BGB version.
+
+use super::bess::CoreState;
+use super::ImportError;
+
+/// Check if the save state was made by BGB.
+pub fn is_bgb(save_state: &[u8]) -> bool {
+    let Ok(fields) = read_fields(save_state) else {
+        return false;
+    };
+    fields.first().is_some_and(|(name, value)| name.eq_ignore_ascii_case("header") && value.starts_with(b"BGB"))
+}
+
+/// Read the CPU and memory state out of a BGB save state.
+pub fn read(save_state: &[u8]) -> Result<CoreState, ImportError> {
+    let fields = read_fields(save_state)?;
+    let field = |name: &'static str| -> Result<&[u8], ImportError> {
+        fields
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+            .ok_or(ImportError::MissingField(name))
+    };
+    let number = |name: &'static str| -> Result<u32, ImportError> {
+        Ok(field(name)?.iter().take(4).rev().fold(0, |n, &b| (n << 8) | b as u32))
+    };
+    let pair = |high: &'static str, low: &'static str| -> Result<u16, ImportError> {
+        Ok(((number(high)? as u16) << 8) | (number(low)? as u16 & 0xFF))
+    };
+
+    let wram = field("WRAM")?.to_vec();
+    Ok(CoreState {
+        // only the Game Boy Color has more than 8 KiB of WRAM
+        cgb: wram.len() > 0x2000,
+        pc: number("PC")? as u16,
+        af: pair("A", "F")?,
+        bc: pair("B", "C")?,
+        de: pair("D", "E")?,
+        hl: pair("H", "L")?,
+        sp: number("SP")? as u16,
+        ime: number("IME")? != 0,
+        ie: number("IE")? as u8,
+        halted: number("HALT").unwrap_or(0) != 0,
+        io: field("IOREGS")?.to_vec(),
+        wram,
+        vram: field("VRAM")?.to_vec(),
+        oam: field("OAM")?.to_vec(),
+        hram: field("HRAM")?.to_vec(),
+        sram: field("SRAM").map(<[u8]>::to_vec).unwrap_or_default(),
+        bg_palettes: field("BGPAL").map(<[u8]>::to_vec).unwrap_or_default(),
+        obj_palettes: field("OBPAL").map(<[u8]>::to_vec).unwrap_or_default(),
+        rom_bank: number("ROMBANK")? as u16,
+        sram_bank: number("SRAMBANK").unwrap_or(0) as u8,
+        sram_enabled: number("SRAMENABLE").unwrap_or(0) != 0,
+        mbc1_mode: number("MBC1MODE").unwrap_or(0) as u8
+    })
+}
+
+fn read_fields(save_state: &[u8]) -> Result<Vec<(&str, &[u8])>, ImportError> {
+    let mut fields = Vec::new();
+    let mut data = save_state;
+    while !data.is_empty() {
+        let name_end = data.iter().position(|&b| b == 0).ok_or(ImportError::Truncated)?;
+        let name = std::str::from_utf8(&data[..name_end]).map_err(|_| ImportError::Truncated)?;
+        let data_start = name_end + 1 + 4;
+        let size = data.get(name_end + 1..data_start).ok_or(ImportError::Truncated)?;
+        let size = u32::from_le_bytes(size.try_into().unwrap()) as usize;
+        let value = data.get(data_start..data_start + size).ok_or(ImportError::Truncated)?;
+        fields.push((name, value));
+        data = &data[data_start + size..];
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::SaveStateFormat;
+
+    fn field(name: &str, value: &[u8]) -> Vec<u8> {
+        [name.as_bytes(), &[0], &(value.len() as u32).to_le_bytes(), value].concat()
+    }
+
+    /// Make a save state laid out like one from BGB, with the given WRAM size.
+    fn state(wram_size: usize, skip: &[&str]) -> Vec<u8> {
+        let fields = [
+            ("header", b"BGB 1.5.10\0".to_vec()),
+            ("PC", vec![0x50, 0x01]),
+            ("SP", vec![0xF0, 0xDF]),
+            ("A", vec![0x11]),
+            ("F", vec![0xB0]),
+            ("B", vec![0x22]),
+            ("C", vec![0x33]),
+            ("D", vec![0x44]),
+            ("E", vec![0x55]),
+            ("H", vec![0x66]),
+            ("L", vec![0x77]),
+            ("IME", vec![0x01]),
+            ("IE", vec![0x1F]),
+            ("HALT", vec![0x00]),
+            ("ROMBANK", vec![0x03, 0x00]),
+            ("SRAMBANK", vec![0x01]),
+            ("SRAMENABLE", vec![0x01]),
+            ("MBC1MODE", vec![0x00]),
+            ("IOREGS", vec![0x91; 0x80]),
+            ("WRAM", vec![0xCC; wram_size]),
+            ("VRAM", vec![0xAA; wram_size.min(0x4000)]),
+            ("OAM", vec![0; 0xA0]),
+            ("HRAM", vec![0x5A; 0x7F]),
+            ("SRAM", vec![0xBB; 0x2000])
+        ];
+        fields.iter().filter(|(name, _)| !skip.contains(name)).flat_map(|(name, value)| field(name, value)).collect()
+    }
+
+    #[test]
+    fn detects_state() {
+        assert!(is_bgb(&state(0x2000, &[])));
+        assert_eq!(SaveStateFormat::detect(&state(0x2000, &[])), Some(SaveStateFormat::Bgb));
+        assert!(!is_bgb(&state(0x2000, &["header"])));
+        assert!(!is_bgb(&field("header", b"SameBoy")));
+        assert!(!is_bgb(b"header"));
+    }
+
+    #[test]
+    fn reads_state() {
+        let core = read(&state(0x2000, &[])).unwrap();
+        assert!(!core.cgb);
+        assert_eq!((core.pc, core.sp, core.af, core.bc, core.de, core.hl), (0x0150, 0xDFF0, 0x11B0, 0x2233, 0x4455, 0x6677));
+        assert!(core.ime && !core.halted && core.sram_enabled);
+        assert_eq!((core.ie, core.rom_bank, core.sram_bank), (0x1F, 3, 1));
+
+        let mut rom = vec![0; 0x8000];
+        rom[0x147] = 0x03; // MBC1+RAM+BATTERY
+        rom[0x149] = 0x02;
+        let bess = core.to_bess(&rom).unwrap();
+        assert!(matches!(safeboy::Gameboy::model_for_save_state(&bess), Ok(safeboy::types::Model::DMGB)));
+    }
+
+    #[test]
+    fn reads_cgb_state() {
+        let core = read(&state(0x8000, &[])).unwrap();
+        assert!(core.cgb);
+        let mut rom = vec![0; 0x8000];
+        rom[0x143] = 0x80;
+        rom[0x147] = 0x10; // MBC3+TIMER+RAM+BATTERY
+        let bess = core.to_bess(&rom).unwrap();
+        assert!(matches!(safeboy::Gameboy::model_for_save_state(&bess), Ok(safeboy::types::Model::CGBE)));
+    }
+
+    #[test]
+    fn missing_and_truncated_fields() {
+        assert_eq!(read(&state(0x2000, &["PC"])).err(), Some(ImportError::MissingField("PC")));
+        let state = state(0x2000, &[]);
+        assert_eq!(read(&state[..state.len() - 1]).err(), Some(ImportError::Truncated));
+    }
+}
@@ -0,0 +1,60 @@
+//! Reading the memory dumps sent by the BizHawk Lua script (`bizhawk/lorelei.lua`).
+//!
+//! BizHawk's own save states depend on the core, and Lua can't get at most of them anyway, so the script sends the
+//! registers and memory domains instead. This is a line of text for each field: a name, a space, and the value in
+//! hexadecimal. The first line is always `LORELEI BIZHAWK 1`.
+
+use super::bess::CoreState;
+use super::{loaded_rom_bank, ImportError};
+
+const HEADER: &str = "LORELEI BIZHAWK 1";
+
+/// Check if the data was sent by the BizHawk script.
+pub fn is_bizhawk(save_state: &[u8]) -> bool {
+    save_state.starts_with(HEADER.as_bytes())
+}
+
+/// Read the CPU and memory state sent by the BizHawk script.
+pub fn read(save_state: &[u8], rom: &[u8]) -> Result<CoreState, ImportError> {
+    let text = std::str::from_utf8(save_state).map_err(|_| ImportError::Truncated)?;
+    let fields: Vec<(&str, &str)> = text.lines().skip(1).filter_map(|line| line.split_once(' ')).collect();
+    let field = |name: &'static str| -> Result<Vec<u8>, ImportError> {
+        let (_, value) = fields.iter().find(|&&(n, _)| n == name).ok_or(ImportError::MissingField(name))?;
+        decode_hex(value.trim()).ok_or(ImportError::Truncated)
+    };
+    let number = |name: &'static str| -> Result<u16, ImportError> {
+        Ok(field(name)?.iter().fold(0, |n, &b| (n << 8) | b as u16))
+    };
+
+    let wram = field("wram")?;
+    let hram = field("hram")?;
+
+    Ok(CoreState {
+        cgb: wram.len() > 0x2000,
+        pc: number("pc")?,
+        af: number("af")?,
+        bc: number("bc")?,
+        de: number("de")?,
+        hl: number("hl")?,
+        sp: number("sp")?,
+        // not every core exposes this, but the games don't disable interrupts for long
+        ime: number("ime") != Ok(0),
+        ie: number("ie")? as u8,
+        halted: false,
+        io: field("io")?,
+        vram: field("vram")?,
+        oam: field("oam")?,
+        sram: field("sram").unwrap_or_default(),
+        rom_bank: loaded_rom_bank(rom, &hram),
+        wram,
+        hram,
+        ..Default::default()
+    })
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
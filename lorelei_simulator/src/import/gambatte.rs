@@ -0,0 +1,202 @@
+//! Reading Gambatte and gambatte-speedrun save states (`.gqs`).
+//!
+//! These start with a two-byte version and a thumbnail of the screen, followed by a list of fields, each stored
+//! as a NUL-terminated label, a 24-bit big endian size, and that many bytes of data. Numbers are big endian. The
+//! fields are sorted by label, so which one comes first depends on what the version saves.
+
+use super::bess::CoreState;
+use super::ImportError;
+
+const VERSION: [u8; 2] = [0x00, 0x01];
+
+/// Fields every version saves, which are used to recognize its save states
+const REQUIRED_LABELS: [&str; 8] = ["pc", "sp", "a", "f", "ime", "hram", "wram", "rombank"];
+
+/// Check if the save state was made by Gambatte.
+pub fn is_gambatte(save_state: &[u8]) -> bool {
+    let Ok(fields) = read_fields(save_state) else {
+        return false;
+    };
+    REQUIRED_LABELS.iter().all(|&label| fields.iter().any(|&(l, _)| l == label))
+}
+
+/// Read the CPU and memory state out of a Gambatte save state.
+///
+/// Gambatte doesn't record whether it ran the game in Game Boy Color mode, so this is taken from the ROM.
+pub fn read(save_state: &[u8], rom: &[u8]) -> Result<CoreState, ImportError> {
+    let fields = read_fields(save_state)?;
+    let field = |label: &'static str| -> Result<&[u8], ImportError> {
+        fields
+            .iter()
+            .find(|&&(l, _)| l == label)
+            .map(|&(_, value)| value)
+            .ok_or(ImportError::MissingField(label))
+    };
+    let number = |label: &'static str| -> Result<u32, ImportError> {
+        Ok(field(label)?.iter().fold(0, |n, &b| (n << 8) | b as u32))
+    };
+    let pair = |high: &'static str, low: &'static str| -> Result<u16, ImportError> {
+        Ok(((number(high)? as u16) << 8) | (number(low)? as u16 & 0xFF))
+    };
+
+    // OAM, then I/O registers at 0x100, then HRAM at 0x180, and IE at the very end
+    let ioamhram = field("hram")?;
+    if ioamhram.len() < 0x200 {
+        return Err(ImportError::Truncated);
+    }
+
+    let cgb = rom.get(0x143).is_some_and(|flags| flags & 0x80 != 0);
+    let (wram_size, vram_size) = if cgb { (0x8000, 0x4000) } else { (0x2000, 0x2000) };
+    let wram = field("wram")?;
+    let vram = field("vram")?;
+
+    Ok(CoreState {
+        cgb,
+        pc: number("pc")? as u16,
+        af: pair("a", "f")?,
+        bc: pair("b", "c")?,
+        de: pair("d", "e")?,
+        hl: pair("h", "l")?,
+        sp: number("sp")? as u16,
+        ime: number("ime")? != 0,
+        ie: ioamhram[0x1FF],
+        halted: number("halted").unwrap_or(0) != 0,
+        io: ioamhram[0x100..0x180].to_vec(),
+        wram: wram[..wram.len().min(wram_size)].to_vec(),
+        vram: vram[..vram.len().min(vram_size)].to_vec(),
+        oam: ioamhram[..0xA0].to_vec(),
+        hram: ioamhram[0x180..0x1FF].to_vec(),
+        sram: field("sram").map(<[u8]>::to_vec).unwrap_or_default(),
+        bg_palettes: field("bgp").map(<[u8]>::to_vec).unwrap_or_default(),
+        obj_palettes: field("objp").map(<[u8]>::to_vec).unwrap_or_default(),
+        rom_bank: number("rombank")? as u16,
+        sram_bank: number("rambank").unwrap_or(0) as u8,
+        sram_enabled: number("enableram").unwrap_or(0) != 0,
+        mbc1_mode: number("rambmod").unwrap_or(0) as u8
+    })
+}
+
+fn read_fields(save_state: &[u8]) -> Result<Vec<(&str, &[u8])>, ImportError> {
+    let Some(data) = save_state.strip_prefix(&VERSION) else {
+        return Err(ImportError::Truncated);
+    };
+
+    // skip the thumbnail
+    let (thumbnail_size, data) = read_size(data)?;
+    let mut data = data.get(thumbnail_size..).ok_or(ImportError::Truncated)?;
+
+    let mut fields = Vec::new();
+    while !data.is_empty() {
+        let label_end = data.iter().position(|&b| b == 0).ok_or(ImportError::Truncated)?;
+        let label = std::str::from_utf8(&data[..label_end]).map_err(|_| ImportError::Truncated)?;
+        let (size, rest) = read_size(&data[label_end + 1..])?;
+        let value = rest.get(..size).ok_or(ImportError::Truncated)?;
+        fields.push((label, value));
+        data = &rest[size..];
+    }
+    Ok(fields)
+}
+
+/// Read a 24-bit big endian size.
+fn read_size(data: &[u8]) -> Result<(usize, &[u8]), ImportError> {
+    match data {
+        [a, b, c, rest @ ..] => Ok((((*a as usize) << 16) | ((*b as usize) << 8) | *c as usize, rest)),
+        _ => Err(ImportError::Truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::SaveStateFormat;
+
+    /// Make a field the way Gambatte's StateSaver writes them.
+    fn field(label: &str, value: &[u8]) -> Vec<u8> {
+        let size = (value.len() as u32).to_be_bytes();
+        [label.as_bytes(), &[0], &size[1..], value].concat()
+    }
+
+    /// Make a save state laid out like one from Gambatte, with a thumbnail and its fields sorted by label.
+    fn state(skip: &[&str]) -> Vec<u8> {
+        let mut ioamhram = vec![0; 0x200];
+        ioamhram[0x140] = 0x91; // LCDC
+        ioamhram[0x180] = 0x5A; // first byte of HRAM
+        ioamhram[0x1FF] = 0x1F; // IE
+        let mut fields = vec![
+            ("cc", vec![0x00, 0x12, 0x34, 0x56]),
+            ("pc", vec![0x01, 0x50]),
+            ("sp", vec![0xDF, 0xF0]),
+            ("a", vec![0x11]),
+            ("b", vec![0x22]),
+            ("c", vec![0x33]),
+            ("d", vec![0x44]),
+            ("e", vec![0x55]),
+            ("f", vec![0xB0]),
+            ("h", vec![0x66]),
+            ("l", vec![0x77]),
+            ("skip", vec![0x00]),
+            ("halttime", vec![0; 4]),
+            ("vram", vec![0xAA; 0x2000]),
+            ("sram", vec![0xBB; 0x8000]),
+            ("wram", vec![0xCC; 0x8000]),
+            ("hram", ioamhram),
+            ("ldivup", vec![0; 4]),
+            ("ime", vec![0x01]),
+            ("halted", vec![0x01]),
+            ("bgp", vec![0; 0x40]),
+            ("objp", vec![0; 0x40]),
+            ("rombank", vec![0x00, 0x05]),
+            ("rambank", vec![0x02]),
+            ("enableram", vec![0x01]),
+            ("rambmod", vec![0x00])
+        ];
+        fields.retain(|(label, _)| !skip.contains(label));
+        fields.sort_by_key(|&(label, _)| label);
+
+        // the thumbnail is a quarter of the screen in each direction, with four bytes per pixel
+        let mut state = VERSION.to_vec();
+        state.extend_from_slice(&[0x00, 0x16, 0x80]);
+        state.extend(std::iter::repeat_n(0xFF, 40 * 36 * 4));
+        for (label, value) in fields {
+            state.extend(field(label, &value));
+        }
+        state
+    }
+
+    #[test]
+    fn detects_sorted_fields() {
+        assert!(is_gambatte(&state(&[])));
+        assert_eq!(SaveStateFormat::detect(&state(&[])), Some(SaveStateFormat::Gambatte));
+    }
+
+    #[test]
+    fn rejects_other_states() {
+        assert!(!is_gambatte(&state(&["rombank"])));
+        assert!(!is_gambatte(&[0x00, 0x01, 0x00, 0x00]));
+        assert!(!is_gambatte(b"BESS"));
+    }
+
+    #[test]
+    fn reads_state() {
+        let mut rom = vec![0; 0x8000];
+        rom[0x147] = 0x1B; // MBC5+RAM+BATTERY
+        rom[0x149] = 0x03;
+        let core = read(&state(&[]), &rom).unwrap();
+        assert!(!core.cgb);
+        assert_eq!((core.pc, core.sp, core.af, core.bc, core.de, core.hl), (0x0150, 0xDFF0, 0x11B0, 0x2233, 0x4455, 0x6677));
+        assert!(core.ime && core.halted && core.sram_enabled);
+        assert_eq!(core.ie, 0x1F);
+        assert_eq!(core.io[0x40], 0x91);
+        assert_eq!(core.hram[0], 0x5A);
+        assert_eq!((core.wram.len(), core.vram.len()), (0x2000, 0x2000));
+        assert_eq!((core.rom_bank, core.sram_bank), (5, 2));
+
+        let bess = core.to_bess(&rom).unwrap();
+        assert!(matches!(safeboy::Gameboy::model_for_save_state(&bess), Ok(safeboy::types::Model::DMGB)));
+    }
+
+    #[test]
+    fn missing_field() {
+        assert_eq!(read(&state(&["a"]), &[0; 0x150]).err(), Some(ImportError::MissingField("a")));
+    }
+}
@@ -0,0 +1,241 @@
+//! Decompressing zlib streams (RFC 1950/1951), as used by PNG files.
+
+use super::ImportError;
+
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+/// Order the code length code lengths are stored in for dynamic blocks
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+/// Decompress a zlib stream. The checksum is not verified.
+///
+/// This stops after `max_size` bytes, since a small stream can expand to far more than any save state.
+pub fn zlib_decompress(data: &[u8], max_size: usize) -> Result<Vec<u8>, ImportError> {
+    // CMF and FLG; 8 (deflate) is the only compression method
+    match data {
+        [cmf, _, ..] if cmf & 0x0F == 8 => inflate(&data[2..], max_size),
+        _ => Err(ImportError::Truncated)
+    }
+}
+
+fn inflate(data: &[u8], max_size: usize) -> Result<Vec<u8>, ImportError> {
+    let mut bits = BitReader { data, position: 0 };
+    let mut output = Vec::new();
+
+    loop {
+        let last = bits.read(1)? == 1;
+        match bits.read(2)? {
+            0 => {
+                bits.align();
+                let start = bits.position / 8;
+                let header = data.get(start..start + 4).ok_or(ImportError::Truncated)?;
+                let length = u16::from_le_bytes([header[0], header[1]]) as usize;
+                let block = data.get(start + 4..start + 4 + length).ok_or(ImportError::Truncated)?;
+                output.extend_from_slice(block);
+                bits.position = (start + 4 + length) * 8;
+            },
+            1 => {
+                let mut lengths = [0u8; 288 + 30];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..288].fill(8);
+                lengths[288..].fill(5);
+                inflate_block(&mut bits, &mut output, max_size, &Huffman::new(&lengths[..288]), &Huffman::new(&lengths[288..]))?;
+            },
+            2 => {
+                let literal_count = bits.read(5)? as usize + 257;
+                let distance_count = bits.read(5)? as usize + 1;
+                let code_length_count = bits.read(4)? as usize + 4;
+
+                let mut code_lengths = [0u8; 19];
+                for &index in &CODE_LENGTH_ORDER[..code_length_count] {
+                    code_lengths[index] = bits.read(3)? as u8;
+                }
+                let code_length_codes = Huffman::new(&code_lengths);
+
+                let mut lengths = Vec::with_capacity(literal_count + distance_count);
+                while lengths.len() < literal_count + distance_count {
+                    let (value, repeat) = match code_length_codes.decode(&mut bits)? {
+                        symbol @ 0..=15 => (symbol as u8, 1),
+                        16 => (*lengths.last().ok_or(ImportError::Truncated)?, 3 + bits.read(2)?),
+                        17 => (0, 3 + bits.read(3)?),
+                        _ => (0, 11 + bits.read(7)?)
+                    };
+                    lengths.extend(std::iter::repeat_n(value, repeat as usize));
+                }
+                if lengths.len() != literal_count + distance_count {
+                    return Err(ImportError::Truncated);
+                }
+
+                let (literals, distances) = lengths.split_at(literal_count);
+                inflate_block(&mut bits, &mut output, max_size, &Huffman::new(literals), &Huffman::new(distances))?;
+            },
+            _ => return Err(ImportError::Truncated)
+        }
+
+        if last || output.len() >= max_size {
+            output.truncate(max_size);
+            return Ok(output);
+        }
+    }
+}
+
+/// Decompress a block with the given codes, stopping early once there are at least `max_size` bytes.
+fn inflate_block(bits: &mut BitReader, output: &mut Vec<u8>, max_size: usize, literals: &Huffman, distances: &Huffman) -> Result<(), ImportError> {
+    while output.len() < max_size {
+        let symbol = literals.decode(bits)? as usize;
+        match symbol {
+            0..=255 => output.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let index = symbol - 257;
+                let (&base, &extra) = LENGTH_BASE.get(index).zip(LENGTH_EXTRA.get(index)).ok_or(ImportError::Truncated)?;
+                let length = base as usize + bits.read(extra)? as usize;
+
+                let index = distances.decode(bits)? as usize;
+                let (&base, &extra) = DISTANCE_BASE.get(index).zip(DISTANCE_EXTRA.get(index)).ok_or(ImportError::Truncated)?;
+                let distance = base as usize + bits.read(extra)? as usize;
+
+                let start = output.len().checked_sub(distance).ok_or(ImportError::Truncated)?;
+                for i in 0..length {
+                    output.push(output[start + i]);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+
+    /// Position in bits
+    position: usize
+}
+
+impl BitReader<'_> {
+    /// Read `count` bits, least significant first.
+    fn read(&mut self, count: u8) -> Result<u32, ImportError> {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = self.data.get(self.position / 8).ok_or(ImportError::Truncated)?;
+            value |= (((byte >> (self.position % 8)) & 1) as u32) << i;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Skip to the next byte.
+    fn align(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+}
+
+/// A canonical Huffman code.
+struct Huffman {
+    /// How many codes there are of each length
+    counts: [u16; 16],
+
+    /// Symbols ordered by code
+    symbols: Vec<u16>
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+
+        let mut symbols = Vec::with_capacity(lengths.len());
+        for length in 1..16u8 {
+            symbols.extend((0..lengths.len() as u16).filter(|&s| lengths[s as usize] == length));
+        }
+        Self { counts, symbols }
+    }
+
+    fn decode(&self, bits: &mut BitReader) -> Result<u16, ImportError> {
+        // Huffman codes are stored most significant bit first, unlike everything else.
+        let mut code = 0;
+        let mut first = 0;
+        let mut index = 0;
+        for &count in &self.counts[1..] {
+            code |= bits.read(1)? as usize;
+            let count = count as usize;
+            if code < first + count {
+                return self.symbols.get(index + code - first).copied().ok_or(ImportError::Truncated);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(ImportError::Truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "abc" in a stored block
+    const STORED: &[u8] = &[0x78, 0x01, 0x01, 0x03, 0x00, 0xFC, 0xFF, 0x61, 0x62, 0x63, 0x02, 0x4D, 0x01, 0x27];
+
+    /// "hello hello hello" in a fixed Huffman block, with back-references
+    const FIXED: &[u8] = &[0x78, 0x01, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x57, 0xC8, 0x40, 0x90, 0x00, 0x3A, 0x2E, 0x06, 0x7D];
+
+    /// [`DYNAMIC_TEXT`] in a dynamic Huffman block
+    const DYNAMIC: &[u8] = &[
+        0x78, 0xDA, 0x0D, 0xC7, 0x31, 0x01, 0x00, 0x00, 0x0C, 0x83, 0x30, 0x6D, 0x50, 0xFC, 0x6B, 0xDA, 0xBE, 0x04, 0x20,
+        0xC3, 0x72, 0xDA, 0x78, 0x8F, 0xFD, 0x56, 0x07, 0x78, 0x8C, 0x07, 0xCD
+    ];
+    const DYNAMIC_TEXT: &[u8] = b"AAAADBDABDDBCBBDCADABCACDBCCDD";
+
+    #[test]
+    fn stored_block() {
+        assert_eq!(zlib_decompress(STORED, usize::MAX).unwrap(), b"abc");
+    }
+
+    #[test]
+    fn fixed_block() {
+        assert_eq!(zlib_decompress(FIXED, usize::MAX).unwrap(), b"hello hello hello");
+    }
+
+    #[test]
+    fn dynamic_block() {
+        assert_eq!((DYNAMIC[2] >> 1) & 3, 2, "not a dynamic block");
+        assert_eq!(zlib_decompress(DYNAMIC, usize::MAX).unwrap(), DYNAMIC_TEXT);
+    }
+
+    #[test]
+    fn stops_at_max_size() {
+        assert_eq!(zlib_decompress(FIXED, 5).unwrap(), b"hello");
+        assert_eq!(zlib_decompress(STORED, 2).unwrap(), b"ab");
+        assert_eq!(zlib_decompress(DYNAMIC, 10).unwrap(), &DYNAMIC_TEXT[..10]);
+    }
+
+    #[test]
+    fn truncated() {
+        for stream in [STORED, FIXED, DYNAMIC] {
+            // the last 4 bytes are the checksum, which isn't needed
+            for length in 0..stream.len() - 4 {
+                assert_eq!(zlib_decompress(&stream[..length], usize::MAX), Err(ImportError::Truncated), "{length} bytes");
+            }
+        }
+    }
+
+    #[test]
+    fn not_deflate() {
+        assert_eq!(zlib_decompress(&[0x79, 0x01, 0x03, 0x00], usize::MAX), Err(ImportError::Truncated));
+    }
+
+    #[test]
+    fn distance_before_start() {
+        // a fixed block starting with a length 3, distance 1 back-reference, when there's nothing to refer to
+        assert_eq!(zlib_decompress(&[0x78, 0x01, 0x03, 0x02, 0x00], usize::MAX), Err(ImportError::Truncated));
+    }
+}
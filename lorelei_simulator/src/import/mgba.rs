@@ -0,0 +1,151 @@
+//! Reading mGBA Game Boy save states (`.ss0`-`.ss9`), either raw or embedded in a PNG screenshot.
+//!
+//! The raw format is mGBA's `GBSerializedState` structure, which has a fixed layout. PNG save states store it
+//! zlib-compressed in a `gbAs` chunk.
+
+use std::borrow::Cow;
+use super::bess::CoreState;
+use super::inflate::zlib_decompress;
+use super::{loaded_rom_bank, ImportError};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1A\n";
+
+/// Upper 16 bits of the version field; the lower bits are the version.
+const MAGIC: u32 = 0x0040_0000;
+
+const STATE_SIZE: usize = 0x11800;
+const MODEL: usize = 0x008;
+const TITLE: usize = 0x00C;
+const REGISTERS: usize = 0x020;
+const CPU_FLAGS: usize = 0x044;
+const MEMORY_FLAGS: usize = 0x194;
+const IO: usize = 0x200;
+const HRAM: usize = 0x280;
+const IE: usize = 0x2FF;
+const OAM: usize = 0x400;
+const VRAM: usize = 0x5800;
+const WRAM: usize = 0x9800;
+
+/// Bit of `GBSerializedCpuFlags` set if the CPU is halted
+const CPU_FLAG_HALTED: u32 = 1 << 4;
+
+/// Bit of `GBSerializedMemoryFlags` set if interrupts are enabled
+const MEMORY_FLAG_IME: u16 = 1 << 3;
+
+/// Check if the save state was made by mGBA.
+pub fn is_mgba(save_state: &[u8]) -> bool {
+    state(save_state).is_ok()
+}
+
+/// Read the CPU and memory state out of an mGBA save state.
+///
+/// The mapper state is taken from the copy of the ROM bank the game keeps in HRAM, since it doesn't change
+/// between mGBA versions the way the rest of the memory state does.
+pub fn read(save_state: &[u8], rom: &[u8]) -> Result<CoreState, ImportError> {
+    let state = state(save_state)?;
+
+    let title = rom.get(0x134..0x143).ok_or(ImportError::Truncated)?;
+    let title_len = title.iter().position(|&b| b == 0).unwrap_or(title.len());
+    if !state[TITLE..].starts_with(&title[..title_len]) {
+        return Err(ImportError::WrongRom);
+    }
+
+    let register = |index: usize| state[REGISTERS + index];
+    let register_pair = |index: usize| u16::from_le_bytes([state[REGISTERS + index], state[REGISTERS + index + 1]]);
+
+    // the model is one of 0x00 (DMG), 0x20 (SGB), 0x40 (MGB), 0x60 (SGB2), 0x80 (CGB), 0xA0 (SGB in CGB), or 0xC0 (AGB)
+    let cgb = state[MODEL] & 0x80 != 0;
+    let (wram_size, vram_size) = if cgb { (0x8000, 0x4000) } else { (0x2000, 0x2000) };
+    let cpu_flags = u32::from_le_bytes(state[CPU_FLAGS..CPU_FLAGS + 4].try_into().unwrap());
+    let memory_flags = u16::from_le_bytes([state[MEMORY_FLAGS], state[MEMORY_FLAGS + 1]]);
+
+    Ok(CoreState {
+        cgb,
+        pc: register_pair(0xA),
+        af: u16::from_be_bytes([register(0), register(1)]),
+        bc: u16::from_be_bytes([register(2), register(3)]),
+        de: u16::from_be_bytes([register(4), register(5)]),
+        hl: u16::from_be_bytes([register(6), register(7)]),
+        sp: register_pair(0x8),
+        ime: memory_flags & MEMORY_FLAG_IME != 0,
+        ie: state[IE],
+        halted: cpu_flags & CPU_FLAG_HALTED != 0,
+        io: state[IO..IO + 0x80].to_vec(),
+        wram: state[WRAM..WRAM + wram_size].to_vec(),
+        vram: state[VRAM..VRAM + vram_size].to_vec(),
+        oam: state[OAM..OAM + 0xA0].to_vec(),
+        hram: state[HRAM..IE].to_vec(),
+        rom_bank: loaded_rom_bank(rom, &state[HRAM..IE]),
+        ..Default::default()
+    })
+}
+
+/// Get the serialized state, decompressing it if it's in a PNG.
+fn state(save_state: &[u8]) -> Result<Cow<'_, [u8]>, ImportError> {
+    let state = match save_state.strip_prefix(PNG_SIGNATURE) {
+        Some(chunks) => Cow::Owned(zlib_decompress(png_chunk(chunks, b"gbAs")?, STATE_SIZE)?),
+        None => Cow::Borrowed(save_state)
+    };
+    if state.len() < STATE_SIZE {
+        return Err(ImportError::Truncated);
+    }
+    let magic = u32::from_le_bytes(state[..4].try_into().unwrap());
+    if magic & 0xFFFF_0000 != MAGIC {
+        return Err(ImportError::Truncated);
+    }
+    Ok(state)
+}
+
+fn png_chunk<'a>(mut chunks: &'a [u8], chunk_type: &[u8; 4]) -> Result<&'a [u8], ImportError> {
+    while let [a, b, c, d, t0, t1, t2, t3, rest @ ..] = chunks {
+        let length = u32::from_be_bytes([*a, *b, *c, *d]) as usize;
+        let data = rest.get(..length).ok_or(ImportError::Truncated)?;
+        if &[*t0, *t1, *t2, *t3] == chunk_type {
+            return Ok(data);
+        }
+        // skip the data and CRC
+        chunks = rest.get(length + 4..).ok_or(ImportError::Truncated)?;
+    }
+    Err(ImportError::MissingField("gbAs"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Make a PNG chunk with a dummy CRC.
+    fn chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(chunk_type);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(&[0; 4]);
+        chunk
+    }
+
+    #[test]
+    fn finds_chunk() {
+        let chunks = [chunk(b"IHDR", &[1; 13]), chunk(b"IDAT", &[2; 7]), chunk(b"gbAs", b"state"), chunk(b"IEND", &[])].concat();
+        assert_eq!(png_chunk(&chunks, b"gbAs"), Ok(&b"state"[..]));
+    }
+
+    #[test]
+    fn missing_chunk() {
+        let chunks = [chunk(b"IHDR", &[1; 13]), chunk(b"IEND", &[])].concat();
+        assert_eq!(png_chunk(&chunks, b"gbAs"), Err(ImportError::MissingField("gbAs")));
+    }
+
+    #[test]
+    fn truncated_chunk() {
+        let chunks = [chunk(b"IHDR", &[1; 13]), chunk(b"gbAs", b"state")].concat();
+        // cut off in the IHDR chunk's CRC, and then in the gbAs chunk's data
+        assert_eq!(png_chunk(&chunks[..24], b"gbAs"), Err(ImportError::Truncated));
+        assert_eq!(png_chunk(&chunks[..chunks.len() - 6], b"gbAs"), Err(ImportError::Truncated));
+    }
+
+    #[test]
+    fn not_a_state() {
+        assert_eq!(state(b"not a save state").err(), Some(ImportError::Truncated));
+        let png = [PNG_SIGNATURE, &chunk(b"gbAs", &[0x78, 0x01, 0x01, 0x03, 0x00, 0xFC, 0xFF, 0x61, 0x62, 0x63])].concat();
+        assert_eq!(state(&png).err(), Some(ImportError::Truncated));
+    }
+}
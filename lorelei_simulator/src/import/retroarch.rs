@@ -0,0 +1,65 @@
+//! Unwrapping RetroArch save states (`.state`).
+//!
+//! RetroArch saves whatever the core gives it, optionally wrapped in a `RASTATE` container with other blocks, and
+//! optionally compressed with its RZIP format (zlib-compressed chunks). The core's data is just a regular save state
+//! for the emulator it's based on, so it can be detected and converted like any other.
+
+use super::inflate::zlib_decompress;
+use super::ImportError;
+
+const RZIP_MAGIC: &[u8] = b"#RZIPv\x01#";
+const RZIP_HEADER_SIZE: usize = 20;
+
+/// Largest RZIP save state accepted, which is far bigger than any Game Boy core's
+const MAX_RZIP_SIZE: usize = 16 << 20;
+
+const RASTATE_MAGIC: &[u8] = b"RASTATE\x01";
+const MEM_BLOCK: &[u8; 4] = b"MEM ";
+const END_BLOCK: &[u8; 4] = b"END ";
+
+/// Take the core's save state out of a RetroArch save state.
+///
+/// Anything that isn't a RetroArch save state is returned as-is.
+pub fn unwrap(save_state: Vec<u8>) -> Result<Vec<u8>, ImportError> {
+    let save_state = if save_state.starts_with(RZIP_MAGIC) { decompress_rzip(&save_state)? } else { save_state };
+    match save_state.strip_prefix(RASTATE_MAGIC) {
+        Some(blocks) => Ok(mem_block(blocks)?.to_vec()),
+        None => Ok(save_state)
+    }
+}
+
+fn decompress_rzip(data: &[u8]) -> Result<Vec<u8>, ImportError> {
+    let header = data.get(..RZIP_HEADER_SIZE).ok_or(ImportError::Truncated)?;
+    let total_size = u64::from_le_bytes(header[12..20].try_into().unwrap());
+    let total_size = usize::try_from(total_size).ok().filter(|&size| size <= MAX_RZIP_SIZE).ok_or(ImportError::TooBig)?;
+
+    let mut output = Vec::with_capacity(total_size);
+    let mut chunks = &data[RZIP_HEADER_SIZE..];
+    while output.len() < total_size {
+        let [a, b, c, d, rest @ ..] = chunks else {
+            return Err(ImportError::Truncated);
+        };
+        let size = u32::from_le_bytes([*a, *b, *c, *d]) as usize;
+        let chunk = rest.get(..size).ok_or(ImportError::Truncated)?;
+        output.extend_from_slice(&zlib_decompress(chunk, total_size - output.len())?);
+        chunks = &rest[size..];
+    }
+    output.truncate(total_size);
+    Ok(output)
+}
+
+/// Blocks have an 8-byte header (type and little endian size) and are padded to 8 bytes.
+fn mem_block(mut blocks: &[u8]) -> Result<&[u8], ImportError> {
+    while let [t0, t1, t2, t3, a, b, c, d, rest @ ..] = blocks {
+        let block_type = [*t0, *t1, *t2, *t3];
+        let size = u32::from_le_bytes([*a, *b, *c, *d]) as usize;
+        if &block_type == MEM_BLOCK {
+            return rest.get(..size).ok_or(ImportError::Truncated);
+        }
+        if &block_type == END_BLOCK {
+            break;
+        }
+        blocks = rest.get(size.next_multiple_of(8)..).ok_or(ImportError::Truncated)?;
+    }
+    Err(ImportError::MissingField("MEM"))
+}
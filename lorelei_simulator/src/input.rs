@@ -0,0 +1,361 @@
+//! Button presses, either scripted to drive the game into a battle, or mashed to get through text while waiting
+//! for the AI.
+//!
+//! Scripts are plain text with one command per line. Blank lines and anything after a `#` are ignored.
+//!
+//! ```text
+//! wait 300        # wait 300 frames without pressing anything
+//! press START     # press START for 5 frames, then release it for a frame
+//! press A 20      # press A for 20 frames, then release it for a frame
+//! press UP+B      # press UP and B together
+//! hold B          # hold B down until it's released
+//! release B       # release B (or everything being held if no buttons are given)
+//! ```
+
+use std::fmt::{Display, Formatter};
+use safeboy::types::Key;
+use crate::game::Addresses;
+
+/// How many frames `press` holds its buttons for if no frame count is given.
+const DEFAULT_PRESS_FRAMES: u32 = 5;
+
+/// A Game Boy button.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Button {
+    A,
+    B,
+    Select,
+    Start,
+    Right,
+    Left,
+    Up,
+    Down
+}
+
+impl Button {
+    pub const ALL: [Button; 8] = [
+        Button::A,
+        Button::B,
+        Button::Select,
+        Button::Start,
+        Button::Right,
+        Button::Left,
+        Button::Up,
+        Button::Down
+    ];
+
+    /// Get the name of the button as written in scripts.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Button::A => "A",
+            Button::B => "B",
+            Button::Select => "SELECT",
+            Button::Start => "START",
+            Button::Right => "RIGHT",
+            Button::Left => "LEFT",
+            Button::Up => "UP",
+            Button::Down => "DOWN"
+        }
+    }
+
+    /// Get the button with the given name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Button> {
+        Self::ALL.into_iter().find(|b| b.name().eq_ignore_ascii_case(name))
+    }
+
+    const fn key(self) -> Key {
+        match self {
+            Button::A => Key::A,
+            Button::B => Key::B,
+            Button::Select => Key::Select,
+            Button::Start => Key::Start,
+            Button::Right => Key::Right,
+            Button::Left => Key::Left,
+            Button::Up => Key::Up,
+            Button::Down => Key::Down
+        }
+    }
+}
+
+/// A single command in an [`InputScript`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum InputStep {
+    /// Hold the buttons for this many frames, then release them for one frame.
+    Press { buttons: Vec<Button>, frames: u32 },
+
+    /// Run this many frames without pressing anything (other than held buttons).
+    Wait { frames: u32 },
+
+    /// Press the buttons and keep them held until they're released.
+    Hold { buttons: Vec<Button> },
+
+    /// Release held buttons, or all of them if this is empty.
+    Release { buttons: Vec<Button> }
+}
+
+/// A sequence of button presses to run on the game.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct InputScript {
+    pub steps: Vec<InputStep>
+}
+
+impl InputScript {
+    /// Parse a script (see the [module documentation](self) for the format).
+    pub fn parse(script: &str) -> Result<Self, InputScriptError> {
+        let mut steps = Vec::new();
+
+        for (index, line) in script.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split('#').next().unwrap();
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+
+            let frames = |word: Option<&str>| -> Result<Option<u32>, InputScriptError> {
+                match word {
+                    Some(w) => w.parse().map(Some).map_err(|_| InputScriptError::BadFrameCount { line: line_number }),
+                    None => Ok(None)
+                }
+            };
+
+            let buttons = |word: Option<&str>| -> Result<Vec<Button>, InputScriptError> {
+                word.ok_or(InputScriptError::MissingArgument { line: line_number })?
+                    .split('+')
+                    .map(|b| Button::from_name(b).ok_or(InputScriptError::UnknownButton { line: line_number }))
+                    .collect()
+            };
+
+            let step = match command.to_ascii_lowercase().as_str() {
+                "wait" => {
+                    let frames = frames(words.next())?.ok_or(InputScriptError::MissingArgument { line: line_number })?;
+                    InputStep::Wait { frames }
+                },
+                "press" => {
+                    let buttons = buttons(words.next())?;
+                    let frames = frames(words.next())?.unwrap_or(DEFAULT_PRESS_FRAMES);
+                    InputStep::Press { buttons, frames }
+                },
+                "hold" => InputStep::Hold { buttons: buttons(words.next())? },
+                "release" => match words.next() {
+                    Some(word) => InputStep::Release { buttons: buttons(Some(word))? },
+                    None => InputStep::Release { buttons: Vec::new() }
+                },
+                _ => return Err(InputScriptError::UnknownCommand { line: line_number })
+            };
+
+            if words.next().is_some() {
+                return Err(InputScriptError::TooManyArguments { line: line_number });
+            }
+            steps.push(step);
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// Get how many frames the script takes to run.
+    pub fn frames(&self) -> u64 {
+        self.steps.iter().map(|step| match step {
+            InputStep::Press { frames, .. } => *frames as u64 + 1,
+            InputStep::Wait { frames } => *frames as u64,
+            InputStep::Hold { .. } | InputStep::Release { .. } => 0
+        }).sum()
+    }
+
+    /// Run the script on the Game Boy, leaving all buttons released.
+    pub(crate) fn run(&self, gameboy: &mut safeboy::Gameboy) {
+        let mut held = Vec::new();
+        for step in &self.steps {
+            match step {
+                InputStep::Press { buttons, frames } => {
+                    set_buttons(gameboy, buttons, true);
+                    run_frames(gameboy, *frames);
+                    let released: Vec<Button> = buttons.iter().copied().filter(|b| !held.contains(b)).collect();
+                    set_buttons(gameboy, &released, false);
+                    run_frames(gameboy, 1);
+                },
+                InputStep::Wait { frames } => run_frames(gameboy, *frames),
+                InputStep::Hold { buttons } => {
+                    set_buttons(gameboy, buttons, true);
+                    for &button in buttons {
+                        if !held.contains(&button) {
+                            held.push(button);
+                        }
+                    }
+                },
+                InputStep::Release { buttons } => {
+                    let released = if buttons.is_empty() { std::mem::take(&mut held) } else { buttons.clone() };
+                    set_buttons(gameboy, &released, false);
+                    held.retain(|b| !released.contains(b));
+                }
+            }
+        }
+        set_buttons(gameboy, &held, false);
+    }
+}
+
+/// What the simulator presses while waiting for the AI, e.g. to get through text.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum InputStrategy {
+    /// Don't press anything.
+    None,
+
+    /// Mash A, but if the battle menu is open with the cursor on anything other than FIGHT, move the cursor to
+    /// FIGHT first (Gen 1). In Gen 2, the AI has already decided by the time the menu opens, so this just mashes
+    /// A.
+    #[default]
+    Navigate,
+
+    /// Press the buttons for `on` frames, then release them for `off` frames, over and over.
+    RapidFire { buttons: Vec<Button>, on: u32, off: u32 }
+}
+
+impl InputStrategy {
+    /// Parse a strategy written as `none`, `navigate`, or buttons with an optional cadence, e.g. `A`, `B`, or
+    /// `A+B:2/4` (2 frames on, 4 frames off).
+    ///
+    /// Returns `None` if it's invalid.
+    pub fn parse(strategy: &str) -> Option<Self> {
+        if strategy.eq_ignore_ascii_case("none") {
+            return Some(Self::None);
+        }
+        if strategy.eq_ignore_ascii_case("navigate") {
+            return Some(Self::Navigate);
+        }
+
+        let (buttons, cadence) = match strategy.split_once(':') {
+            Some((buttons, cadence)) => (buttons, Some(cadence)),
+            None => (strategy, None)
+        };
+        let buttons = buttons.split('+').map(Button::from_name).collect::<Option<Vec<_>>>()?;
+        let (on, off) = match cadence {
+            Some(cadence) => {
+                let (on, off) = cadence.split_once('/')?;
+                (on.parse().ok()?, off.parse().ok()?)
+            },
+            None => (3, 3)
+        };
+        if on == 0 {
+            return None;
+        }
+        Some(Self::RapidFire { buttons, on, off })
+    }
+}
+
+impl Display for InputStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Navigate => f.write_str("navigate"),
+            Self::RapidFire { buttons, on, off } => {
+                let buttons: Vec<&str> = buttons.iter().map(|b| b.name()).collect();
+                write!(f, "{}:{on}/{off}", buttons.join("+"))
+            }
+        }
+    }
+}
+
+/// How many frames [`InputStrategy::Navigate`] presses and releases each button for.
+const NAVIGATE_CADENCE: u32 = 3;
+
+/// Presses buttons according to an [`InputStrategy`] as frames go by.
+pub(crate) struct AutoInput {
+    strategy: InputStrategy,
+    menu_cursor: Option<u16>,
+    counter: u32,
+    odd_frame: bool,
+
+    /// The button [`InputStrategy::Navigate`] is pressing
+    navigate_button: Button
+}
+
+impl AutoInput {
+    pub fn new(strategy: InputStrategy, addresses: &Addresses) -> Self {
+        Self { strategy, menu_cursor: addresses.menu_cursor, counter: 0, odd_frame: false, navigate_button: Button::A }
+    }
+
+    /// Update the buttons for the current frame. Returns `true` if a new frame started.
+    pub fn update(&mut self, gameboy: &mut safeboy::Gameboy) -> bool {
+        if self.odd_frame == gameboy.is_odd_frame() {
+            return false;
+        }
+        self.odd_frame = !self.odd_frame;
+
+        match &self.strategy {
+            InputStrategy::None => (),
+            InputStrategy::Navigate => {
+                self.counter = (self.counter + 1) % (NAVIGATE_CADENCE * 2);
+                if self.counter == 1 {
+                    self.navigate_button = navigate(gameboy, self.menu_cursor);
+                }
+                set_buttons(gameboy, &[self.navigate_button], self.counter < NAVIGATE_CADENCE);
+            },
+            InputStrategy::RapidFire { buttons, on, off } => {
+                self.counter = (self.counter + 1) % (on + off);
+                set_buttons(gameboy, buttons, self.counter < *on);
+            }
+        }
+        true
+    }
+}
+
+/// Where the battle menu's cursor is drawn in Gen 1.
+const BATTLE_MENU_Y: u8 = 14;
+const BATTLE_MENU_LEFT_X: u8 = 9;
+const BATTLE_MENU_RIGHT_X: u8 = 15;
+
+/// Get the button that gets the battle menu's cursor closer to FIGHT, or A if it's already there.
+fn navigate(gameboy: &mut safeboy::Gameboy, menu_cursor: Option<u16>) -> Button {
+    let Some(address) = menu_cursor else {
+        return Button::A;
+    };
+    let y = gameboy.safe_read_memory(address);
+    let x = gameboy.safe_read_memory(address + 1);
+    let item = gameboy.safe_read_memory(address + 2);
+    let last_item = gameboy.safe_read_memory(address + 4);
+
+    // The battle menu has FIGHT over ITEM in the left column and PKMN over RUN in the right column. The cursor
+    // moves between columns with LEFT and RIGHT and within them with UP and DOWN.
+    if y != BATTLE_MENU_Y || last_item != 1 {
+        return Button::A;
+    }
+    match (x, item) {
+        (BATTLE_MENU_RIGHT_X, _) => Button::Left,
+        (BATTLE_MENU_LEFT_X, 1) => Button::Up,
+        _ => Button::A
+    }
+}
+
+/// Error returned by [`InputScript::parse`]. Line numbers start at 1.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum InputScriptError {
+    UnknownCommand { line: usize },
+    UnknownButton { line: usize },
+    MissingArgument { line: usize },
+    TooManyArguments { line: usize },
+    BadFrameCount { line: usize }
+}
+
+impl Display for InputScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCommand { line } => write!(f, "Unknown command on line {line} (expected press, hold, release, or wait)"),
+            Self::UnknownButton { line } => write!(f, "Unknown button on line {line} (expected A, B, SELECT, START, RIGHT, LEFT, UP, or DOWN)"),
+            Self::MissingArgument { line } => write!(f, "Missing argument on line {line}"),
+            Self::TooManyArguments { line } => write!(f, "Too many arguments on line {line}"),
+            Self::BadFrameCount { line } => write!(f, "Invalid frame count on line {line}")
+        }
+    }
+}
+
+fn set_buttons(gameboy: &mut safeboy::Gameboy, buttons: &[Button], pressed: bool) {
+    for button in buttons {
+        gameboy.set_key_state(button.key(), pressed);
+    }
+}
+
+fn run_frames(gameboy: &mut safeboy::Gameboy, frames: u32) {
+    for _ in 0..frames {
+        gameboy.run_frame();
+    }
+}
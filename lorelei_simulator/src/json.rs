@@ -0,0 +1,523 @@
+//! Minimal JSON values for exporting data to other programs and reading it back.
+
+use std::fmt::{Display, Formatter, Write};
+
+/// A JSON value.
+///
+/// Formatting with `{}` gives compact JSON, and `{:#}` gives indented JSON.
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+
+    /// Non-finite numbers are written as `null`.
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+
+    /// Keys are written in the order given.
+    Object(Vec<(String, JsonValue)>)
+}
+
+impl JsonValue {
+    /// Make an object from key/value pairs.
+    pub fn object<K: Into<String>>(entries: impl IntoIterator<Item = (K, JsonValue)>) -> Self {
+        Self::Object(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// Parse a JSON document.
+    pub fn parse(text: &str) -> Result<Self, JsonError> {
+        let mut parser = Parser { text, position: 0 };
+        let value = parser.value(0)?;
+        parser.skip_whitespace();
+        if parser.position != text.len() {
+            return Err(parser.error("expected the end of the document"));
+        }
+        Ok(value)
+    }
+
+    /// Get the value of a key if this is an object and has it.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            Self::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None
+        }
+    }
+
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None
+        }
+    }
+
+    /// Get the value if it's a whole number that fits in a `u64`.
+    pub fn as_u64(&self) -> Option<u64> {
+        // 2^64 itself rounds to u64::MAX with `as`, so it has to be excluded.
+        self.as_f64().filter(|n| n.fract() == 0.0 && *n >= 0.0 && *n < 18446744073709551616.0).map(|n| n as u64)
+    }
+
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None
+        }
+    }
+
+    fn write(&self, f: &mut Formatter<'_>, pretty: bool, depth: usize) -> std::fmt::Result {
+        let newline = |f: &mut Formatter<'_>, depth: usize| -> std::fmt::Result {
+            if pretty {
+                f.write_char('\n')?;
+                for _ in 0..depth {
+                    f.write_str("  ")?;
+                }
+            }
+            Ok(())
+        };
+
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Self::Number(_) => f.write_str("null"),
+            Self::String(s) => write_string(f, s),
+            Self::Array(values) => {
+                if values.is_empty() {
+                    return f.write_str("[]");
+                }
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    newline(f, depth + 1)?;
+                    value.write(f, pretty, depth + 1)?;
+                }
+                newline(f, depth)?;
+                f.write_char(']')
+            },
+            Self::Object(entries) => {
+                if entries.is_empty() {
+                    return f.write_str("{}");
+                }
+                f.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i != 0 {
+                        f.write_char(',')?;
+                    }
+                    newline(f, depth + 1)?;
+                    write_string(f, key)?;
+                    f.write_str(if pretty { ": " } else { ":" })?;
+                    value.write(f, pretty, depth + 1)?;
+                }
+                newline(f, depth)?;
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?
+        }
+    }
+    f.write_char('"')
+}
+
+/// An error from [`JsonValue::parse`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonError {
+    /// Line the error is on, starting from 1
+    pub line: usize,
+
+    /// Column the error is at in characters, starting from 1
+    pub column: usize,
+
+    pub message: &'static str
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (line {}, column {})", self.message, self.line, self.column)
+    }
+}
+
+/// Nesting limit, so deeply nested documents can't overflow the stack
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    text: &'a str,
+
+    /// Position in bytes
+    position: usize
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> JsonError {
+        let before = &self.text[..self.position];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        JsonError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// Consume `expected` if it's next.
+    fn eat(&mut self, expected: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(expected);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn value(&mut self, depth: usize) -> Result<JsonValue, JsonError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => {
+                self.position += 1;
+                let mut entries = Vec::new();
+                if self.eat(b'}') {
+                    return Ok(JsonValue::Object(entries));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() != Some(b'"') {
+                        return Err(self.error("expected a key"));
+                    }
+                    let key = self.string()?;
+                    if !self.eat(b':') {
+                        return Err(self.error("expected ':'"));
+                    }
+                    entries.push((key, self.value(depth + 1)?));
+                    if self.eat(b'}') {
+                        return Ok(JsonValue::Object(entries));
+                    }
+                    if !self.eat(b',') {
+                        return Err(self.error("expected ',' or '}'"));
+                    }
+                }
+            },
+            Some(b'[') => {
+                self.position += 1;
+                let mut values = Vec::new();
+                if self.eat(b']') {
+                    return Ok(JsonValue::Array(values));
+                }
+                loop {
+                    values.push(self.value(depth + 1)?);
+                    if self.eat(b']') {
+                        return Ok(JsonValue::Array(values));
+                    }
+                    if !self.eat(b',') {
+                        return Err(self.error("expected ',' or ']'"));
+                    }
+                }
+            },
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => {
+                for (word, value) in [("null", JsonValue::Null), ("true", JsonValue::Bool(true)), ("false", JsonValue::Bool(false))] {
+                    if self.text[self.position..].starts_with(word) {
+                        self.position += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("expected a value"))
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let start = parser.position;
+            while parser.peek().is_some_and(|c| c.is_ascii_digit()) {
+                parser.position += 1;
+            }
+            parser.position != start
+        };
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        let integer_start = self.position;
+        if !digits(self) || (self.text.as_bytes()[integer_start] == b'0' && self.position - integer_start > 1) {
+            return Err(self.error("invalid number"));
+        }
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if !digits(self) {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            if !digits(self) {
+                return Err(self.error("invalid number"));
+            }
+        }
+
+        // What's left is always valid for f64's parser.
+        Ok(JsonValue::Number(self.text[start..self.position].parse().unwrap_or(f64::NAN)))
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        // Skip the opening quote.
+        self.position += 1;
+        let mut string = String::new();
+        loop {
+            let rest = &self.text[self.position..];
+            let Some(end) = rest.find(['"', '\\']) else {
+                return Err(self.error("unterminated string"));
+            };
+            if let Some(control) = rest[..end].find(|c: char| (c as u32) < 0x20) {
+                self.position += control;
+                return Err(self.error("control character in string"));
+            }
+            string.push_str(&rest[..end]);
+            self.position += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(string);
+            }
+
+            let escape = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+            self.position += 1;
+            string.push(match escape {
+                b'"' => '"',
+                b'\\' => '\\',
+                b'/' => '/',
+                b'b' => '\u{8}',
+                b'f' => '\u{c}',
+                b'n' => '\n',
+                b'r' => '\r',
+                b't' => '\t',
+                b'u' => {
+                    let high = self.hex_escape()?;
+                    let code = if (0xD800..0xDC00).contains(&high) {
+                        if !self.text[self.position..].starts_with("\\u") {
+                            return Err(self.error("unpaired surrogate"));
+                        }
+                        self.position += 2;
+                        let low = self.hex_escape()?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return Err(self.error("unpaired surrogate"));
+                        }
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    }
+                    else {
+                        high
+                    };
+                    char::from_u32(code).ok_or_else(|| self.error("unpaired surrogate"))?
+                },
+                _ => {
+                    self.position -= 1;
+                    return Err(self.error("invalid escape"));
+                }
+            });
+        }
+    }
+
+    /// Read the four hex digits of a `\u` escape.
+    fn hex_escape(&mut self) -> Result<u32, JsonError> {
+        let digits = self.text.get(self.position..self.position + 4).filter(|d| d.bytes().all(|c| c.is_ascii_hexdigit()));
+        let code = digits.and_then(|d| u32::from_str_radix(d, 16).ok()).ok_or_else(|| self.error("invalid escape"))?;
+        self.position += 4;
+        Ok(code)
+    }
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write(f, f.alternate(), 0)
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for JsonValue {
+                fn from(value: $t) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i32, i64, f32, f64);
+
+impl From<&str> for JsonValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Null)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(value: Vec<T>) -> Self {
+        Self::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(text: &str) -> (usize, usize, &'static str) {
+        let error = JsonValue::parse(text).unwrap_err();
+        (error.line, error.column, error.message)
+    }
+
+    #[test]
+    fn parses_values() {
+        let value = JsonValue::parse(r#" {"a": [1, -2.5, 3e2, 0.25E-1], "b": {"c": null, "d": true, "e": false}, "f": ""} "#).unwrap();
+        assert_eq!(value, JsonValue::object([
+            ("a", JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Number(-2.5), JsonValue::Number(300.0), JsonValue::Number(0.025)])),
+            ("b", JsonValue::object([("c", JsonValue::Null), ("d", JsonValue::Bool(true)), ("e", JsonValue::Bool(false))])),
+            ("f", JsonValue::from(""))
+        ]));
+        assert_eq!(JsonValue::parse("[]").unwrap(), JsonValue::Array(Vec::new()));
+        assert_eq!(JsonValue::parse("{ }").unwrap(), JsonValue::Object(Vec::new()));
+    }
+
+    #[test]
+    fn number_grammar() {
+        for valid in ["0", "-0", "10", "1.5", "-1.5e10", "1E+2", "1e-2"] {
+            assert!(JsonValue::parse(valid).is_ok(), "{valid}");
+        }
+        for invalid in ["01", "-", "1.", ".5", "+1", "1e", "1e+", "0x10", "-01"] {
+            assert!(JsonValue::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn as_u64() {
+        assert_eq!(JsonValue::parse("18446744073709549568").unwrap().as_u64(), Some(18446744073709549568));
+        assert_eq!(JsonValue::parse("18446744073709551616").unwrap().as_u64(), None);
+        assert_eq!(JsonValue::Number(-1.0).as_u64(), None);
+        assert_eq!(JsonValue::Number(1.5).as_u64(), None);
+    }
+
+    #[test]
+    fn escapes() {
+        let value = JsonValue::parse(r#""\"\\\/\b\f\n\r\té\u0001""#).unwrap();
+        assert_eq!(value.as_str(), Some("\"\\/\u{8}\u{c}\n\r\t\u{e9}\u{1}"));
+        assert_eq!(parse_error(r#""\x""#), (1, 3, "invalid escape"));
+        assert_eq!(parse_error(r#""\u12G4""#), (1, 4, "invalid escape"));
+        assert_eq!(parse_error("\"a\u{1}\""), (1, 3, "control character in string"));
+        assert_eq!(parse_error(r#""abc"#), (1, 2, "unterminated string"));
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        assert_eq!(JsonValue::parse(r#""😀""#).unwrap().as_str(), Some("\u{1F600}"));
+        assert_eq!(parse_error(r#""\ud83d""#).2, "unpaired surrogate");
+        assert_eq!(parse_error(r#""\ud83dx""#).2, "unpaired surrogate");
+        assert_eq!(parse_error(r#""\ud83dA""#).2, "unpaired surrogate");
+        assert_eq!(parse_error(r#""\ude00""#).2, "unpaired surrogate");
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(JsonValue::parse(&nested(MAX_DEPTH + 1)).is_ok());
+        assert_eq!(parse_error(&nested(MAX_DEPTH + 2)).2, "nested too deeply");
+        assert_eq!(parse_error(&nested(100_000)).2, "nested too deeply");
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(parse_error(""), (1, 1, "expected a value"));
+        assert_eq!(parse_error("[1,\n  2,\n  x]"), (3, 3, "expected a value"));
+        assert_eq!(parse_error("{\"é\": 1 2}"), (1, 9, "expected ',' or '}'"));
+        assert_eq!(parse_error("{1: 2}"), (1, 2, "expected a key"));
+        assert_eq!(parse_error("{\"a\" 2}"), (1, 6, "expected ':'"));
+        assert_eq!(parse_error("[1 2]"), (1, 4, "expected ',' or ']'"));
+        assert_eq!(parse_error("1 2"), (1, 3, "expected the end of the document"));
+        assert_eq!(parse_error("nul"), (1, 1, "expected a value"));
+    }
+
+    #[test]
+    fn writes_compact_and_pretty() {
+        let value = JsonValue::object([
+            ("a", JsonValue::from(vec![1u8, 2])),
+            ("b", JsonValue::object::<&str>([])),
+            ("c", JsonValue::Array(Vec::new())),
+            ("d", JsonValue::Number(f64::NAN)),
+            ("e", JsonValue::from(None::<u8>))
+        ]);
+        assert_eq!(value.to_string(), r#"{"a":[1,2],"b":{},"c":[],"d":null,"e":null}"#);
+        assert_eq!(format!("{value:#}"), "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {},\n  \"c\": [],\n  \"d\": null,\n  \"e\": null\n}");
+        assert_eq!(JsonValue::Number(0.1).to_string(), "0.1");
+        assert_eq!(JsonValue::Number(-3.0).to_string(), "-3");
+    }
+
+    #[test]
+    fn writes_escapes() {
+        let value = JsonValue::from("\"\\\n\r\t\u{1}\u{1f}é😀/");
+        assert_eq!(value.to_string(), r#""\"\\\n\r\t\u0001\u001fé😀/""#);
+    }
+
+    #[test]
+    fn round_trips() {
+        let value = JsonValue::object([
+            ("text", JsonValue::from("line\nbreak \"quoted\" \u{7f} \u{1F600}")),
+            ("numbers", JsonValue::from(vec![0.0, -1.5, 1e300, 123456789.0])),
+            ("nested", JsonValue::Array(vec![JsonValue::object([("x", JsonValue::Null)]), JsonValue::Bool(true)]))
+        ]);
+        assert_eq!(JsonValue::parse(&value.to_string()).unwrap(), value);
+        assert_eq!(JsonValue::parse(&format!("{value:#}")).unwrap(), value);
+    }
+}
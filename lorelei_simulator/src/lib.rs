@@ -1,40 +1,291 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::num::NonZeroUsize;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::mpsc::Sender;
 use std::thread::{JoinHandle};
-use rand::random;
-use safeboy::types::{DirectAccess, Key, Model};
+use std::time::{Duration, Instant, SystemTime};
+use rand::rngs::StdRng;
+use rand::{random, Rng, SeedableRng};
+use safeboy::types::{DirectAccess, Model};
+use import::SaveStateFormat;
 
+pub mod battle;
+mod boot;
+mod checkpoint;
 mod data;
+mod game;
+pub mod import;
+pub mod input;
+pub mod json;
+pub mod report;
+mod rom;
+mod validate;
 
-#[derive(Copy, Clone)]
-enum Game {
-    Yellow,
-    Red,
-    Blue,
+pub use game::{Addresses, Game};
+pub use validate::{Severity, StateIssue};
+pub use data::{Effectiveness, Gen1TrainerClass, Generation, Language, MoveInfo, Species, TrainerAiAction, TrainerAiActionKind, TrainerCategory, Type};
+
+/// Result of a single trial.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Outcome {
+    /// The AI selected the move with the given index.
+    Move(u8),
 
-    Gold,
-    Silver,
-    Crystal
+    /// The AI used the item with the given index instead of its selected move.
+    Item(u8)
 }
 
-impl Display for Game {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            Self::Gold => "Pokémon: Gold Version",
-            Self::Silver => "Pokémon: Silver Version",
-            Self::Crystal => "Pokémon: Crystal Version",
-            Self::Yellow => "Pokémon Yellow Version: Special Pikachu Edition",
-            Self::Red => "Pokémon: Red Version",
-            Self::Blue => "Pokémon: Blue Version",
-        };
-        f.write_str(s)
+/// A finished trial, from [`Simulator::set_trial_listener`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Trial {
+    pub outcome: Outcome,
+
+    /// How many frames the trial ran for, from the save state or, once it's been found, the first read of the RNG
+    pub frames: u32,
+
+    /// When the trial finished
+    pub finished: SystemTime
+}
+
+/// Which accesses a [`Watchpoint`] is called for.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WatchKind {
+    Read,
+    Write,
+    ReadWrite
+}
+
+/// A read or write of a watched address, passed to a [`Watchpoint`]'s callback.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MemoryAccess {
+    pub address: u16,
+
+    /// The value written, or the value the game read (which for the RNG is the simulator's random number)
+    pub value: u8,
+    pub write: bool,
+
+    /// Where the CPU was when it accessed the address
+    pub pc: u16
+}
+
+/// A callback for reads or writes of an address during trials, from [`Simulator::add_watchpoint`].
+///
+/// The callback is called from the simulator's threads, possibly from several at once, and slows trials down for as
+/// long as it takes, so it should be quick.
+#[derive(Clone)]
+pub struct Watchpoint {
+    pub address: u16,
+    pub kind: WatchKind,
+    pub callback: Arc<dyn Fn(MemoryAccess) + Send + Sync>
+}
+
+impl Watchpoint {
+    fn matches(&self, address: u16, write: bool) -> bool {
+        self.address == address && match self.kind {
+            WatchKind::Read => !write,
+            WatchKind::Write => write,
+            WatchKind::ReadWrite => true
+        }
+    }
+}
+
+/// Results captured all at once, from [`Simulator::snapshot`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Snapshot {
+    pub results: HashMap<Outcome, u64>,
+
+    /// Total of the counts in `results`
+    pub sample_count: u64,
+
+    /// How long the simulator has been running since it was last started, or how long it ran for if it stopped, not
+    /// counting time spent paused
+    pub elapsed: Duration
+}
+
+/// How one of the simulator's threads is doing, from [`Simulator::thread_statuses`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct ThreadStatus {
+    /// Trials the thread finished since the simulator was last started
+    pub trials: u64,
+
+    /// Whether the thread is still running, which is `false` if it stopped early (e.g. from a timeout)
+    pub running: bool
+}
+
+/// Which rule stopped the simulator before it reached the number of trials, from [`Simulator::stop_cause`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StopCause {
+    /// Every outcome's percentage was known to within [`Simulator::set_precision`]
+    Precision,
+
+    /// No trial finished within [`Simulator::set_timeout`]
+    Timeout,
+
+    /// It ran for [`Simulator::set_max_duration`]
+    MaxDuration
+}
+
+struct ThreadCounter {
+    trials: AtomicU64,
+    running: AtomicBool
+}
+
+/// Trials per second over the last few seconds, worked out from the number of trials whenever it's checked.
+#[derive(Clone, Default)]
+pub struct TrialRate {
+    /// When the number of trials was checked, and what it was
+    samples: VecDeque<(Instant, u64)>
+}
+
+impl TrialRate {
+    const WINDOW: Duration = Duration::from_secs(5);
+
+    /// Record the current number of trials and get the rate, once there's enough to go by.
+    pub fn update(&mut self, trials: u64) -> Option<f64> {
+        let now = Instant::now();
+        self.samples.push_back((now, trials));
+        while self.samples.get(1).is_some_and(|&(time, _)| now - time >= Self::WINDOW) {
+            self.samples.pop_front();
+        }
+
+        let &(first_time, first_trials) = self.samples.front()?;
+        let seconds = (now - first_time).as_secs_f64();
+        (seconds >= 1.0).then(|| trials.saturating_sub(first_trials) as f64 / seconds)
+    }
+}
+
+/// A way to keep track of a simulator from another thread, from [`Simulator::progress`].
+#[derive(Clone)]
+pub struct Progress {
+    inner: Arc<SimulatorInner>
+}
+
+impl Progress {
+    /// Get how many trials are done, including ones from [`Simulator::add_results`].
+    pub fn sample_count(&self) -> u64 {
+        self.inner.sample_count.load(Ordering::Relaxed)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.inner.running_threads.load(Ordering::Relaxed) > 0
+    }
+
+    /// Wait up to `timeout` for the simulator to finish on its own, e.g. after reaching the number of trials.
+    ///
+    /// Returns `true` if it isn't running anymore.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now().checked_add(timeout);
+        let (lock, thread_stopped) = &self.inner.thread_stopped;
+        let mut guard = lock.lock().unwrap();
+        while self.is_running() {
+            guard = match deadline {
+                Some(deadline) => {
+                    let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                        return false;
+                    };
+                    thread_stopped.wait_timeout(guard, remaining).unwrap().0
+                },
+                // too far away to be reached
+                None => thread_stopped.wait(guard).unwrap()
+            };
+        }
+        true
+    }
+}
+
+/// Result of [`Simulator::probe`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Probe {
+    /// How many frames were run
+    pub frames: u32,
+
+    /// The frame the game first read the random number generator on, if it did
+    pub rng_frame: Option<u32>,
+
+    /// The frame the AI made its decision on, if it did
+    pub decision_frame: Option<u32>,
+
+    /// The frame the player selected a move on before the AI made its decision, if it did (Gen 2 only)
+    pub player_move_frame: Option<u32>,
+
+    /// What the AI decided, if it did
+    pub outcome: Option<Outcome>
+}
+
+/// A frame shown on the LCD.
+#[derive(Copy, Clone, Debug)]
+pub struct Frame<'a> {
+    pub width: usize,
+    pub height: usize,
+
+    /// Pixels in rows from the top left, as `0xAARRGGBB`
+    pub pixels: &'a [u32]
+}
+
+impl<'a> Frame<'a> {
+    fn of(gameboy: &'a safeboy::Gameboy) -> Self {
+        Self { width: gameboy.get_screen_width(), height: gameboy.get_screen_height(), pixels: gameboy.get_pixel_buffer() }
     }
 }
 
+/// A copy of the LCD, from [`Simulator::decision_screenshot`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Screenshot {
+    pub width: usize,
+    pub height: usize,
+
+    /// Pixels in rows from the top left, as `0xAARRGGBB`
+    pub pixels: Vec<u32>
+}
+
+impl Screenshot {
+    pub fn frame(&self) -> Frame<'_> {
+        Frame { width: self.width, height: self.height, pixels: &self.pixels }
+    }
+}
+
+impl From<Frame<'_>> for Screenshot {
+    fn from(frame: Frame<'_>) -> Self {
+        Self { width: frame.width, height: frame.height, pixels: frame.pixels.to_vec() }
+    }
+}
+
+/// Most Pokémon a party can have.
+const MAX_PARTY_SIZE: u8 = 6;
+
+/// How many frames [`Simulator::validate_state`] waits for the AI's decision.
+const VALIDATE_PROBE_FRAMES: u32 = 60 * 60;
+
+/// If the RNG is first read after this many frames, the save state was probably made mid-animation.
+const MID_ANIMATION_FRAMES: u32 = 60 * 2;
+
+/// How long [`Simulator::start`] looks for the first read of the RNG before starting the threads anyway, in frames.
+const START_WARM_UP_MAX_FRAMES: u32 = 60 * 60;
+
+/// How often to log that the warm-up is still going, in frames.
+const WARM_UP_LOG_FRAMES: u32 = 60 * 5;
+
+/// How many frames to keep running after the AI selects a move when looking for item use.
+const ITEM_FOLLOW_THROUGH_FRAMES: u32 = 60 * 30;
+
+/// [`SimulatorInner::trials`] when there's no number of trials to run
+const NO_TRIAL_LIMIT: u64 = u64::MAX;
+
+/// How many trials to run between checks of [`Simulator::set_precision`], and before the first one, since the
+/// intervals aren't reliable for very few trials.
+pub const PRECISION_CHECK_TRIALS: u64 = 100;
+
+/// How many times to run the Game Boy between checks of [`Simulator::set_timeout`] and
+/// [`Simulator::set_max_duration`].
+const TIME_CHECK_INTERVAL: u32 = 1024;
+
+/// Z-score for 95% confidence
+const Z: f64 = 1.96;
+
 pub struct Simulator {
     inner: Arc<SimulatorInner>,
     threads: Vec<JoinHandle<()>>
@@ -45,14 +296,30 @@ impl Simulator {
         save_state: &[u8],
         trials: Option<u64>
     ) -> Result<Self, SimulatorError> {
-        Self::new_from_vec(rom.to_vec(), save_state.to_vec(), trials)
+        Self::new_from_vec(rom.to_vec(), save_state.to_vec(), trials, None)
     }
 
+    /// Make a simulator from a ROM and save state.
+    ///
+    /// The game is detected from the ROM's title unless `game` is given, e.g. for ROM hacks that change it.
     pub fn new_from_vec(
         rom: Vec<u8>,
         save_state: Vec<u8>,
-        trials: Option<u64>
+        trials: Option<u64>,
+        game: Option<Game>
     ) -> Result<Self, SimulatorError> {
+        let save_state = import::unwrap_retroarch(save_state).map_err(|_| SimulatorError::SaveStateError)?;
+
+        // save states that aren't in a known format might still be old SameBoy save states
+        let format = SaveStateFormat::detect(&save_state).unwrap_or(SaveStateFormat::Bess);
+        let save_state = match format {
+            SaveStateFormat::Bess => save_state,
+            _ => {
+                log::info!("Converting the {format} save state");
+                import::convert(&rom, &save_state, format).map_err(|error| SimulatorError::ImportError { format, error })?
+            }
+        };
+
         let Ok(model) = safeboy::Gameboy::model_for_save_state(&save_state) else {
             return Err(SimulatorError::SaveStateError);
         };
@@ -65,69 +332,755 @@ impl Simulator {
         }
 
         let title = gameboy.get_rom_title();
-        let game = match title.as_str() {
-            "POKEMON YELLOW" => Game::Yellow,
-            "POKEMON RED" => Game::Red,
-            "POKEMON BLUE" => Game::Blue,
-            "POKEMON_GLDAAUE" => Game::Gold,
-            "POKEMON_SLVAAXE" => Game::Silver,
-            "PM_CRYSTAL" => Game::Crystal,
-            n => {
-                return Err(SimulatorError::UnknownGame {
-                    name_len: n.len(),
-                    game: {
-                        let mut data = [0u8; 64];
-                        data[..n.len()].copy_from_slice(n.as_bytes());
-                        data
-                    }
-                })
-            }
+        let Some(game) = game.or_else(|| Game::from_rom_title(&title)) else {
+            let n = title.as_str();
+            return Err(SimulatorError::UnknownGame {
+                name_len: n.len(),
+                game: {
+                    let mut data = [0u8; 64];
+                    data[..n.len()].copy_from_slice(n.as_bytes());
+                    data
+                }
+            })
         };
 
+        let (language, rom_move_names) = rom::read_move_names(&rom)
+            .unwrap_or_else(|| (rom::header_language(&rom).unwrap_or(Language::English), Vec::new()));
+
+        let state_hash = AtomicU64::new(hash_state(&rom, &save_state));
+
         Ok(Self {
             inner: Arc::new(SimulatorInner {
                 model,
                 rom,
+                format,
+                state_hash,
+                language,
+                rom_move_names,
                 save_state: Mutex::new(Arc::new(save_state)),
+                unpatched: Mutex::new(None),
                 sample_count: AtomicU64::new(0),
-                trials,
-                results: Mutex::new(Default::default()),
+                trials: AtomicU64::new(trials.unwrap_or(NO_TRIAL_LIMIT)),
+                results: Results::new(),
+                detect_items: AtomicBool::new(false),
+                input_strategy: Mutex::new(Default::default()),
+                trial_listener: Mutex::new(None),
+                watchpoints: Mutex::new(Vec::new()),
+                seed: Mutex::new(None),
+                stop_rules: Mutex::new(Default::default()),
+                stop_cause: Mutex::new(None),
+                warm: AtomicBool::new(false),
                 stop: AtomicBool::new(false),
+                paused: AtomicBool::new(false),
+                resumed: (Mutex::new(()), Condvar::new()),
+                warming: (Mutex::new(false), Condvar::new()),
                 running_threads: AtomicUsize::new(0),
+                thread_stopped: (Mutex::new(()), Condvar::new()),
+                thread_counters: Mutex::new(Arc::from([])),
+                run_time: Mutex::new(Default::default()),
+                addresses: Mutex::new(*game.addresses()),
                 game,
             }),
             threads: Vec::new()
         })
     }
 
+    /// Boot the game from power-on with its battery save, then run `script` to get into the battle.
+    ///
+    /// The state the game is in once the script finishes is used as the save state, so the script should end
+    /// where a save state would be made by hand.
+    pub fn new_from_battery_save(
+        rom: Vec<u8>,
+        battery_save: &[u8],
+        script: &input::InputScript,
+        trials: Option<u64>,
+        game: Option<Game>
+    ) -> Result<Self, SimulatorError> {
+        // CGB-compatible games (Gold, Silver, and Crystal) need to boot on a Game Boy Color to run in colour mode
+        let cgb = rom.get(0x143).is_some_and(|flags| flags & 0x80 != 0);
+        let model = if cgb { Model::CGBE } else { Model::DMGB };
+
+        let mut gameboy = safeboy::Gameboy::new(model);
+        gameboy.load_boot_rom_from_buffer(&boot::boot_rom(cgb));
+        gameboy.load_rom_from_buffer(&rom);
+        gameboy.reset();
+        gameboy.load_sram_from_buffer(battery_save);
+        gameboy.set_turbo_mode(true, true);
+
+        script.run(&mut gameboy);
+
+        let save_state = gameboy.read_save_state_to_vec();
+        Self::new_from_vec(rom, save_state, trials, game)
+    }
+
+    /// Make a simulator from a state returned by [`Simulator::warm_state`], so threads don't have to look for
+    /// the first RNG read again.
+    pub fn new_from_warm_state(
+        rom: Vec<u8>,
+        warm_state: Vec<u8>,
+        trials: Option<u64>,
+        game: Option<Game>
+    ) -> Result<Self, SimulatorError> {
+        let simulator = Self::new_from_vec(rom, warm_state, trials, game)?;
+        simulator.inner.warm.swap(true, Ordering::Relaxed);
+        Ok(simulator)
+    }
+
+    /// Make a simulator from a [`Simulator::checkpoint`] and the ROM it was made with, carrying on with the results
+    /// and warm state it had.
+    ///
+    /// Settings like the addresses and stop rules aren't in the checkpoint, so they have to be set again.
+    pub fn new_from_checkpoint(
+        rom: Vec<u8>,
+        checkpoint: &[u8],
+        trials: Option<u64>,
+        game: Option<Game>
+    ) -> Result<Self, SimulatorError> {
+        let checkpoint = checkpoint::Checkpoint::from_bytes(checkpoint).ok_or(SimulatorError::CheckpointError)?;
+        let mut simulator = Self::new_from_vec(rom, checkpoint.save_state, trials, game)?;
+        simulator.inner.warm.swap(checkpoint.warm, Ordering::Relaxed);
+        simulator.inner.state_hash.swap(checkpoint.state_hash, Ordering::Relaxed);
+        simulator.add_results(checkpoint.results);
+        Ok(simulator)
+    }
+
+    /// Save the results so far and the save state (warmed up, if it has been) so the run can be carried on later
+    /// with [`Simulator::new_from_checkpoint`], even after the program exits.
+    ///
+    /// This can be called while running; trials that finish afterwards aren't in it.
+    pub fn checkpoint(&self) -> Vec<u8> {
+        // threads store the warm state before setting the flag, so holding the lock means a cold state is never marked warm
+        let save_state = self.inner.save_state.lock().unwrap();
+        let warm = self.inner.warm.load(Ordering::Relaxed);
+        checkpoint::Checkpoint {
+            state_hash: self.state_hash(),
+            warm,
+            results: self.results().into_iter().collect(),
+            save_state: save_state.to_vec()
+        }.to_bytes()
+    }
+
     pub fn is_running(&self) -> bool {
         self.inner.running_threads.load(Ordering::Relaxed) > 0
     }
 
+    /// Get how many trials are done, including ones from [`Simulator::add_results`].
+    pub fn sample_count(&self) -> u64 {
+        self.inner.sample_count.load(Ordering::Relaxed)
+    }
+
+    /// Get a handle for keeping track of the simulator from another thread.
+    pub fn progress(&self) -> Progress {
+        Progress { inner: self.inner.clone() }
+    }
+
+    /// Get the save state each trial starts from, once a thread has found the game's first read of the RNG.
+    ///
+    /// Returns `None` if it hasn't been found yet. This can be saved and passed to
+    /// [`Simulator::new_from_warm_state`] to skip the warm-up the next time the same battle is simulated.
+    pub fn warm_state(&self) -> Option<Vec<u8>> {
+        if !self.inner.warm.load(Ordering::Relaxed) {
+            return None;
+        }
+        Some(self.inner.save_state.lock().unwrap().to_vec())
+    }
+
+    /// Start trials from a state [`Simulator::warm_state`] returned for the same save state, skipping the warm-up.
+    ///
+    /// Unlike [`Simulator::new_from_warm_state`], this keeps the state hash of the loaded save state. Returns `false`
+    /// (leaving the simulator as it was) if the state can't be loaded.
+    pub fn set_warm_state(&mut self, warm_state: Vec<u8>) -> bool {
+        assert!(!self.is_running(), "can't change the warm state while running");
+        let mut gameboy = safeboy::Gameboy::new(self.inner.model);
+        gameboy.load_rom_from_buffer(&self.inner.rom);
+        if gameboy.load_state_from_buffer(&warm_state).is_err() {
+            return false;
+        }
+        *self.inner.save_state.lock().unwrap() = Arc::new(warm_state);
+        self.inner.warm.swap(true, Ordering::Relaxed);
+        *self.inner.unpatched.lock().unwrap() = None;
+        true
+    }
+
+    /// Look for the game's first read of the RNG on the calling thread for up to `max_frames` frames, so trials start
+    /// from there and [`Simulator::warm_state`] returns it without simulating anything.
+    ///
+    /// Returns how many frames in it was found, or `None` if it wasn't found in time.
+    pub fn warm_up(&mut self, max_frames: u32) -> Option<u32> {
+        assert!(!self.is_running(), "can't warm up while running");
+        if self.inner.warm.load(Ordering::Relaxed) {
+            return Some(0);
+        }
+        self.inner.stop.swap(false, Ordering::Relaxed);
+        self.inner.warm_up(max_frames, false)
+    }
+
+    /// Add results from an earlier run of the same battle (e.g. read back from a file) as if they were simulated, so
+    /// they count towards the number of trials to run.
+    pub fn add_results(&mut self, results: impl IntoIterator<Item = (Outcome, u64)>) {
+        for (outcome, count) in results {
+            self.inner.results.add(outcome, count);
+            self.inner.sample_count.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    /// Throw away the results so far, including ones from [`Simulator::add_results`], so the number of trials to run
+    /// starts over.
+    ///
+    /// This can be called while running, in which case the trials carry on from zero.
+    pub fn reset_results(&mut self) {
+        self.inner.results.clear();
+        self.inner.sample_count.swap(0, Ordering::Relaxed);
+    }
+
     /// Get current results.
-    pub fn results(&self) -> HashMap<u8, u64> {
-        self.inner.results.lock().unwrap().clone()
+    pub fn results(&self) -> HashMap<Outcome, u64> {
+        self.inner.results.to_map()
+    }
+
+    /// Get the current results along with their total and how long the simulator has been running, all from the
+    /// same moment, so trials finishing in between can't skew percentages worked out from them.
+    pub fn snapshot(&self) -> Snapshot {
+        let results = self.inner.results.to_map();
+        let elapsed = self.inner.run_time.lock().unwrap().elapsed();
+        Snapshot { sample_count: results.values().sum(), results, elapsed }
+    }
+
+    /// Get the full name of the loaded game.
+    pub fn game_name(&self) -> &'static str {
+        self.inner.game.name()
+    }
+
+    /// Get the generation of the loaded game.
+    pub fn generation(&self) -> Generation {
+        self.inner.game.generation()
+    }
+
+    /// Decode the battle in the loaded save state.
+    ///
+    /// Returns `None` if the save state is not in a battle.
+    pub fn battle_context(&self) -> Option<battle::BattleContext> {
+        let mut gameboy = self.load_gameboy();
+        let generation = self.generation();
+        let addresses = self.addresses();
+
+        let battle_mode = gameboy.safe_read_memory(addresses.battle_mode);
+        if battle_mode != 1 && battle_mode != 2 {
+            return None;
+        }
+
+        let mon = |gameboy: &mut safeboy::Gameboy, address: u16| {
+            battle::BattleMon::parse(generation, &read_memory(gameboy, address, battle::BattleMon::size(generation)))
+        };
+        let stages = |gameboy: &mut safeboy::Gameboy, address: u16| {
+            battle::StatStages::parse(generation, &read_memory(gameboy, address, battle::StatStages::size(generation)))
+        };
+
+        Some(battle::BattleContext {
+            trainer_battle: battle_mode == 2,
+            player: mon(&mut gameboy, addresses.player_battle_mon)?,
+            player_stat_stages: stages(&mut gameboy, addresses.player_stat_stages)?,
+            enemy: mon(&mut gameboy, addresses.enemy_battle_mon)?,
+            enemy_stat_stages: stages(&mut gameboy, addresses.enemy_stat_stages)?,
+            badges: match generation {
+                Generation::One => gameboy.safe_read_memory(addresses.badges) as u16,
+                Generation::Two => u16::from_le_bytes(read_memory(&mut gameboy, addresses.badges, 2).try_into().unwrap())
+            }
+        })
+    }
+
+    /// Run a single trial on the calling thread for up to `max_frames` frames and report what happened.
+    ///
+    /// This is meant for diagnosing save states where the AI never seems to make a decision.
+    pub fn probe(&self, max_frames: u32) -> Probe {
+        self.playback(max_frames, |_| true)
+    }
+
+    /// Run a single trial like [`Simulator::probe`], calling `on_frame` with each frame shown on the LCD.
+    ///
+    /// This is meant for watching what a trial does. Return `false` from `on_frame` to stop early.
+    pub fn playback(&self, max_frames: u32, on_frame: impl FnMut(Frame<'_>) -> bool) -> Probe {
+        self.run_probe(max_frames, on_frame, |_| ())
+    }
+
+    /// Run a single trial like [`Simulator::probe`] and capture the LCD the moment the AI makes its decision.
+    ///
+    /// The screen is usually partway through being drawn at that point. Returns `None` if the AI doesn't decide
+    /// within `max_frames` frames.
+    pub fn decision_screenshot(&self, max_frames: u32) -> Option<Screenshot> {
+        let mut screenshot = None;
+        self.run_probe(max_frames, |_| true, |frame| screenshot = Some(Screenshot::from(frame)));
+        screenshot
+    }
+
+    fn run_probe(&self, max_frames: u32, mut on_frame: impl FnMut(Frame<'_>) -> bool, on_decision: impl FnOnce(Frame<'_>)) -> Probe {
+        let mut gameboy = self.load_gameboy();
+        gameboy.set_turbo_mode(true, true);
+        gameboy.set_rendering_disabled(false);
+        let addresses = self.addresses();
+        install_rules(&mut gameboy, self.inner.game, addresses, false);
+
+        let flags = TrialFlags::attach(&mut gameboy, addresses, None, Default::default());
+        let mut auto_input = input::AutoInput::new(self.inner.input_strategy.lock().unwrap().clone(), &addresses);
+        let mut probe = Probe::default();
+
+        while probe.frames < max_frames {
+            if auto_input.update(&mut gameboy) {
+                probe.frames += 1;
+                if !on_frame(Frame::of(&gameboy)) {
+                    break;
+                }
+            }
+
+            if probe.rng_frame.is_none() && flags.rng_hit.load(Ordering::Relaxed) {
+                probe.rng_frame = Some(probe.frames);
+            }
+            if probe.player_move_frame.is_none() && flags.player_move_selected.load(Ordering::Relaxed) {
+                probe.player_move_frame = Some(probe.frames);
+            }
+
+            let item = flags.item_used.load(Ordering::Relaxed);
+            let decision = flags.decision_made.load(Ordering::Relaxed);
+            if item != 0 || decision != 0 {
+                probe.decision_frame = Some(probe.frames);
+                probe.outcome = Some(if item != 0 { Outcome::Item(item) } else { Outcome::Move(decision) });
+                on_decision(Frame::of(&gameboy));
+                break;
+            }
+
+            gameboy.run();
+        }
+
+        if probe.decision_frame.is_none() && probe.frames >= max_frames {
+            log::debug!("Gave up waiting for the AI's decision after {max_frames} frames");
+        }
+        probe
+    }
+
+    /// Check the loaded save state for anything that would stop the simulator from giving useful results.
+    ///
+    /// This runs a short probe (see [`Simulator::probe`]) on the calling thread.
+    pub fn validate_state(&self) -> Vec<StateIssue> {
+        let mut issues = Vec::new();
+        let mut gameboy = self.load_gameboy();
+        let addresses = self.addresses();
+
+        match gameboy.safe_read_memory(addresses.battle_mode) {
+            1 => issues.push(StateIssue::WildBattle),
+            2 => (),
+            _ => {
+                issues.push(StateIssue::NotInBattle);
+                return issues;
+            }
+        }
+
+        let status = read_memory(&mut gameboy, addresses.enemy_battle_status, 5);
+        if validate::enemy_locked_in(self.generation(), &status) {
+            issues.push(StateIssue::EnemyLockedIn);
+        }
+
+        let probe = self.probe(VALIDATE_PROBE_FRAMES);
+        if probe.player_move_frame.is_some() {
+            issues.push(StateIssue::MenuOpen);
+        }
+        else if probe.decision_frame.is_none() {
+            issues.push(StateIssue::NoDecision { frames: probe.frames });
+        }
+        if let Some(frames) = probe.rng_frame.filter(|&f| f > MID_ANIMATION_FRAMES) {
+            issues.push(StateIssue::MidAnimation { frames });
+        }
+
+        issues
+    }
+
+    /// Get the memory addresses the simulator uses for the loaded game.
+    pub fn addresses(&self) -> Addresses {
+        *self.inner.addresses.lock().unwrap()
+    }
+
+    /// Use different memory addresses for the loaded game, e.g. for ROM hacks that move things around.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_addresses(&mut self, addresses: Addresses) {
+        *self.inner.addresses.lock().unwrap() = addresses;
+    }
+
+    /// Get the name of the Game Boy model the save state was made on.
+    pub fn model_name(&self) -> &'static str {
+        match self.inner.model {
+            Model::DMGB => "Game Boy (DMG-B)",
+            Model::SGBNTSC | Model::SGBNTSCNoSFC => "Super Game Boy (NTSC)",
+            Model::SGBPAL | Model::SGBPALNoSFC => "Super Game Boy (PAL)",
+            Model::SGB2 | Model::SGB2NoSFC => "Super Game Boy 2",
+            Model::MGB => "Game Boy Pocket",
+            Model::CGB0 => "Game Boy Color (CPU CGB 0)",
+            Model::CGBA => "Game Boy Color (CPU CGB A)",
+            Model::CGBB => "Game Boy Color (CPU CGB B)",
+            Model::CGBC => "Game Boy Color (CPU CGB C)",
+            Model::CGBD => "Game Boy Color (CPU CGB D)",
+            Model::CGBE => "Game Boy Color (CPU CGB E)",
+            Model::AGBA => "Game Boy Advance",
+            Model::GBPA => "Game Boy Player"
+        }
+    }
+
+    /// Get a hash of the ROM and save state the simulator started from, for telling whether two sets of results
+    /// are for the same thing.
+    ///
+    /// Running an input script changes this, since it changes the save state.
+    pub fn state_hash(&self) -> u64 {
+        self.inner.state_hash.load(Ordering::Relaxed)
+    }
+
+    /// Get the emulator the save state was made with. Save states from other emulators are converted when the
+    /// simulator is made.
+    pub fn save_state_format(&self) -> SaveStateFormat {
+        self.inner.format
+    }
+
+    /// Get the opposing trainer's class and ID.
+    ///
+    /// Returns `None` if the save state is not in a trainer battle.
+    pub fn opponent(&self) -> Option<battle::Opponent> {
+        let mut gameboy = self.load_gameboy();
+        let addresses = self.addresses();
+
+        if gameboy.safe_read_memory(addresses.battle_mode) != 2 {
+            return None;
+        }
+
+        let class = gameboy.safe_read_memory(addresses.trainer_class);
+        let (name, category) = match data::trainer_class(self.generation(), class) {
+            Some((name, category)) => (Some(name), category),
+            None => (None, TrainerCategory::Trainer)
+        };
+        Some(battle::Opponent {
+            class,
+            id: gameboy.safe_read_memory(addresses.trainer_id),
+            name,
+            category
+        })
+    }
+
+    /// Decode the opposing trainer's party, including any Pokémon that have fainted.
+    ///
+    /// Returns `None` if the save state is not in a trainer battle.
+    pub fn enemy_party(&self) -> Option<Vec<battle::PartyMon>> {
+        let mut gameboy = self.load_gameboy();
+        let generation = self.generation();
+        let addresses = self.addresses();
+
+        if gameboy.safe_read_memory(addresses.battle_mode) != 2 {
+            return None;
+        }
+
+        let count = gameboy.safe_read_memory(addresses.enemy_party_count).min(MAX_PARTY_SIZE) as usize;
+        let size = battle::PartyMon::size(generation);
+        let data = read_memory(&mut gameboy, addresses.enemy_party_mons, size * count);
+        data.chunks_exact(size).map(|mon| battle::PartyMon::parse(generation, mon)).collect()
+    }
+
+    /// Make a new Game Boy with the ROM and save state loaded.
+    fn load_gameboy(&self) -> safeboy::Gameboy {
+        let mut gameboy = safeboy::Gameboy::new(self.inner.model);
+        gameboy.load_rom_from_buffer(&self.inner.rom);
+        let save_state = Arc::clone(&self.inner.save_state.lock().unwrap());
+        gameboy.load_state_from_buffer(&save_state).unwrap();
+        gameboy
+    }
+
+    /// Get the language of the loaded game.
+    ///
+    /// This is detected from the ROM's move name table, or from the ROM's header if it can't be found, falling back to
+    /// English.
+    pub fn language(&self) -> Language {
+        self.inner.language
+    }
+
+    /// Get the name of a trial outcome, or `None` if the index is unknown.
+    ///
+    /// Move names are in the game's language. In other languages than English, the ROM's own move name table is
+    /// used first, so the names match the game even if its translation differs from the built-in table. In English
+    /// games, moves that aren't in the built-in table for the game's generation (e.g. ones added by a ROM hack) are
+    /// looked up in the ROM's table. Item names are always English.
+    pub fn outcome_name(&self, outcome: Outcome) -> Option<&str> {
+        match outcome {
+            Outcome::Move(index) => {
+                let rom_name = self.inner.rom_move_names.get(index as usize).and_then(|n| n.as_deref());
+                let built_in = match self.generation() {
+                    Generation::One if index > data::MoveType::Struggle as u8 => None,
+                    _ => localized_move_name(self.inner.language, index)
+                };
+                if self.inner.language != Language::English {
+                    return rom_name.or(built_in);
+                }
+                built_in.or(rom_name)
+            },
+            Outcome::Item(index) => item_name(self.generation(), index)
+        }
+    }
+
+    /// Set whether or not to keep running each trial after the AI selects a move to see if it uses an item
+    /// instead.
+    ///
+    /// This makes trials considerably slower, and it is currently only supported in Gen 1. Returns `false` if
+    /// the game does not support it.
+    pub fn set_detect_items(&mut self, detect_items: bool) -> bool {
+        if detect_items && !self.inner.game.supports_item_detection() {
+            return false;
+        }
+        self.inner.detect_items.swap(detect_items, Ordering::Relaxed);
+        true
+    }
+
+    /// Run an input script on the save state and use wherever it ends up as the new save state.
+    ///
+    /// This is for save states made a little too early (e.g. during text, or before a menu is open). The script
+    /// runs without randomizing the RNG, so it should stop before the AI makes its decision.
+    pub fn run_input_script(&mut self, script: &input::InputScript) {
+        assert!(!self.is_running(), "already running");
+        let mut gameboy = self.load_gameboy();
+        gameboy.set_turbo_mode(true, true);
+        script.run(&mut gameboy);
+        self.replace_save_state(gameboy.read_save_state_to_vec());
+        *self.inner.unpatched.lock().unwrap() = None;
+    }
+
+    /// Change the battle in the save state, e.g. to see what the AI would do if its Pokémon had less HP.
+    ///
+    /// This can be undone with [`Simulator::clear_patches`]. Since the results so far are from a different battle,
+    /// they are cleared. Returns `false` without changing anything if the save state isn't in a battle.
+    pub fn patch_battle(&mut self, patches: &[battle::BattlePatch]) -> bool {
+        assert!(!self.is_running(), "already running");
+        let Some(context) = self.battle_context() else {
+            return false;
+        };
+        self.inner.unpatched.lock().unwrap().get_or_insert_with(|| Unpatched {
+            save_state: self.inner.save_state.lock().unwrap().clone(),
+            warm: self.inner.warm.load(Ordering::Relaxed),
+            state_hash: self.inner.state_hash.load(Ordering::Relaxed)
+        });
+        self.reset_results();
+        let addresses = self.addresses();
+        let mut gameboy = self.load_gameboy();
+        for &patch in patches {
+            let (address, mon) = match patch.side() {
+                Some(battle::Side::Enemy) => (addresses.enemy_battle_mon, &context.enemy),
+                _ => (addresses.player_battle_mon, &context.player)
+            };
+            for (address, value) in patch.writes(self.generation(), address, mon) {
+                gameboy.write_memory(address, value);
+            }
+        }
+        self.replace_save_state(gameboy.read_save_state_to_vec());
+        true
+    }
+
+    /// Go back to the save state from before [`Simulator::patch_battle`], clearing the results like it does.
+    ///
+    /// Returns `false` without changing anything if it wasn't patched.
+    pub fn clear_patches(&mut self) -> bool {
+        assert!(!self.is_running(), "already running");
+        let Some(unpatched) = self.inner.unpatched.lock().unwrap().take() else {
+            return false;
+        };
+        *self.inner.save_state.lock().unwrap() = unpatched.save_state;
+        self.inner.warm.swap(unpatched.warm, Ordering::Relaxed);
+        self.inner.state_hash.swap(unpatched.state_hash, Ordering::Relaxed);
+        self.reset_results();
+        true
+    }
+
+    /// Use a new save state, which also has to be warmed up again.
+    fn replace_save_state(&mut self, save_state: Vec<u8>) {
+        self.inner.state_hash.swap(hash_state(&self.inner.rom, &save_state), Ordering::Relaxed);
+        *self.inner.save_state.lock().unwrap() = Arc::new(save_state);
+        self.inner.warm.swap(false, Ordering::Relaxed);
+    }
+
+    /// Set what to press while waiting for the AI. By default, A is mashed to get through text, and the cursor is
+    /// moved to FIGHT if the battle menu is open (see [`input::InputStrategy::Navigate`]).
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_input_strategy(&mut self, strategy: input::InputStrategy) {
+        *self.inner.input_strategy.lock().unwrap() = strategy;
+    }
+
+    /// Send every finished trial to `listener`, or stop if `None`.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_trial_listener(&mut self, listener: Option<Sender<Trial>>) {
+        *self.inner.trial_listener.lock().unwrap() = listener;
+    }
+
+    /// Call a watchpoint's callback whenever its address is accessed during a trial, in addition to the simulator's
+    /// own memory callbacks.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.inner.watchpoints.lock().unwrap().push(watchpoint);
+    }
+
+    /// Remove all watchpoints added with [`Simulator::add_watchpoint`].
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn clear_watchpoints(&mut self) {
+        self.inner.watchpoints.lock().unwrap().clear();
+    }
+
+    /// Seed the RNG the game reads, or use random numbers if `None`.
+    ///
+    /// Each thread gets its own sequence from the seed, so results only repeat exactly with one thread. This takes
+    /// effect the next time the simulator is started.
+    pub fn set_seed(&mut self, seed: Option<u64>) {
+        *self.inner.seed.lock().unwrap() = seed;
+    }
+
+    /// Get the number of trials to run, or `None` if it runs until stopped.
+    pub fn trials(&self) -> Option<u64> {
+        Some(self.inner.trials.load(Ordering::Relaxed)).filter(|&t| t != NO_TRIAL_LIMIT)
+    }
+
+    /// Change the number of trials to run, or run until stopped if `None`. This counts the trials already done, so
+    /// to run 10,000 more, add 10,000 to [`Simulator::sample_count`].
+    ///
+    /// This can be called while running and takes effect straight away, though a simulator that already stopped from
+    /// reaching the number of trials has to be started again.
+    pub fn set_trials(&mut self, trials: Option<u64>) {
+        self.inner.trials.swap(trials.unwrap_or(NO_TRIAL_LIMIT), Ordering::Relaxed);
+    }
+
+    /// Stop once every outcome's percentage is known to within `precision` (a fraction, e.g. 0.005 for ±0.5%) with
+    /// 95% confidence, or never if `None`.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_precision(&mut self, precision: Option<f64>) {
+        self.inner.stop_rules.lock().unwrap().precision = precision;
+    }
+
+    /// Stop if no trial finishes within `timeout` of starting, e.g. because the save state isn't where the AI
+    /// decides, or never if `None`. Trials from earlier runs and [`Simulator::add_results`] don't count.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.inner.stop_rules.lock().unwrap().timeout = timeout;
+    }
+
+    /// Stop after running for `max_duration` no matter how many trials are done, or never if `None`.
+    ///
+    /// This takes effect the next time the simulator is started.
+    pub fn set_max_duration(&mut self, max_duration: Option<Duration>) {
+        self.inner.stop_rules.lock().unwrap().max_duration = max_duration;
+    }
+
+    /// Get which of the rules from [`Simulator::set_precision`] and the like stopped the simulator the last time it
+    /// ran, if one did.
+    pub fn stop_cause(&self) -> Option<StopCause> {
+        *self.inner.stop_cause.lock().unwrap()
     }
 
     /// Run the simulator with the given thread count.
+    ///
+    /// If it isn't warm, the game's first read of the RNG is looked for on one thread before the rest are started, so
+    /// they all start from there. After a minute of game time, they're started anyway and one of them keeps looking
+    /// while the others wait.
     pub fn start(&mut self, thread_count: NonZeroUsize) {
         assert!(!self.is_running(), "already running");
         self.inner.stop.swap(false, Ordering::Relaxed);
-        for _ in 0..thread_count.get() {
-            let inner_cloned = self.inner.clone();
-            self.inner.running_threads.fetch_add(1, Ordering::Relaxed);
-            self.threads.push(std::thread::spawn(move || {
-                simulate(inner_cloned.clone());
-                inner_cloned.running_threads.fetch_sub(1, Ordering::Relaxed);
-            }))
+        self.inner.paused.swap(false, Ordering::Relaxed);
+        *self.inner.stop_cause.lock().unwrap() = None;
+        log::info!("Starting {thread_count} thread{}", if thread_count.get() == 1 { "" } else { "s" });
+        if self.inner.warm.load(Ordering::Relaxed) {
+            log::info!("Starting from a warm state, so there's no warm-up");
+        }
+        let started = Instant::now();
+        *self.inner.run_time.lock().unwrap() = RunTime { started: Some(started), ..Default::default() };
+        let counters: Arc<[ThreadCounter]> = (0..thread_count.get()).map(|_| ThreadCounter { trials: AtomicU64::new(0), running: AtomicBool::new(true) }).collect();
+        *self.inner.thread_counters.lock().unwrap() = counters.clone();
+
+        // This counts as a running thread until the others are started, so the simulator is running (and can be
+        // stopped) during the warm-up.
+        let inner = self.inner.clone();
+        self.inner.running_threads.fetch_add(1, Ordering::Relaxed);
+        self.threads.push(std::thread::spawn(move || {
+            if !inner.warm.load(Ordering::Relaxed) {
+                inner.warm_up(START_WARM_UP_MAX_FRAMES, true);
+            }
+            let threads: Vec<JoinHandle<()>> = if inner.stop.load(Ordering::Relaxed) {
+                counters.iter().for_each(|counter| counter.running.store(false, Ordering::Relaxed));
+                Vec::new()
+            }
+            else {
+                (0..thread_count.get()).map(|thread| {
+                    let inner = inner.clone();
+                    let counters = counters.clone();
+                    inner.running_threads.fetch_add(1, Ordering::Relaxed);
+                    std::thread::spawn(move || {
+                        log::debug!("Thread {thread} started");
+                        simulate(inner.clone(), thread, &counters[thread].trials);
+                        counters[thread].running.store(false, Ordering::Relaxed);
+                        inner.thread_stopped(started);
+                        log::debug!("Thread {thread} stopped");
+                    })
+                }).collect()
+            };
+            inner.thread_stopped(started);
+            for thread in threads {
+                let _ = thread.join();
+            }
+        }));
+    }
+
+    /// Wait up to `timeout` for the simulator to finish on its own, e.g. after reaching the number of trials.
+    ///
+    /// Returns `true` if it isn't running anymore.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        self.progress().wait(timeout)
+    }
+
+    /// Get how each thread from the last time the simulator was started is doing, in the order they were started.
+    pub fn thread_statuses(&self) -> Vec<ThreadStatus> {
+        self.inner
+            .thread_counters
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|counter| ThreadStatus { trials: counter.trials.load(Ordering::Relaxed), running: counter.running.load(Ordering::Relaxed) })
+            .collect()
+    }
+
+    /// Pause the simulator after the trials it's in the middle of, until [`Simulator::resume`] is called.
+    ///
+    /// Does nothing if it isn't running.
+    pub fn pause(&self) {
+        let _guard = self.inner.resumed.0.lock().unwrap();
+        if !self.is_running() || self.inner.paused.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        self.inner.run_time.lock().unwrap().pause();
+        log::info!("Paused");
+    }
+
+    pub fn resume(&self) {
+        let (lock, resumed) = &self.inner.resumed;
+        let _guard = lock.lock().unwrap();
+        if !self.inner.paused.swap(false, Ordering::Relaxed) {
+            return;
         }
+        self.inner.run_time.lock().unwrap().resume();
+        resumed.notify_all();
+        log::info!("Resumed");
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_running() && self.inner.paused.load(Ordering::Relaxed)
     }
 
     pub fn stop(&mut self) {
         if !self.is_running() {
             return;
         }
-        self.inner.stop.swap(true, Ordering::Relaxed);
+        self.inner.request_stop();
         for t in self.threads.drain(..) {
             let _ = t.join();
         }
@@ -137,7 +1090,9 @@ impl Simulator {
 #[derive(Copy, Clone)]
 pub enum SimulatorError {
     SaveStateError,
-    UnknownGame { game: [u8; 64], name_len: usize }
+    UnknownGame { game: [u8; 64], name_len: usize },
+    ImportError { format: SaveStateFormat, error: import::ImportError },
+    CheckpointError
 }
 
 impl Drop for Simulator {
@@ -153,7 +1108,9 @@ impl Display for SimulatorError {
             SimulatorError::UnknownGame { game, name_len } => {
                 let game_name = std::str::from_utf8(&game[..*name_len]).unwrap();
                 f.write_fmt(format_args!("Unknown game {game_name} from ROM"))
-            }
+            },
+            SimulatorError::ImportError { format, error } => write!(f, "Can't convert {format} save state: {error}"),
+            SimulatorError::CheckpointError => f.write_str("Can't read checkpoint")
         }
     }
 }
@@ -161,31 +1118,372 @@ impl Display for SimulatorError {
 struct SimulatorInner {
     model: Model,
     rom: Vec<u8>,
+    format: SaveStateFormat,
+    language: Language,
+    rom_move_names: Vec<Option<String>>,
     save_state: Mutex<Arc<Vec<u8>>>,
+
+    /// The save state from before it was patched, for [`Simulator::clear_patches`]
+    unpatched: Mutex<Option<Unpatched>>,
+    state_hash: AtomicU64,
     sample_count: AtomicU64,
-    trials: Option<u64>,
-    results: Mutex<HashMap<u8, u64>>,
+    /// Number of trials to run, or [`NO_TRIAL_LIMIT`]
+    trials: AtomicU64,
+    results: Results,
+    detect_items: AtomicBool,
+    input_strategy: Mutex<input::InputStrategy>,
+    trial_listener: Mutex<Option<Sender<Trial>>>,
+    watchpoints: Mutex<Vec<Watchpoint>>,
+    seed: Mutex<Option<u64>>,
+    stop_rules: Mutex<StopRules>,
+
+    /// Which of `stop_rules` stopped the last run, if any
+    stop_cause: Mutex<Option<StopCause>>,
+
+    /// Whether `save_state` is at the first read of the RNG
+    warm: AtomicBool,
     running_threads: AtomicUsize,
+
+    /// Notified whenever a thread stops, for [`Simulator::wait`]
+    thread_stopped: (Mutex<()>, Condvar),
+
+    /// One for each thread from the last time the simulator was started
+    thread_counters: Mutex<Arc<[ThreadCounter]>>,
+    run_time: Mutex<RunTime>,
     stop: AtomicBool,
-    game: Game
+
+    /// Whether the threads should wait for [`Simulator::resume`] before starting another trial
+    paused: AtomicBool,
+
+    /// Notified when `paused` is cleared or the simulator is stopped
+    resumed: (Mutex<()>, Condvar),
+
+    /// Whether a thread is looking for the first read of the RNG, notified when it's done or the simulator is stopped
+    warming: (Mutex<bool>, Condvar),
+    game: Game,
+
+    /// The game's addresses, unless they were changed with [`Simulator::set_addresses`]
+    addresses: Mutex<Addresses>
+}
+
+impl SimulatorInner {
+    /// Tell the threads to stop, including any that are paused.
+    fn request_stop(&self) {
+        self.stop.swap(true, Ordering::Relaxed);
+        let (lock, resumed) = &self.resumed;
+        drop(lock.lock().unwrap());
+        resumed.notify_all();
+        let (lock, warmed) = &self.warming;
+        drop(lock.lock().unwrap());
+        warmed.notify_all();
+    }
+
+    /// Tell the threads to stop because of one of the stop rules, unless another one already did.
+    fn stop_for(&self, cause: StopCause) {
+        if !self.stop.load(Ordering::Relaxed) {
+            self.stop_cause.lock().unwrap().get_or_insert(cause);
+        }
+        self.request_stop();
+    }
+
+    /// Get how many trials finished since the simulator was last started, which doesn't count earlier runs or
+    /// [`Simulator::add_results`].
+    fn trials_this_run(&self) -> u64 {
+        self.thread_counters.lock().unwrap().iter().map(|counter| counter.trials.load(Ordering::Relaxed)).sum()
+    }
+
+    /// Record that a thread from the run that began at `started` stopped, and tell anyone waiting for it.
+    fn thread_stopped(&self, started: Instant) {
+        if self.running_threads.fetch_sub(1, Ordering::Relaxed) == 1 {
+            self.run_time.lock().unwrap().stop(started);
+        }
+        let (lock, thread_stopped) = &self.thread_stopped;
+        let _guard = lock.lock().unwrap();
+        thread_stopped.notify_all();
+    }
+
+    /// Look for the game's first read of the RNG for up to `max_frames` frames, and start trials from there if it's
+    /// found. This gives up if the simulator is stopped, or if it runs out of time while `running`.
+    ///
+    /// Returns how many frames in it was found.
+    fn warm_up(&self, max_frames: u32, running: bool) -> Option<u32> {
+        let save_state = Arc::clone(&self.save_state.lock().unwrap());
+        let stop_rules = *self.stop_rules.lock().unwrap();
+
+        // Saving the state before every run to keep the one from just before the read would be slow, so count the
+        // runs it takes, then do all but the last one again and save the state there.
+        let mut gameboy = self.warm_up_gameboy(&save_state);
+        let (flags, mut auto_input) = self.attach_warm_up(&mut gameboy);
+        let mut runs = 0u32;
+        let mut frames = 0;
+        while !flags.rng_hit.load(Ordering::Relaxed) {
+            if frames >= max_frames {
+                log::debug!("Gave up looking for the first read of the random number generator after {max_frames} frames");
+                return None;
+            }
+            if self.stop.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            runs += 1;
+            if running && runs.is_multiple_of(TIME_CHECK_INTERVAL) {
+                let elapsed = self.run_time.lock().unwrap().elapsed();
+                if let Some(cause) = stop_rules.out_of_time(elapsed, self.trials_this_run()) {
+                    log::info!("Stopping after {:.1} seconds", elapsed.as_secs_f64());
+                    self.stop_for(cause);
+                    return None;
+                }
+            }
+
+            if auto_input.update(&mut gameboy) {
+                frames += 1;
+                if frames % WARM_UP_LOG_FRAMES == 0 {
+                    log::debug!("Still looking for the first read of the random number generator after {frames} frames");
+                }
+            }
+            gameboy.run();
+        }
+
+        let mut replay = self.warm_up_gameboy(&save_state);
+        let (replay_flags, mut replay_input) = self.attach_warm_up(&mut replay);
+        for _ in 1..runs {
+            replay_input.update(&mut replay);
+            replay.run();
+        }
+        if replay_flags.rng_hit.load(Ordering::Relaxed) {
+            log::warn!("The game read the random number generator sooner when run again, so leaving the warm-up to the trials");
+            return None;
+        }
+        *self.save_state.lock().unwrap() = Arc::new(replay.read_save_state_to_vec());
+        self.warm.swap(true, Ordering::Relaxed);
+        log::info!("Found the first read of the random number generator after {frames} frames");
+        Some(frames)
+    }
+
+    /// Make a Game Boy at `save_state` for [`SimulatorInner::warm_up`].
+    fn warm_up_gameboy(&self, save_state: &[u8]) -> safeboy::Gameboy {
+        let mut gameboy = safeboy::Gameboy::new(self.model);
+        gameboy.load_rom_from_buffer(&self.rom);
+        gameboy.load_state_from_buffer(save_state).unwrap();
+        gameboy.set_turbo_mode(true, true);
+        gameboy.set_rendering_disabled(false);
+        gameboy
+    }
+
+    /// Give a Game Boy the memory callbacks for [`SimulatorInner::warm_up`].
+    ///
+    /// The Game Boy can't be moved after this, since the callbacks point to it.
+    fn attach_warm_up(&self, gameboy: &mut safeboy::Gameboy) -> (TrialFlags, input::AutoInput) {
+        let addresses = *self.addresses.lock().unwrap();
+        install_rules(gameboy, self.game, addresses, false);
+        let flags = TrialFlags::attach(gameboy, addresses, None, Default::default());
+        (flags, input::AutoInput::new(self.input_strategy.lock().unwrap().clone(), &addresses))
+    }
+
+    /// Get whether the calling thread should look for the first read of the RNG, which is `false` if another thread
+    /// already found it (waiting for it if it's looking) or the simulator is stopping.
+    ///
+    /// If it's `true`, the thread has to hold a [`WarmUpClaim`] until it finds it or gives up.
+    fn claim_warm_up(&self) -> bool {
+        let (lock, warmed) = &self.warming;
+        let mut warming = lock.lock().unwrap();
+        loop {
+            if self.warm.load(Ordering::Relaxed) || self.stop.load(Ordering::Relaxed) {
+                return false;
+            }
+            if !*warming {
+                *warming = true;
+                return true;
+            }
+            warming = warmed.wait(warming).unwrap();
+        }
+    }
+
+    /// Block while the simulator is paused.
+    fn wait_while_paused(&self) {
+        if !self.paused.load(Ordering::Relaxed) {
+            return;
+        }
+        let (lock, resumed) = &self.resumed;
+        let mut guard = lock.lock().unwrap();
+        while self.paused.load(Ordering::Relaxed) && !self.stop.load(Ordering::Relaxed) {
+            guard = resumed.wait(guard).unwrap();
+        }
+    }
+}
+
+/// Held by the thread looking for the first read of the RNG, letting the others carry on when it's dropped.
+struct WarmUpClaim<'a>(&'a SimulatorInner);
+
+impl Drop for WarmUpClaim<'_> {
+    fn drop(&mut self) {
+        let (lock, warmed) = &self.0.warming;
+        *lock.lock().unwrap() = false;
+        warmed.notify_all();
+    }
+}
+
+struct Unpatched {
+    save_state: Arc<Vec<u8>>,
+    warm: bool,
+    state_hash: u64
+}
+
+/// When the simulator was last started and stopped, for [`Snapshot::elapsed`].
+#[derive(Copy, Clone, Default)]
+struct RunTime {
+    started: Option<Instant>,
+    stopped: Option<Instant>,
+    paused: Option<Instant>,
+
+    /// Time spent paused, not counting the current pause
+    paused_for: Duration
+}
+
+impl RunTime {
+    fn elapsed(&self) -> Duration {
+        match self.started {
+            Some(started) => {
+                let end = self.stopped.or(self.paused).unwrap_or_else(Instant::now);
+                end.saturating_duration_since(started).saturating_sub(self.paused_for)
+            },
+            None => Duration::ZERO
+        }
+    }
+
+    fn pause(&mut self) {
+        self.paused.get_or_insert_with(Instant::now);
+    }
+
+    fn resume(&mut self) {
+        if let Some(paused) = self.paused.take() {
+            self.paused_for += paused.elapsed();
+        }
+    }
+
+    /// Record that the run that began at `started` stopped, unless another one has begun since.
+    fn stop(&mut self, started: Instant) {
+        if self.started == Some(started) {
+            self.resume();
+            self.stopped = Some(Instant::now());
+        }
+    }
+}
+
+/// When to stop other than after the number of trials, from [`Simulator::set_precision`] and the like.
+#[derive(Copy, Clone, Default)]
+struct StopRules {
+    precision: Option<f64>,
+    timeout: Option<Duration>,
+    max_duration: Option<Duration>
+}
+
+impl StopRules {
+    /// Get whether the simulator has run out of time, given how long it has been running and how many trials it
+    /// finished in that time.
+    fn out_of_time(&self, elapsed: Duration, trials_this_run: u64) -> Option<StopCause> {
+        if self.max_duration.is_some_and(|max| elapsed >= max) {
+            Some(StopCause::MaxDuration)
+        }
+        else if trials_this_run == 0 && self.timeout.is_some_and(|timeout| elapsed >= timeout) {
+            Some(StopCause::Timeout)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Whether the results are precise enough to stop.
+    fn precise_enough(&self, results: &HashMap<Outcome, u64>, total: u64) -> bool {
+        self.precision.is_some_and(|precision| total >= PRECISION_CHECK_TRIALS && max_margin(results, total) <= precision)
+    }
+}
+
+/// Get the widest of the outcomes' [`confidence_margin`]s.
+fn max_margin(results: &HashMap<Outcome, u64>, total: u64) -> f64 {
+    results.values().map(|&count| confidence_margin(count, total)).fold(0.0, f64::max)
+}
+
+/// Trial counts for every move and item index, which threads add to without taking a lock.
+struct Results {
+    moves: [AtomicU64; 256],
+    items: [AtomicU64; 256]
+}
+
+impl Results {
+    fn new() -> Self {
+        Self { moves: std::array::from_fn(|_| AtomicU64::new(0)), items: std::array::from_fn(|_| AtomicU64::new(0)) }
+    }
+
+    fn counter(&self, outcome: Outcome) -> &AtomicU64 {
+        match outcome {
+            Outcome::Move(index) => &self.moves[index as usize],
+            Outcome::Item(index) => &self.items[index as usize]
+        }
+    }
+
+    fn add(&self, outcome: Outcome, count: u64) {
+        self.counter(outcome).fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn clear(&self) {
+        for counter in self.moves.iter().chain(&self.items) {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Get the outcomes that happened at least once.
+    fn to_map(&self) -> HashMap<Outcome, u64> {
+        let moves = self.moves.iter().enumerate().map(|(index, count)| (Outcome::Move(index as u8), count));
+        let items = self.items.iter().enumerate().map(|(index, count)| (Outcome::Item(index as u8), count));
+        moves
+            .chain(items)
+            .map(|(outcome, count)| (outcome, count.load(Ordering::Relaxed)))
+            .filter(|&(_, count)| count != 0)
+            .collect()
+    }
 }
 
 struct Status {
     gameboy: &'static safeboy::Gameboy,
     rng_hit: Rc<AtomicBool>,
     decision_made: Rc<AtomicU8>,
+    item_used: Rc<AtomicU8>,
+    move_executed: Rc<AtomicBool>,
+    player_move_selected: Rc<AtomicBool>,
+
+    /// Used by the memory callbacks if the addresses were changed
+    addresses: Addresses,
+
+    /// Where the RNG's values come from if it was seeded with [`Simulator::set_seed`]
+    rng: Option<Rc<RefCell<StdRng>>>,
+
+    /// From [`Simulator::add_watchpoint`], only used if the rules were installed with watchpoints
+    watchpoints: Arc<Vec<Watchpoint>>
 }
 
-fn simulate(inner: Arc<SimulatorInner>) {
-    let mut gameboy = safeboy::Gameboy::new(inner.model);
-    gameboy.load_rom_from_buffer(inner.rom.as_slice());
-    gameboy.set_turbo_mode(true, true);
-    gameboy.set_rendering_disabled(false);
+impl Status {
+    fn random(&self) -> u8 {
+        match &self.rng {
+            Some(rng) => rng.borrow_mut().gen(),
+            None => random()
+        }
+    }
+}
 
+/// Set up the memory callbacks that report the AI's decision and randomize the RNG, and call the watchpoints if
+/// `watch` is set.
+fn install_rules(gameboy: &mut safeboy::Gameboy, game: Game, addresses: Addresses, watch: bool) {
+    // The callbacks can't capture anything, so they use the game's addresses as constants unless they were changed or
+    // there are watchpoints, in which case they're read from the Status for every memory access (which is slower).
     macro_rules! make_gen2_rules {
-        ($enemy_current_move_addr:expr, $enemy_current_move_num_addr:expr, $rand_low:expr, $rand_high:expr) => {
-            gameboy.set_write_memory_callback(Some(|status, address, data| -> bool {
-                if address == $enemy_current_move_addr && data != 0 {
+        ($get_addresses:expr, $watch:expr) => {
+            gameboy.set_write_memory_callback(Some(|mut status, address, data| -> bool {
+                if $watch {
+                    call_watchpoints(status.as_deref_mut(), address, data, true);
+                }
+                let addresses = ($get_addresses)(&status);
+                if address == addresses.enemy_selected_move && data != 0 {
                     let status = status.unwrap().downcast_mut::<Status>().unwrap();
                     let pc = status.gameboy.get_registers().pc as usize;
                     if pc > 0x4000 {
@@ -193,124 +1491,360 @@ fn simulate(inner: Arc<SimulatorInner>) {
                         let (rom, bank) = status.gameboy.get_direct_access(DirectAccess::ROM);
                         let rom = &rom[0x4000 * bank as usize..];
                         let rom = rom.get(offset..offset+6);
-                        let high = ($enemy_current_move_num_addr >> 8) as u8;
-                        let low = ($enemy_current_move_num_addr & 0xFF) as u8;
+                        let high = (addresses.enemy_move_num >> 8) as u8;
+                        let low = (addresses.enemy_move_num & 0xFF) as u8;
 
                         // use a signature so ROM hacks can work provided RAM isn't moved around too much
                         if rom == Some(&[0x79, 0xEA, low, high, 0xC9, 0x91]) {
                             status.decision_made.swap(data, Ordering::Relaxed);
                         }
+                        else {
+                            log::debug!("Ignoring a write of 0x{data:02X} to the enemy's selected move from {bank:02X}:{pc:04X}, which isn't the AI's code");
+                        }
                     }
                 }
+                else if address == addresses.player_selected_move && data != 0 {
+                    let status = status.unwrap().downcast_mut::<Status>().unwrap();
+                    status.player_move_selected.swap(true, Ordering::Relaxed);
+                }
                 true
             }));
-            gameboy.set_read_memory_callback(Some(|status, address, data| -> u8 {
-                if address == $rand_low || address == $rand_high {
-                    status.unwrap().downcast_mut::<Status>().unwrap().rng_hit.swap(true, Ordering::Relaxed);
-                    return random();
+            gameboy.set_read_memory_callback(Some(|mut status, address, data| -> u8 {
+                let addresses = ($get_addresses)(&status);
+                let mut value = data;
+                if address == addresses.random_low || address == addresses.random_high {
+                    let status = status.as_deref_mut().unwrap().downcast_mut::<Status>().unwrap();
+                    status.rng_hit.swap(true, Ordering::Relaxed);
+                    value = status.random();
+                }
+                if $watch {
+                    call_watchpoints(status, address, value, false);
                 }
-                data
+                value
             }));
         };
     }
 
-    match inner.game {
-        Game::Red | Game::Blue | Game::Yellow => {
-            gameboy.set_write_memory_callback(Some(|status, address, data| -> bool {
-                if address == 0xCCDD && data != 0 {
+    macro_rules! make_gen1_rules {
+        ($get_addresses:expr, $watch:expr) => {
+            gameboy.set_write_memory_callback(Some(|mut status, address, data| -> bool {
+                if $watch {
+                    call_watchpoints(status.as_deref_mut(), address, data, true);
+                }
+                let addresses = ($get_addresses)(&status);
+                if data == 0 {
+                    return true;
+                }
+                if address == addresses.enemy_selected_move {
                     let status = status.unwrap().downcast_mut::<Status>().unwrap();
                     status.decision_made.swap(data, Ordering::Relaxed);
                 }
+                else if address == addresses.enemy_move_num {
+                    // the enemy is now executing its move, so it did not use an item this turn
+                    let status = status.unwrap().downcast_mut::<Status>().unwrap();
+                    status.move_executed.swap(true, Ordering::Relaxed);
+                }
+                else if Some(address) == addresses.ai_item {
+                    let status = status.unwrap().downcast_mut::<Status>().unwrap();
+                    status.item_used.swap(data, Ordering::Relaxed);
+                }
                 true
             }));
-            gameboy.set_read_memory_callback(Some(|status, address, data| -> u8 {
-                if address == 0xFFD3 || address == 0xFFD4 {
-                    status.unwrap().downcast_mut::<Status>().unwrap().rng_hit.swap(true, Ordering::Relaxed);
-                    return random();
+            gameboy.set_read_memory_callback(Some(|mut status, address, data| -> u8 {
+                let addresses = ($get_addresses)(&status);
+                let mut value = data;
+                if address == addresses.random_low || address == addresses.random_high {
+                    let status = status.as_deref_mut().unwrap().downcast_mut::<Status>().unwrap();
+                    status.rng_hit.swap(true, Ordering::Relaxed);
+                    value = status.random();
+                }
+                if $watch {
+                    call_watchpoints(status, address, value, false);
                 }
-                data
+                value
             }));
+        };
+    }
+
+    type Callback<'a> = Option<&'a mut dyn std::any::Any>;
+    fn changed(status: &Callback) -> Addresses {
+        status.as_deref().unwrap().downcast_ref::<Status>().unwrap().addresses
+    }
+
+    fn call_watchpoints(status: Callback, address: u16, value: u8, write: bool) {
+        let status = status.unwrap().downcast_mut::<Status>().unwrap();
+        for watchpoint in status.watchpoints.iter().filter(|watchpoint| watchpoint.matches(address, write)) {
+            let pc = status.gameboy.get_registers().pc;
+            (watchpoint.callback)(MemoryAccess { address, value, write, pc });
+        }
+    }
+
+    if watch {
+        match game.generation() {
+            Generation::One => {
+                make_gen1_rules!(changed, true);
+            },
+            Generation::Two => {
+                make_gen2_rules!(changed, true);
+            }
+        }
+        return;
+    }
+
+    if addresses != *game.addresses() {
+        match game.generation() {
+            Generation::One => {
+                make_gen1_rules!(changed, false);
+            },
+            Generation::Two => {
+                make_gen2_rules!(changed, false);
+            }
+        }
+        return;
+    }
+
+    match game {
+        Game::Red | Game::Blue => {
+            make_gen1_rules!(|_: &Callback| game::RED_BLUE, false);
+        },
+        Game::Yellow => {
+            make_gen1_rules!(|_: &Callback| game::YELLOW, false);
         },
         Game::Gold | Game::Silver => {
-            make_gen2_rules!(0xCBC2, 0xCBC7, 0xFFE3, 0xFFE4);
+            make_gen2_rules!(|_: &Callback| game::GOLD_SILVER, false);
         }
         Game::Crystal => {
-            make_gen2_rules!(0xC6E4, 0xC6E9, 0xFFE1, 0xFFE2);
+            make_gen2_rules!(|_: &Callback| game::CRYSTAL, false);
         }
     }
+}
+
+/// Flags set by the memory callbacks during a trial.
+#[derive(Default)]
+struct TrialFlags {
+    rng_hit: Rc<AtomicBool>,
+    decision_made: Rc<AtomicU8>,
+    item_used: Rc<AtomicU8>,
+    move_executed: Rc<AtomicBool>,
+    player_move_selected: Rc<AtomicBool>
+}
 
+impl TrialFlags {
+    /// Make a new set of flags and give them to the Game Boy's memory callbacks, along with the RNG to use if it's
+    /// seeded and the watchpoints.
+    fn attach(gameboy: &mut safeboy::Gameboy, addresses: Addresses, rng: Option<Rc<RefCell<StdRng>>>, watchpoints: Arc<Vec<Watchpoint>>) -> Self {
+        let flags = Self::default();
+        let status = Status {
+            gameboy: unsafe { &*(gameboy as *const _) },
+            rng_hit: flags.rng_hit.clone(),
+            decision_made: flags.decision_made.clone(),
+            item_used: flags.item_used.clone(),
+            move_executed: flags.move_executed.clone(),
+            player_move_selected: flags.player_move_selected.clone(),
+            addresses,
+            rng,
+            watchpoints
+        };
+        gameboy.set_user_data(Some(Box::new(status)));
+        flags
+    }
+}
+
+fn simulate(inner: Arc<SimulatorInner>, thread: usize, thread_trials: &AtomicU64) {
+    let mut gameboy = safeboy::Gameboy::new(inner.model);
+    gameboy.load_rom_from_buffer(inner.rom.as_slice());
+    gameboy.set_turbo_mode(true, true);
+    gameboy.set_rendering_disabled(false);
+
+    let addresses = *inner.addresses.lock().unwrap();
+    let watchpoints = Arc::new(inner.watchpoints.lock().unwrap().clone());
+    install_rules(&mut gameboy, inner.game, addresses, !watchpoints.is_empty());
+
+    let detect_items = inner.detect_items.load(Ordering::Relaxed);
+    let input_strategy = inner.input_strategy.lock().unwrap().clone();
+    let trial_listener = inner.trial_listener.lock().unwrap().clone();
+
+    // Only one thread looks for the first read of the RNG, since it's the same for all of them; the rest wait for it
+    // and start from there.
+    let mut warm_up_claim = inner.claim_warm_up().then(|| WarmUpClaim(&inner));
     let mut save_state = Arc::clone(&inner.save_state.lock().unwrap());
-    let mut found_best_save_state = false;
+    let mut found_best_save_state = inner.warm.load(Ordering::Relaxed);
+    let stop_rules = *inner.stop_rules.lock().unwrap();
+    let rng = inner.seed.lock().unwrap().map(|seed| Rc::new(RefCell::new(StdRng::seed_from_u64(seed.wrapping_add(thread as u64)))));
+    let mut runs = 0u32;
 
     loop {
+        inner.wait_while_paused();
+
         // We can load to the first instance of the random number generator if possible.
         gameboy.load_state_from_buffer(&save_state).unwrap();
 
-        let rng_hit = Rc::new(AtomicBool::new(false));
-        let decision_made = Rc::new(AtomicU8::new(0));
-
-        let memes = Status {
-            gameboy: unsafe { &*(&gameboy as *const _) },
-            rng_hit: rng_hit.clone(),
-            decision_made: decision_made.clone()
-        };
-
-        gameboy.set_user_data(Some(Box::new(memes)));
+        let TrialFlags { rng_hit, decision_made, item_used, move_executed, .. } = TrialFlags::attach(&mut gameboy, addresses, rng.clone(), watchpoints.clone());
 
-        let mut rapid_fire = 0u8;
-        let mut odd_frame = false;
+        let mut auto_input = input::AutoInput::new(input_strategy.clone(), &addresses);
+        let mut follow_through_frames: Option<u32> = None;
+        let mut frames = 0;
 
-        let move_found = loop {
+        let outcome = loop {
             if inner.stop.load(Ordering::Relaxed) {
                 return;
             }
 
+            runs = runs.wrapping_add(1);
+            if runs.is_multiple_of(TIME_CHECK_INTERVAL) {
+                let elapsed = inner.run_time.lock().unwrap().elapsed();
+                if let Some(cause) = stop_rules.out_of_time(elapsed, inner.trials_this_run()) {
+                    log::info!("Stopping after {:.1} seconds", elapsed.as_secs_f64());
+                    inner.stop_for(cause);
+                    return;
+                }
+            }
+
             if !found_best_save_state {
                 if rng_hit.load(Ordering::Relaxed) {
                     // We found where the first random() call is!
                     //
                     // Cache this for further calls to simulate().
                     *inner.save_state.lock().unwrap() = save_state.clone();
+                    if !inner.warm.swap(true, Ordering::Relaxed) {
+                        log::info!("Found the first read of the random number generator after {frames} frames; trials start from there now");
+                    }
                     found_best_save_state = true;
+                    drop(warm_up_claim.take());
                 }
                 else {
                     save_state = Arc::new(gameboy.read_save_state_to_vec());
                 }
             }
 
-            if odd_frame != gameboy.is_odd_frame() {
-                rapid_fire = (rapid_fire + 1) % 6;
-                gameboy.set_key_state(Key::A, rapid_fire < 3);
-                odd_frame = !odd_frame;
+            if auto_input.update(&mut gameboy) {
+                frames += 1;
+                if !found_best_save_state && frames % WARM_UP_LOG_FRAMES == 0 {
+                    log::debug!("Still looking for the first read of the random number generator after {frames} frames");
+                }
+                if let Some(frames) = follow_through_frames.as_mut() {
+                    *frames += 1;
+                }
+            }
+
+            let item = item_used.load(Ordering::Relaxed);
+            if item != 0 {
+                break Outcome::Item(item);
             }
 
             let result = decision_made.load(Ordering::Relaxed);
             if result != 0 {
-                break result;
+                // If we're looking for items, the AI only decides to use one after it has selected its move.
+                let frames = *follow_through_frames.get_or_insert(0);
+                if !detect_items || move_executed.load(Ordering::Relaxed) || frames >= ITEM_FOLLOW_THROUGH_FRAMES {
+                    break Outcome::Move(result);
+                }
             }
 
             gameboy.run();
         };
 
         let new_count = inner.sample_count.fetch_add(1, Ordering::Relaxed);
-        if inner.trials.is_some_and(|t| new_count >= t) {
+        if new_count >= inner.trials.load(Ordering::Relaxed) {
             inner.sample_count.fetch_sub(1, Ordering::Relaxed);
             return;
         }
 
-        let mut hm = inner.results.lock().unwrap();
-        if let Some(n) = hm.get_mut(&move_found) {
-            *n += 1;
+        inner.results.add(outcome, 1);
+        let total = new_count + 1;
+        if total.is_multiple_of(PRECISION_CHECK_TRIALS) && stop_rules.precise_enough(&inner.results.to_map(), total) {
+            log::info!("Stopping after {total} trials since the results are precise enough");
+            inner.stop_for(StopCause::Precision);
         }
-        else {
-            hm.insert(move_found, 1);
+        thread_trials.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(listener) = &trial_listener {
+            let _ = listener.send(Trial { outcome, frames, finished: SystemTime::now() });
         }
     }
 }
 
+/// Hash the ROM and save state with 64-bit FNV-1a.
+fn hash_state(rom: &[u8], save_state: &[u8]) -> u64 {
+    rom.iter().chain(save_state).fold(0xCBF2_9CE4_8422_2325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3))
+}
+
+fn read_memory(gameboy: &mut safeboy::Gameboy, address: u16, length: usize) -> Vec<u8> {
+    (0..length as u16).map(|i| gameboy.safe_read_memory(address.wrapping_add(i))).collect()
+}
+
 pub const fn move_name(move_index: u8) -> Option<&'static str> {
     match data::MoveType::from_u8(move_index) {
         Some(n) => Some(n.name()),
         None => None
     }
 }
+
+/// Get the name of the move with the given index in the given language, as the games write it.
+pub const fn localized_move_name(language: Language, move_index: u8) -> Option<&'static str> {
+    data::localized_move_name(language, move_index)
+}
+
+/// Get the type, power, accuracy, and PP of the move with the given index for the given generation.
+pub fn move_info(generation: Generation, move_index: u8) -> Option<MoveInfo> {
+    data::move_info(generation, move_index)
+}
+
+/// Get the 95% Wilson score interval for the fraction of `total` trials that `count` of them are.
+pub fn confidence_interval(count: u64, total: u64) -> (f64, f64) {
+    if total == 0 {
+        return (0.0, 1.0);
+    }
+    let n = total as f64;
+    let p = count as f64 / n;
+    let center = p + Z * Z / (2.0 * n);
+    let spread = Z * (p * (1.0 - p) / n + Z * Z / (4.0 * n * n)).sqrt();
+    let scale = 1.0 + Z * Z / n;
+    (((center - spread) / scale).max(0.0), ((center + spread) / scale).min(1.0))
+}
+
+/// Get how far the 95% confidence interval from [`confidence_interval`] reaches from `count`'s fraction of `total` on
+/// its wider side.
+pub fn confidence_margin(count: u64, total: u64) -> f64 {
+    let p = if total == 0 { 0.0 } else { count as f64 / total as f64 };
+    let (low, high) = confidence_interval(count, total);
+    (p - low).max(high - p)
+}
+
+/// Get whether two fractions of trials are different enough that it's unlikely to be chance, using a two-proportion
+/// z-test at 95% confidence.
+pub fn significantly_different(a_count: u64, a_total: u64, b_count: u64, b_total: u64) -> bool {
+    if a_total == 0 || b_total == 0 {
+        return false;
+    }
+    let (a_n, b_n) = (a_total as f64, b_total as f64);
+    let pooled = (a_count + b_count) as f64 / (a_n + b_n);
+    let error = (pooled * (1.0 - pooled) * (1.0 / a_n + 1.0 / b_n)).sqrt();
+    error > 0.0 && (a_count as f64 / a_n - b_count as f64 / b_n).abs() / error > Z
+}
+
+/// Get the title in a ROM's header, the way the game is identified (unless one is given to the simulator).
+pub fn rom_title(rom: &[u8]) -> String {
+    // like SameBoy, stop at the first character that isn't printable ASCII
+    rom.get(0x134..0x144)
+        .unwrap_or_default()
+        .iter()
+        .take_while(|c| (0x20..0x80).contains(*c))
+        .map(|&c| c as char)
+        .collect()
+}
+
+/// Get the index of the move with the given name, ignoring case.
+pub fn move_id_from_name(name: &str) -> Option<u8> {
+    data::MoveType::from_name(name).map(|m| m as u8)
+}
+
+/// Get the English name of the item with the given index for the given generation.
+pub const fn item_name(generation: Generation, item_index: u8) -> Option<&'static str> {
+    data::item_name(generation, item_index)
+}
+
+/// Get the move, species, and item tables as JSON, using the same indices as the rest of the API.
+pub fn data_tables_json() -> json::JsonValue {
+    data::tables_json()
+}
@@ -0,0 +1,171 @@
+//! Results of a run in the versioned JSON format the command-line tool saves them in, which `simulator_results_json`
+//! in the C API also gives.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use crate::json::JsonValue;
+use crate::{Outcome, Simulator};
+
+/// Version of the JSON format, which goes up whenever a change would stop older versions from reading it
+pub const FORMAT_VERSION: u64 = 1;
+
+/// Results of a run, ready to be written out.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Report {
+    /// Full name of the game
+    pub game: String,
+
+    /// See [`Simulator::state_hash`]
+    pub state_hash: u64,
+
+    /// Number of trials
+    pub total: u64,
+
+    /// One row per outcome, in index order
+    pub rows: Vec<Row>
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Row {
+    pub outcome: Outcome,
+
+    /// See [`display_name`]
+    pub name: String,
+    pub count: u64
+}
+
+impl Report {
+    pub fn new(simulator: &Simulator) -> Self {
+        let mut rows: Vec<Row> = simulator
+            .results()
+            .into_iter()
+            .map(|(outcome, count)| Row { outcome, name: display_name(simulator, outcome).into_owned(), count })
+            .collect();
+        rows.sort_by_key(|row| row.outcome);
+        Self {
+            game: simulator.game_name().to_owned(),
+            state_hash: simulator.state_hash(),
+            total: rows.iter().map(|row| row.count).sum(),
+            rows
+        }
+    }
+
+    /// Get the report in the versioned JSON format.
+    ///
+    /// The percentages and confidence intervals are only there for convenience, since they can be worked out from
+    /// the counts.
+    pub fn to_json(&self) -> JsonValue {
+        let results = self.rows.iter().map(|row| {
+            let (kind, index) = kind_and_index(row.outcome);
+            let (low, high) = row.interval(self.total);
+            JsonValue::object([
+                ("kind", JsonValue::from(kind)),
+                ("index", JsonValue::from(index)),
+                ("name", JsonValue::from(row.name.as_str())),
+                ("count", JsonValue::from(row.count)),
+                ("percent", JsonValue::Number(100.0 * row.fraction(self.total))),
+                ("ci_low", JsonValue::Number(100.0 * low)),
+                ("ci_high", JsonValue::Number(100.0 * high))
+            ])
+        });
+        JsonValue::object([
+            ("format_version", JsonValue::from(FORMAT_VERSION)),
+            ("game", JsonValue::from(self.game.as_str())),
+            ("state_hash", JsonValue::from(format!("{:016x}", self.state_hash))),
+            ("trials", JsonValue::from(self.total)),
+            ("results", JsonValue::Array(results.collect()))
+        ])
+    }
+
+    /// Read a report back from the versioned JSON format.
+    ///
+    /// Only the counts are read; the percentages and confidence intervals are worked out again.
+    pub fn from_json(json: &JsonValue) -> Result<Self, ReportError> {
+        let version = json.get("format_version").and_then(JsonValue::as_u64).ok_or(ReportError::Invalid("format_version"))?;
+        if version != FORMAT_VERSION {
+            return Err(ReportError::UnsupportedVersion(version));
+        }
+
+        let game = json.get("game").and_then(JsonValue::as_str).ok_or(ReportError::Invalid("game"))?;
+        let state_hash = json.get("state_hash")
+            .and_then(JsonValue::as_str)
+            .and_then(|hash| u64::from_str_radix(hash, 16).ok())
+            .ok_or(ReportError::Invalid("state_hash"))?;
+
+        let results = json.get("results").and_then(JsonValue::as_array).ok_or(ReportError::Invalid("results"))?;
+        let mut rows = Vec::with_capacity(results.len());
+        for result in results {
+            let index = result.get("index")
+                .and_then(JsonValue::as_u64)
+                .and_then(|index| u8::try_from(index).ok())
+                .ok_or(ReportError::Invalid("index"))?;
+            let outcome = match result.get("kind").and_then(JsonValue::as_str) {
+                Some("move") => Outcome::Move(index),
+                Some("item") => Outcome::Item(index),
+                _ => return Err(ReportError::Invalid("kind"))
+            };
+            rows.push(Row {
+                outcome,
+                name: result.get("name").and_then(JsonValue::as_str).ok_or(ReportError::Invalid("name"))?.to_owned(),
+                count: result.get("count").and_then(JsonValue::as_u64).ok_or(ReportError::Invalid("count"))?
+            });
+        }
+        rows.sort_by_key(|row| row.outcome);
+
+        Ok(Self { game: game.to_owned(), state_hash, total: rows.iter().map(|row| row.count).sum(), rows })
+    }
+}
+
+impl Row {
+    pub fn fraction(&self, total: u64) -> f64 {
+        if total == 0 { 0.0 } else { self.count as f64 / total as f64 }
+    }
+
+    /// See [`crate::confidence_margin`].
+    pub fn margin(&self, total: u64) -> f64 {
+        crate::confidence_margin(self.count, total)
+    }
+
+    /// See [`crate::confidence_interval`].
+    pub fn interval(&self, total: u64) -> (f64, f64) {
+        crate::confidence_interval(self.count, total)
+    }
+}
+
+/// Why [`Report::from_json`] couldn't read a report.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ReportError {
+    /// Made by a newer version that changed the format
+    UnsupportedVersion(u64),
+
+    /// A field is missing or has the wrong type
+    Invalid(&'static str)
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(f, "format version {version} isn't supported (only {FORMAT_VERSION} is); try a newer version of the simulator"),
+            Self::Invalid(field) => write!(f, "not a results file (missing or invalid \"{field}\")")
+        }
+    }
+}
+
+/// Get whether an outcome is a move or item, and its index, as written in the machine-readable formats.
+pub const fn kind_and_index(outcome: Outcome) -> (&'static str, u8) {
+    match outcome {
+        Outcome::Move(index) => ("move", index),
+        Outcome::Item(index) => ("item", index)
+    }
+}
+
+/// Get the name of an outcome as it's shown in reports, which tells items apart from moves and has the index of
+/// anything [`Simulator::outcome_name`] doesn't know.
+pub fn display_name(simulator: &Simulator, outcome: Outcome) -> Cow<'_, str> {
+    match (outcome, simulator.outcome_name(outcome)) {
+        (Outcome::Move(_), Some(name)) => Cow::Borrowed(name),
+        (Outcome::Move(index), None) => Cow::Owned(format!("UNK (0x{index:02X})")),
+        (Outcome::Item(_), Some(name)) => Cow::Owned(format!("{name} (ITEM)")),
+        (Outcome::Item(index), None) => Cow::Owned(format!("UNK ITEM (0x{index:02X})"))
+    }
+}
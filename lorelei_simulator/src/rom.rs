@@ -0,0 +1,113 @@
+//! Reads data out of the ROM itself so ROM hacks and localized releases can still be displayed correctly.
+
+use crate::data::{decode_text, encode_text, Language, TEXT_TERMINATOR};
+
+/// Longest move name that fits in the games' move menu.
+pub const MAX_MOVE_NAME_LENGTH: usize = 12;
+
+/// Find and decode the ROM's move name table, detecting the game's language in the process.
+///
+/// The table is found by searching for its first two entries in each language, so this works as long as the
+/// ROM still has Pound and Karate Chop as moves 1 and 2. The returned names are indexed by move index (index 0
+/// is unused). Returns `None` if the table could not be found.
+pub fn read_move_names(rom: &[u8]) -> Option<(Language, Vec<Option<String>>)> {
+    Language::ALL.into_iter().find_map(|language| {
+        let mut signature = Vec::new();
+        for name in language.first_move_names() {
+            signature.extend(encode_text(language, name).unwrap());
+            signature.push(TEXT_TERMINATOR);
+        }
+
+        let start = rom.windows(signature.len()).position(|w| w == signature)?;
+
+        let mut names = vec![None];
+        for entry in rom[start..].split(|&b| b == TEXT_TERMINATOR).take(u8::MAX as usize) {
+            // Stop at whatever comes after the table
+            match decode_text(language, entry) {
+                Some(name) if !name.is_empty() && name.chars().count() <= MAX_MOVE_NAME_LENGTH => names.push(Some(name)),
+                _ => break
+            }
+        }
+        Some((language, names))
+    })
+}
+
+/// Guess the game's language from its header, for when the move name table can't be found.
+///
+/// Generation 2 games end their manufacturer code with a letter for the region. Generation 1 games only say whether
+/// they were sold in Japan, so the rest are told apart by the translated colour in their titles (Yellow's title
+/// isn't translated, so those give `None`).
+pub fn header_language(rom: &[u8]) -> Option<Language> {
+    let header = rom.get(0x134..0x150)?;
+
+    // Generation 2 games support the Game Boy Color
+    if header[0x143 - 0x134] & 0x80 != 0 {
+        return match header[0x142 - 0x134] {
+            b'E' => Some(Language::English),
+            b'F' => Some(Language::French),
+            b'D' => Some(Language::German),
+            b'S' => Some(Language::Spanish),
+            b'I' => Some(Language::Italian),
+            b'J' => Some(Language::Japanese),
+            _ => None
+        };
+    }
+
+    if header[0x14A - 0x134] == 0x00 {
+        return Some(Language::Japanese);
+    }
+    let title = &header[..0x144 - 0x134];
+    let title = &title[..title.iter().position(|&b| b == 0).unwrap_or(title.len())];
+    match title {
+        b"POKEMON RED" | b"POKEMON BLUE" => Some(Language::English),
+        b"POKEMON ROUGE" | b"POKEMON BLEU" => Some(Language::French),
+        b"POKEMON ROT" | b"POKEMON BLAU" => Some(Language::German),
+        b"POKEMON ROJO" | b"POKEMON AZUL" => Some(Language::Spanish),
+        b"POKEMON ROSSO" | b"POKEMON BLU" => Some(Language::Italian),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Make a ROM with the given header title, CGB flag, and destination code.
+    fn rom(title: &[u8], cgb: u8, destination: u8) -> Vec<u8> {
+        let mut rom = vec![0; 0x8000];
+        rom[0x134..0x134 + title.len()].copy_from_slice(title);
+        rom[0x143] = cgb;
+        rom[0x14A] = destination;
+        rom
+    }
+
+    #[test]
+    fn header_language_of_generation_2() {
+        assert_eq!(header_language(&rom(b"POKEMON_GLDAAUE", 0x80, 0x01)), Some(Language::English));
+        assert_eq!(header_language(&rom(b"POKEMON_SLVAAXD", 0x80, 0x01)), Some(Language::German));
+        assert_eq!(header_language(&rom(b"PM_CRYSTAL\0BYTF", 0xC0, 0x01)), Some(Language::French));
+        assert_eq!(header_language(&rom(b"POKEMON_GLDAAUJ", 0x80, 0x00)), Some(Language::Japanese));
+    }
+
+    #[test]
+    fn header_language_of_generation_1() {
+        assert_eq!(header_language(&rom(b"POKEMON ROSSO", 0x00, 0x01)), Some(Language::Italian));
+        assert_eq!(header_language(&rom(b"POKEMON AZUL", 0x00, 0x01)), Some(Language::Spanish));
+        assert_eq!(header_language(&rom(b"POKEMON RED", 0x00, 0x00)), Some(Language::Japanese));
+        assert_eq!(header_language(&rom(b"POKEMON YELLOW", 0x00, 0x01)), None);
+        assert_eq!(header_language(&[0; 0x100]), None);
+    }
+
+    #[test]
+    fn reads_localized_move_names() {
+        let mut rom = vec![0; 0x100];
+        for index in 1..=3 {
+            rom.extend(encode_text(Language::German, crate::data::localized_move_name(Language::German, index).unwrap()).unwrap());
+            rom.push(TEXT_TERMINATOR);
+        }
+        rom.push(0x00);
+        let (language, names) = read_move_names(&rom).unwrap();
+        assert_eq!(language, Language::German);
+        assert_eq!(names, [None, Some("PFUND".to_owned()), Some("KARATESCHLAG".to_owned()), Some("DUPLEXHIEB".to_owned())]);
+    }
+}
@@ -0,0 +1,79 @@
+//! Checks for save states that won't simulate the way the user expects.
+
+use std::fmt::{Display, Formatter};
+use crate::data::Generation;
+
+/// How serious a [`StateIssue`] is.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Severity {
+    /// Results will be valid, but may not be what the user wants.
+    Warning,
+
+    /// Results will be meaningless or the simulator will never record any.
+    Error
+}
+
+/// A problem found by [`Simulator::validate_state`](crate::Simulator::validate_state).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StateIssue {
+    /// The save state is not in a battle.
+    NotInBattle,
+
+    /// The save state is in a wild battle, where the enemy picks its moves at random.
+    WildBattle,
+
+    /// The player's move menu was already open, so the AI had already made its decision for the turn (Gen 2).
+    MenuOpen,
+
+    /// The enemy is locked into a move (e.g. charging, thrashing, or recharging), so the AI has no choice.
+    EnemyLockedIn,
+
+    /// The game took this many frames to first read the RNG, so the save state was probably made during an
+    /// animation or text. Trials will still work, but each thread spends longer warming up.
+    MidAnimation { frames: u32 },
+
+    /// The AI did not make a decision in this many frames.
+    NoDecision { frames: u32 }
+}
+
+impl StateIssue {
+    pub const fn severity(self) -> Severity {
+        match self {
+            Self::WildBattle | Self::MidAnimation { .. } => Severity::Warning,
+            Self::NotInBattle | Self::MenuOpen | Self::EnemyLockedIn | Self::NoDecision { .. } => Severity::Error
+        }
+    }
+}
+
+impl Display for StateIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotInBattle => f.write_str("The save state is not in a battle"),
+            Self::WildBattle => f.write_str("This is a wild battle, so the enemy picks its moves at random"),
+            Self::MenuOpen => f.write_str("The move menu is already open, so the AI has already decided this turn"),
+            Self::EnemyLockedIn => f.write_str("The enemy is locked into a move, so the AI doesn't get to choose"),
+            Self::MidAnimation { frames } => write!(f, "The game took {frames} frames to read the RNG; the save state may be mid-animation"),
+            Self::NoDecision { frames } => write!(f, "The AI did not make a decision in {frames} frames")
+        }
+    }
+}
+
+/// Check the enemy's battle status flags for anything that forces it to use a move.
+pub fn enemy_locked_in(generation: Generation, status: &[u8]) -> bool {
+    match generation {
+        // wEnemyBattleStatus1: storing energy, thrashing, attacking multiple times, charging, trapping
+        // wEnemyBattleStatus2: needs to recharge
+        Generation::One => status[0] & 0b0011_0111 != 0 || status[1] & (1 << 5) != 0,
+
+        // wEnemySubStatus1: rollout
+        // wEnemySubStatus3: bide, rampage, in loop, charged, underground, flying
+        // wEnemySubStatus4: recharge
+        // wEnemySubStatus5: encored
+        Generation::Two => {
+            status[0] & (1 << 6) != 0
+                || status[2] & 0b0111_0111 != 0
+                || status[3] & (1 << 5) != 0
+                || status[4] & (1 << 2) != 0
+        }
+    }
+}
@@ -7,8 +7,58 @@
 #include <stdint.h>
 #include <stdbool.h>
 
+/**
+ * A simulator, from simulator_new or simulator_new_ex.
+ *
+ * A simulator can be used from any number of threads at once; e.g. one thread can start it while another shows its
+ * results. The exceptions are simulator_free, which must not be called while any other call on the simulator is in
+ * progress, and the callbacks, which must not call simulator_stop or simulator_free on their own simulator since
+ * those wait for the callbacks to finish.
+ */
 typedef struct Simulator Simulator;
 
+/**
+ * Version of the API in this header, which goes up whenever something changes that would break existing callers
+ */
+#define SIMULATOR_ABI_VERSION 2
+
+/**
+ * Why a call failed, from simulator_last_error_code. These values never change, but new ones may be added.
+ */
+typedef enum SimulatorErrorCode {
+    /** nothing failed */
+    SIMULATOR_OK = 0,
+
+    /** the save state couldn't be read */
+    SIMULATOR_ERROR_SAVE_STATE = 1,
+
+    /** the game couldn't be told from the ROM's title (give one in SimulatorOptions for ROM hacks) */
+    SIMULATOR_ERROR_UNKNOWN_GAME = 2,
+
+    /** the save state is from another emulator and couldn't be converted */
+    SIMULATOR_ERROR_IMPORT = 3,
+
+    /** an argument or option was invalid, e.g. an unknown address name */
+    SIMULATOR_ERROR_INVALID_ARGUMENT = 4,
+
+    /** the simulator is already running */
+    SIMULATOR_ERROR_ALREADY_RUNNING = 5,
+
+    /** the save state isn't in a battle */
+    SIMULATOR_ERROR_NOT_IN_BATTLE = 6,
+
+    /** the checkpoint couldn't be read (e.g. it's damaged or from a newer version of the library) */
+    SIMULATOR_ERROR_CHECKPOINT = 7
+} SimulatorErrorCode;
+
+/**
+ * Get the version of the API the library was built with. If this isn't SIMULATOR_ABI_VERSION, the library can't be
+ * used with this header.
+ *
+ * @returns the version
+ */
+uint32_t simulator_abi_version(void);
+
 /**
  * Create a new simulator
  *
@@ -18,7 +68,7 @@ typedef struct Simulator Simulator;
  * @param save_state_size  save state size
  * @param number_of_trials max number of trials; if null, never end
  *
- * @returns the simulator, or null if an error occurred
+ * @returns the simulator, or null if an error occurred (see simulator_last_error_code)
  */
 Simulator *simulator_new(
     const void *rom,
@@ -28,6 +78,109 @@ Simulator *simulator_new(
     const size_t *number_of_trials
 );
 
+/**
+ * Version of SimulatorOptions in this header
+ */
+#define SIMULATOR_OPTIONS_VERSION 1
+
+/**
+ * A memory address to change, e.g. for ROM hacks
+ */
+typedef struct SimulatorAddress {
+    /** name of the address, e.g. "decision" or "player_battle_mon" */
+    const char *name;
+
+    /** address to use instead */
+    uint16_t address;
+} SimulatorAddress;
+
+/**
+ * Options for simulator_new_ex. Use simulator_options_init to fill in the defaults before changing anything, so
+ * fields added in later versions get their defaults too.
+ */
+typedef struct SimulatorOptions {
+    /** SIMULATOR_OPTIONS_VERSION; newer versions of the library also accept older versions */
+    uint32_t version;
+
+    /** max number of trials; if 0, never end */
+    uint64_t trials;
+
+    /** seed for the game's RNG (results only repeat exactly with 1 thread); if null, use random numbers */
+    const uint64_t *seed;
+
+    /** game to simulate the ROM as ("red", "blue", "yellow", "gold", "silver", or "crystal"); if null, go by its title */
+    const char *game;
+
+    /** number of threads simulator_start uses when given 0; if 0, use as many as there are CPU threads */
+    size_t thread_count;
+
+    /** stop once every move's percentage is within this many percentage points (95% confidence), e.g. 0.5; if 0, never */
+    double precision;
+
+    /**
+     * stop if the AI hasn't decided this many milliseconds after simulator_start, not counting trials from earlier
+     * runs; if 0, never
+     */
+    uint64_t timeout_ms;
+
+    /** stop after this many milliseconds no matter how many trials are done; if 0, never */
+    uint64_t max_duration_ms;
+
+    /** addresses to change */
+    const SimulatorAddress *addresses;
+
+    /** number of addresses */
+    size_t address_count;
+} SimulatorOptions;
+
+/**
+ * Fill in the default options.
+ *
+ * @param options options to fill in
+ */
+void simulator_options_init(
+    SimulatorOptions *options
+);
+
+/**
+ * Create a new simulator with options
+ *
+ * @param rom              rom data
+ * @param rom_size         rom size
+ * @param save_state       save state data
+ * @param save_state_size  save state size
+ * @param options          options; if null, use the defaults
+ *
+ * @returns the simulator, or null if an error occurred (see simulator_last_error_code)
+ */
+Simulator *simulator_new_ex(
+    const void *rom,
+    size_t rom_size,
+    const void *save_state,
+    size_t save_state_size,
+    const SimulatorOptions *options
+);
+
+/**
+ * Create a new simulator from a checkpoint from simulator_checkpoint, carrying on with the results and warmed up save
+ * state it had. The options aren't in the checkpoint, so they have to be given again.
+ *
+ * @param rom             rom data, which must be the ROM the checkpoint was made with
+ * @param rom_size        rom size
+ * @param checkpoint      checkpoint data
+ * @param checkpoint_size checkpoint size
+ * @param options         options; if null, use the defaults
+ *
+ * @returns the simulator, or null if an error occurred (see simulator_last_error_code)
+ */
+Simulator *simulator_new_from_checkpoint(
+    const void *rom,
+    size_t rom_size,
+    const void *checkpoint,
+    size_t checkpoint_size,
+    const SimulatorOptions *options
+);
+
 /**
  * Free the simulator, stopping it if it is running.
  *
@@ -38,18 +191,22 @@ void simulator_free(
 );
 
 /**
- * Start the simulator. Crashes if it is already running.
+ * Start the simulator. This returns straight away. If the simulator isn't warm, one of its threads looks for the
+ * game's first read of the RNG before the others are started from there.
  *
  * @param simulator    simulator to check
- * @param thread_count number of threads to use; if 0, automatically determine how many CPU threads you have
+ * @param thread_count number of threads to use; if 0, use the thread count from simulator_new_ex, or automatically
+ *                     determine how many CPU threads you have
+ *
+ * @returns SIMULATOR_OK, or SIMULATOR_ERROR_ALREADY_RUNNING if it is already running
  */
-void simulator_start(
+SimulatorErrorCode simulator_start(
     Simulator *simulator,
     size_t thread_count
 );
 
 /**
- * Stop the simulator if it's running.
+ * Stop the simulator if it's running, and wait for callbacks to finish.
  *
  * @param simulator simulator to check
  */
@@ -57,6 +214,87 @@ void simulator_stop(
     Simulator *simulator
 );
 
+/**
+ * Pause the simulator once the trials it's in the middle of are done. Does nothing if it isn't running.
+ *
+ * It still counts as running while paused, and time spent paused doesn't count towards the timeout, the maximum
+ * duration, or the elapsed time in snapshots. simulator_stop also works while paused.
+ *
+ * @param simulator simulator to pause
+ */
+void simulator_pause(
+    const Simulator *simulator
+);
+
+/**
+ * Resume the simulator if it's paused.
+ *
+ * @param simulator simulator to resume
+ */
+void simulator_resume(
+    const Simulator *simulator
+);
+
+/**
+ * Check if the simulator is paused.
+ *
+ * @param simulator simulator to check
+ *
+ * @returns true if the simulator is running and paused
+ */
+bool simulator_is_paused(
+    const Simulator *simulator
+);
+
+/**
+ * Get the number of trials finished so far. This is the total of the results without having to take them.
+ *
+ * @param simulator simulator to check
+ *
+ * @returns number of trials
+ */
+uint64_t simulator_sample_count(
+    const Simulator *simulator
+);
+
+/**
+ * Get how many trials per second the simulator is running, over the last few seconds of calls to this function.
+ *
+ * This is 0 until it has been called over at least a second, so call it regularly (e.g. when updating a display).
+ *
+ * @param simulator simulator to check
+ *
+ * @returns trials per second, or 0 if not known yet
+ */
+double simulator_trial_rate(
+    const Simulator *simulator
+);
+
+/**
+ * Throw away the results so far, so the number of trials to run starts over. This can be called while running, in
+ * which case the trials carry on from zero.
+ *
+ * @param simulator simulator to reset
+ */
+void simulator_reset_results(
+    Simulator *simulator
+);
+
+/**
+ * Change the number of trials to run. This counts the trials already done, so to run 10,000 more, pass
+ * simulator_sample_count plus 10,000.
+ *
+ * This can be called while running and takes effect straight away, though a simulator that already stopped from
+ * reaching the number of trials has to be started again with simulator_start.
+ *
+ * @param simulator simulator to change
+ * @param trials    max number of trials; if 0, never end
+ */
+void simulator_set_trials(
+    Simulator *simulator,
+    uint64_t trials
+);
+
 /**
  * Check if the simulator is running.
  *
@@ -69,7 +307,180 @@ bool simulator_is_running(
 );
 
 /**
- * Get the current results for the simulation.
+ * Function called with the simulator's progress (see simulator_set_progress_callback)
+ *
+ * @param sample_count      number of trials done
+ * @param trials_per_second number of trials done per second over the last few seconds, or 0 at first
+ * @param user_data         user data given to simulator_set_progress_callback
+ */
+typedef void (*SimulatorProgressCallback)(uint64_t sample_count, double trials_per_second, void *user_data);
+
+/**
+ * Set a function to call with the progress every 250 ms while running, and once more when it stops. It is called
+ * from a separate thread, so user_data must be safe to use from it. Once simulator_stop or simulator_free returns,
+ * it isn't called anymore.
+ *
+ * This takes effect the next time the simulator is started.
+ *
+ * @param simulator simulator to set it for
+ * @param callback  callback to use; if null, don't call one
+ * @param user_data pointer to give to the callback
+ */
+void simulator_set_progress_callback(
+    Simulator *simulator,
+    SimulatorProgressCallback callback,
+    void *user_data
+);
+
+/**
+ * Function called with each finished trial (see simulator_set_trial_callback)
+ *
+ * @param index     index of the move the AI chose, or of the item it used if is_item is true
+ * @param is_item   true if the AI used an item instead of choosing a move
+ * @param frames    number of frames the trial ran for
+ * @param user_data user data given to simulator_set_trial_callback
+ */
+typedef void (*SimulatorTrialCallback)(uint8_t index, bool is_item, uint32_t frames, void *user_data);
+
+/**
+ * Set a function to call with each trial as it finishes. It is always called from one separate thread (not the
+ * simulator's threads), in the order the trials finish, so user_data must be safe to use from it. Once
+ * simulator_stop or simulator_free returns, it isn't called anymore.
+ *
+ * This takes effect the next time the simulator is started.
+ *
+ * @param simulator simulator to set it for
+ * @param callback  callback to use; if null, don't call one
+ * @param user_data pointer to give to the callback
+ */
+void simulator_set_trial_callback(
+    Simulator *simulator,
+    SimulatorTrialCallback callback,
+    void *user_data
+);
+
+/**
+ * How important a log message is (see simulator_set_log_callback)
+ */
+typedef enum SimulatorLogLevel {
+    /** something failed */
+    SIMULATOR_LOG_ERROR = 1,
+
+    /** something is probably wrong, e.g. the AI's decision never came before a timeout */
+    SIMULATOR_LOG_WARN = 2,
+
+    /** what the simulator is doing, e.g. starting threads or warming up */
+    SIMULATOR_LOG_INFO = 3,
+
+    /** details for diagnosing problems, e.g. writes ignored because they aren't from the AI's code */
+    SIMULATOR_LOG_DEBUG = 4,
+
+    /** even more details */
+    SIMULATOR_LOG_TRACE = 5
+} SimulatorLogLevel;
+
+/**
+ * Function called with a log message (see simulator_set_log_callback)
+ *
+ * @param level     how important the message is
+ * @param message   null terminated UTF-8 message, valid only until the callback returns
+ * @param user_data user data given to simulator_set_log_callback
+ */
+typedef void (*SimulatorLogCallback)(SimulatorLogLevel level, const char *message, void *user_data);
+
+/**
+ * Set a function to call with the simulator's log messages. This applies to every simulator in the program. It can be
+ * called from any thread, including the simulator's threads, possibly at the same time, so it and user_data must be
+ * thread safe.
+ *
+ * @param callback  callback to use; if null, don't log anything
+ * @param max_level least important SimulatorLogLevel to pass on, or 0 for none
+ * @param user_data pointer to give to the callback
+ *
+ * @returns SIMULATOR_OK, or SIMULATOR_ERROR_INVALID_ARGUMENT if the level is unknown or something else in the program
+ *          already handles Rust log messages
+ */
+SimulatorErrorCode simulator_set_log_callback(
+    SimulatorLogCallback callback,
+    int max_level,
+    void *user_data
+);
+
+/**
+ * Which accesses a watchpoint is called for (see simulator_add_watchpoint)
+ */
+typedef enum SimulatorWatchKind {
+    SIMULATOR_WATCH_READ = 0,
+    SIMULATOR_WATCH_WRITE = 1,
+    SIMULATOR_WATCH_READ_WRITE = 2
+} SimulatorWatchKind;
+
+/**
+ * Function called when a watched address is read or written (see simulator_add_watchpoint)
+ *
+ * @param address   address accessed
+ * @param value     value written, or the value the game read (which for the RNG is the simulator's random number)
+ * @param is_write  true if it was written, false if it was read
+ * @param pc        where the CPU was when it accessed the address
+ * @param user_data user data given to simulator_add_watchpoint
+ */
+typedef void (*SimulatorWatchCallback)(uint16_t address, uint8_t value, bool is_write, uint16_t pc, void *user_data);
+
+/**
+ * Call a function whenever an address is read or written during a trial, alongside the simulator's own memory hooks,
+ * e.g. to collect statistics on something the simulator doesn't track. Any number of watchpoints can be added.
+ *
+ * The callback is called from all of the simulator's threads, possibly at the same time, so it and user_data must be
+ * thread safe. It also holds up the trial while it runs, so it should be quick. Having any watchpoints slows trials
+ * down a bit. Once simulator_stop or simulator_free returns, it isn't called anymore.
+ *
+ * This takes effect the next time the simulator is started.
+ *
+ * @param simulator simulator to add it to
+ * @param address   address to watch
+ * @param kind      a SimulatorWatchKind
+ * @param callback  callback to call
+ * @param user_data pointer to give to the callback
+ *
+ * @returns SIMULATOR_OK, or SIMULATOR_ERROR_INVALID_ARGUMENT if the kind is unknown or the callback is null
+ */
+SimulatorErrorCode simulator_add_watchpoint(
+    Simulator *simulator,
+    uint16_t address,
+    int kind,
+    SimulatorWatchCallback callback,
+    void *user_data
+);
+
+/**
+ * Remove all watchpoints added with simulator_add_watchpoint.
+ *
+ * This takes effect the next time the simulator is started.
+ *
+ * @param simulator simulator to remove them from
+ */
+void simulator_clear_watchpoints(
+    Simulator *simulator
+);
+
+/**
+ * Wait for the simulator to finish on its own, e.g. after reaching the number of trials or a stop rule from
+ * simulator_new_ex.
+ *
+ * @param simulator  simulator to wait for
+ * @param timeout_ms longest to wait in milliseconds; if 0, return immediately, and if UINT64_MAX, wait until it
+ *                   finishes
+ *
+ * @returns true if the simulator isn't running anymore, or false if it's still running after the timeout
+ */
+bool simulator_wait(
+    const Simulator *simulator,
+    uint64_t timeout_ms
+);
+
+/**
+ * Get the current results for the simulation. Only moves are included; use simulator_snapshot to get items too,
+ * along with the total number of trials.
  *
  * @param simulator simulator to check
  * @param indices   pointer to move indices (must have at least size available)
@@ -84,10 +495,429 @@ void simulator_results(
 );
 
 /**
- * Get the move name for the move with the index.
+ * How many times the AI made one choice, in a SimulatorSnapshot
+ */
+typedef struct SimulatorResult {
+    /** index of the move, or of the item if is_item is true */
+    uint8_t index;
+
+    /** true if the AI used an item instead of choosing a move */
+    bool is_item;
+
+    /** number of trials the AI made this choice in */
+    uint64_t count;
+} SimulatorResult;
+
+/**
+ * Results of a simulation taken all at once, so percentages worked out from them aren't skewed by trials finishing
+ * in between
+ */
+typedef struct SimulatorSnapshot {
+    /** total number of trials, which is the sum of the results' counts */
+    uint64_t sample_count;
+
+    /** milliseconds the simulator has been running since it was last started, or ran for if it stopped */
+    uint64_t elapsed_ms;
+
+    /** number of results */
+    size_t result_count;
+
+    /** results, sorted with moves by index first and then items by index */
+    SimulatorResult *results;
+} SimulatorSnapshot;
+
+/**
+ * Get the current results for the simulation, along with the total number of trials and how long it has been
+ * running.
+ *
+ * @param simulator simulator to check
+ *
+ * @returns the snapshot, which must be freed with simulator_snapshot_free
+ */
+SimulatorSnapshot *simulator_snapshot(
+    const Simulator *simulator
+);
+
+/**
+ * Free a snapshot from simulator_snapshot.
+ *
+ * @param snapshot snapshot to free; if null, do nothing
+ */
+void simulator_snapshot_free(
+    SimulatorSnapshot *snapshot
+);
+
+/**
+ * What the AI chose, from simulator_results_iter_next
+ */
+typedef enum SimulatorOutcomeKind {
+    /** the AI chose a move */
+    SIMULATOR_OUTCOME_MOVE = 0,
+
+    /** the AI used an item */
+    SIMULATOR_OUTCOME_ITEM = 1
+} SimulatorOutcomeKind;
+
+/**
+ * Results from simulator_results_iter_new, read one at a time with simulator_results_iter_next
+ */
+typedef struct SimulatorResultsIter SimulatorResultsIter;
+
+/**
+ * Get the current results for the simulation to read one at a time, for bindings that can't easily pass arrays.
+ * The results are taken all at once, so trials that finish afterwards aren't in them.
+ *
+ * @param simulator simulator to check
+ *
+ * @returns the results, which must be freed with simulator_results_iter_free
+ */
+SimulatorResultsIter *simulator_results_iter_new(
+    const Simulator *simulator
+);
+
+/**
+ * Read the next result, going through moves by index first and then items by index.
+ *
+ * @param iter  results from simulator_results_iter_new
+ * @param kind  written to with whether the AI chose a move or used an item; can be null
+ * @param index written to with the index of the move or item; can be null
+ * @param count written to with the number of trials the AI made this choice in; can be null
+ *
+ * @returns true if a result was read, or false if there are no more (in which case nothing is written)
+ */
+bool simulator_results_iter_next(
+    SimulatorResultsIter *iter,
+    SimulatorOutcomeKind *kind,
+    uint8_t *index,
+    uint64_t *count
+);
+
+/**
+ * Free results from simulator_results_iter_new.
+ *
+ * @param iter results to free; if null, do nothing
+ */
+void simulator_results_iter_free(
+    SimulatorResultsIter *iter
+);
+
+/**
+ * Non-volatile status condition of a Pokémon
+ */
+typedef enum SimulatorStatus {
+    SIMULATOR_STATUS_NONE = 0,
+    SIMULATOR_STATUS_SLEEP = 1,
+    SIMULATOR_STATUS_POISON = 2,
+    SIMULATOR_STATUS_BURN = 3,
+    SIMULATOR_STATUS_FREEZE = 4,
+    SIMULATOR_STATUS_PARALYSIS = 5
+} SimulatorStatus;
+
+/**
+ * Calculated stats of a Pokémon. In Generation 1, special_attack and special_defense are both the Special stat.
+ */
+typedef struct SimulatorStats {
+    uint16_t attack;
+    uint16_t defense;
+    uint16_t speed;
+    uint16_t special_attack;
+    uint16_t special_defense;
+} SimulatorStats;
+
+/**
+ * Stat stages from -6 to +6
+ */
+typedef struct SimulatorStatStages {
+    int8_t attack;
+    int8_t defense;
+    int8_t speed;
+    int8_t special_attack;
+    int8_t special_defense;
+    int8_t accuracy;
+    int8_t evasion;
+} SimulatorStatStages;
+
+/**
+ * The Pokémon one side has out, in a SimulatorBattleContext
+ */
+typedef struct SimulatorBattleMon {
+    /** the game's index for the species */
+    uint8_t species_index;
+
+    /** National Pokédex number, or 0 if the index is unused */
+    uint8_t dex;
+
+    /** English name of the species, or NULL if the index is unused; this lives forever and must not be freed */
+    const char *species_name;
+
+    /** index of the held item, or 0 if there isn't one (always 0 in Generation 1) */
+    uint8_t item;
+
+    uint8_t level;
+    uint16_t hp;
+    uint16_t max_hp;
+    SimulatorStatus status;
+
+    /** turns left asleep if status is SIMULATOR_STATUS_SLEEP */
+    uint8_t sleep_turns;
+
+    /** the game's indices for the types (see SimulatorMoveInfo) */
+    uint8_t types[2];
+
+    /** move indices; 0 is an empty slot (see simulator_move_name and simulator_move_info) */
+    uint8_t moves[4];
+
+    /** current PP of each move */
+    uint8_t pp[4];
+
+    /** PP Ups used on each move */
+    uint8_t pp_ups[4];
+
+    uint16_t dvs;
+    SimulatorStats stats;
+} SimulatorBattleMon;
+
+/**
+ * Both sides of the battle in the save state, from simulator_battle_context
+ */
+typedef struct SimulatorBattleContext {
+    /** true for a trainer battle, false for a wild battle */
+    bool trainer_battle;
+
+    SimulatorBattleMon player;
+    SimulatorStatStages player_stat_stages;
+    SimulatorBattleMon enemy;
+    SimulatorStatStages enemy_stat_stages;
+
+    /**
+     * the player's badges as bit flags, in the order they're listed in-game; in Generation 2, the low byte is the
+     * Johto badges and the high byte is the Kanto badges
+     */
+    uint16_t badges;
+} SimulatorBattleContext;
+
+/**
+ * Read both sides of the battle from the save state the simulator started from, e.g. to show the matchup.
+ *
+ * @param simulator simulator to check
+ * @param context   written to with the battle if successful
+ *
+ * @returns SIMULATOR_OK, SIMULATOR_ERROR_NOT_IN_BATTLE if the save state isn't in a battle, or
+ *          SIMULATOR_ERROR_INVALID_ARGUMENT if context is null
+ */
+SimulatorErrorCode simulator_battle_context(
+    const Simulator *simulator,
+    SimulatorBattleContext *context
+);
+
+/**
+ * What a SimulatorPatch changes
+ */
+typedef enum SimulatorPatchKind {
+    /** set the HP to value percent of the max HP, rounding down but leaving at least 1 HP */
+    SIMULATOR_PATCH_HP_PERCENT = 0,
+
+    /** set the status byte to value, as the games store it (e.g. 0x40 for paralysis, or 1-7 for turns asleep) */
+    SIMULATOR_PATCH_STATUS = 1,
+
+    /** replace the move in slot (0-3) with the move with index value */
+    SIMULATOR_PATCH_MOVE = 2,
+
+    /** write value to address, which can be anywhere in memory */
+    SIMULATOR_PATCH_MEMORY = 3
+} SimulatorPatchKind;
+
+/**
+ * A change to the battle in the save state, for simulator_patch_battle
+ */
+typedef struct SimulatorPatch {
+    /** a SimulatorPatchKind */
+    int kind;
+
+    /** true to change the enemy's Pokémon, false for the player's (unused for SIMULATOR_PATCH_MEMORY) */
+    bool enemy;
+
+    /** what to change it to (see SimulatorPatchKind) */
+    uint8_t value;
+
+    /** move slot for SIMULATOR_PATCH_MOVE */
+    uint8_t slot;
+
+    /** address for SIMULATOR_PATCH_MEMORY */
+    uint16_t address;
+} SimulatorPatch;
+
+/**
+ * Change the battle in the save state, e.g. to see what the AI would do if its Pokémon had less HP. This can be
+ * called more than once, and undone with simulator_clear_patches.
+ *
+ * The results are cleared, since they were for a different battle. To compare scenarios, patch the battle for each
+ * one, run the simulator, and take a snapshot (see simulator_snapshot) before clearing the patches for the next.
+ *
+ * @param simulator   simulator to patch
+ * @param patches     patches to apply in order
+ * @param patch_count number of patches
+ *
+ * @returns SIMULATOR_OK, SIMULATOR_ERROR_NOT_IN_BATTLE if the save state isn't in a battle,
+ *          SIMULATOR_ERROR_ALREADY_RUNNING if it's running, or SIMULATOR_ERROR_INVALID_ARGUMENT if a patch's kind or
+ *          move slot is invalid (in which case nothing is changed)
+ */
+SimulatorErrorCode simulator_patch_battle(
+    Simulator *simulator,
+    const SimulatorPatch *patches,
+    size_t patch_count
+);
+
+/**
+ * Go back to the save state from before simulator_patch_battle was first called, clearing the results. Does nothing
+ * if it wasn't patched.
+ *
+ * @param simulator simulator to clear the patches of
+ *
+ * @returns SIMULATOR_OK, or SIMULATOR_ERROR_ALREADY_RUNNING if it's running
+ */
+SimulatorErrorCode simulator_clear_patches(
+    Simulator *simulator
+);
+
+/**
+ * Get the current results for the simulation as JSON, in the same format as the command-line tool's results files
+ * (--format json) with "running" added, e.g.:
+ *
+ *     {"format_version":1,"game":"Pokémon: Red Version","state_hash":"0123456789abcdef","trials":1000,"running":true,
+ *      "results":[{"kind":"move","index":59,"name":"BLIZZARD","count":412,"percent":41.2,"ci_low":38.2,"ci_high":44.3}]}
+ *
+ * The percentages and 95% confidence intervals ("ci_low" and "ci_high") are from 0 to 100. "kind" is "move", or
+ * "item" if the AI used an item. "name" is the same as the command-line tool shows, e.g. "POTION (ITEM)", or
+ * "UNK (0xFF)" for indices the simulator doesn't know.
+ *
+ * @param simulator simulator to check
+ *
+ * @returns a null terminated UTF-8 string, which must be freed with simulator_string_free
+ */
+char *simulator_results_json(
+    const Simulator *simulator
+);
+
+/**
+ * Free a string returned by the simulator.
+ *
+ * @param string string to free; if null, do nothing
+ */
+void simulator_string_free(
+    char *string
+);
+
+/**
+ * Save the results so far and the save state so the run can be carried on later with simulator_new_from_checkpoint,
+ * e.g. after the host restarts. This can be called while running; trials that finish afterwards aren't in it.
+ *
+ * The format is the same on every platform, so checkpoints can be written to a file and moved between machines.
+ *
+ * @param simulator simulator to save
+ * @param size      written to with the size of the checkpoint
+ *
+ * @returns the checkpoint, which must be freed with simulator_checkpoint_free
+ */
+uint8_t *simulator_checkpoint(
+    const Simulator *simulator,
+    size_t *size
+);
+
+/**
+ * Free a checkpoint returned by simulator_checkpoint.
+ *
+ * @param checkpoint checkpoint to free; if null, do nothing
+ * @param size       size written by simulator_checkpoint
+ */
+void simulator_checkpoint_free(
+    uint8_t *checkpoint,
+    size_t size
+);
+
+/**
+ * A move's data, from simulator_move_info
+ */
+typedef struct SimulatorMoveInfo {
+    /** the game's index for the move's type */
+    uint8_t type;
+
+    /** English name of the type (e.g. "FIRE"); this lives forever and must not be freed */
+    const char *type_name;
+
+    /** base power, or 0 for moves that don't do damage or work it out some other way (e.g. Seismic Toss) */
+    uint8_t power;
+
+    /** accuracy as a percentage, or 0 for moves that can't miss */
+    uint8_t accuracy;
+
+    /** PP without any PP Ups */
+    uint8_t pp;
+} SimulatorMoveInfo;
+
+/**
+ * Get the type, power, accuracy, and PP of a move. Some moves changed between generations, e.g. Bite is NORMAL in
+ * Generation 1 and DARK in Generation 2.
+ *
+ * @param index      index of the move
+ * @param generation generation of the game (1 or 2)
+ * @param info       written to with the move's data if successful
+ *
+ * @returns SIMULATOR_OK, or SIMULATOR_ERROR_INVALID_ARGUMENT if info is null, the generation is unknown, or the move
+ *          isn't in that generation
+ */
+SimulatorErrorCode simulator_move_info(
+    uint8_t index,
+    uint8_t generation,
+    SimulatorMoveInfo *info
+);
+
+/**
+ * Get the English name of a move.
+ *
+ * The name is only written if it fits along with its null terminator, so call this with a null buffer first to get
+ * the size needed, or use a buffer of at least 32 bytes, which fits any move name.
+ *
+ * @param index         index of the move
+ * @param generation    generation of the game (1 or 2)
+ * @param buffer        buffer to write the null terminated name to; can be null if buffer_length is 0
+ * @param buffer_length size of buffer in bytes
+ *
+ * @returns the size of the name in bytes including the null terminator (which was written if it's no more than
+ *          buffer_length), or 0 with SIMULATOR_ERROR_INVALID_ARGUMENT if the generation is unknown or the move isn't
+ *          in that generation
+ */
+size_t simulator_move_name(
+    uint8_t index,
+    uint8_t generation,
+    char *buffer,
+    size_t buffer_length
+);
+
+/**
+ * Get why the last call on this thread that can fail (i.e. one that returns null or a SimulatorErrorCode) failed.
+ *
+ * @returns the error code, or SIMULATOR_OK if it didn't fail
+ */
+SimulatorErrorCode simulator_last_error_code(void);
+
+/**
+ * Get a message saying why the last call on this thread that can fail failed, to show to users. This has more
+ * detail than simulator_error_code_message, such as the name of an unknown game.
+ *
+ * @returns a null terminated UTF-8 string that is valid until the next call that can fail on this thread, or NULL if
+ *          it didn't fail
+ */
+const char *simulator_last_error_message(void);
+
+/**
+ * Get a short message for an error code, e.g. for one returned directly from a call.
+ *
+ * @param code error code
  *
- * @returns a null terminated C string if the index exists, or NULL if not
+ * @returns a null terminated UTF-8 string that is always valid, which is "Unknown error" for codes the library
+ *          doesn't know
  */
-const char *simulator_move_name(uint8_t index);
+const char *simulator_error_code_message(int code);
 
 #endif
@@ -1,7 +1,280 @@
-use std::ffi::c_char;
+// Documentation for these functions lives in include/lorelei_simulator.h.
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
 use std::num::NonZeroUsize;
 use std::ptr::null;
-use lorelei_simulator::Simulator;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, RwLock};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use lorelei_simulator::battle::{self, BattlePatch, Side, StatusCondition};
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::report::Report;
+use lorelei_simulator::{Game, Generation, MemoryAccess, Outcome, Progress, SimulatorError, Species, Trial, TrialRate, Type, WatchKind, Watchpoint};
+
+/// Version of the C API, which goes up whenever something changes that would break existing callers
+const ABI_VERSION: u32 = 2;
+
+/// How often the progress callback is called
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How often the trial callback's thread checks whether the simulator stopped when there are no trials
+const TRIAL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Version of [`SimulatorOptions`] this library was built with; older versions are also accepted.
+const OPTIONS_VERSION: u32 = 1;
+
+thread_local! {
+    /// Why the last call that failed on this thread failed
+    static LAST_ERROR: RefCell<Option<(SimulatorErrorCode, CString)>> = const { RefCell::new(None) };
+}
+
+/// These values are part of the ABI, so they can't change; new ones go at the end.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorErrorCode {
+    Ok = 0,
+    SaveState = 1,
+    UnknownGame = 2,
+    Import = 3,
+    InvalidArgument = 4,
+    AlreadyRunning = 5,
+    NotInBattle = 6,
+    Checkpoint = 7
+}
+
+impl SimulatorErrorCode {
+    const ALL: [Self; 8] = [
+        Self::Ok,
+        Self::SaveState,
+        Self::UnknownGame,
+        Self::Import,
+        Self::InvalidArgument,
+        Self::AlreadyRunning,
+        Self::NotInBattle,
+        Self::Checkpoint
+    ];
+
+    const fn message(self) -> &'static CStr {
+        match self {
+            SimulatorErrorCode::Ok => c"No error",
+            SimulatorErrorCode::SaveState => c"Can't read save state",
+            SimulatorErrorCode::UnknownGame => c"Unknown game",
+            SimulatorErrorCode::Import => c"Can't convert save state",
+            SimulatorErrorCode::InvalidArgument => c"Invalid argument",
+            SimulatorErrorCode::AlreadyRunning => c"Already running",
+            SimulatorErrorCode::NotInBattle => c"Not in a battle",
+            SimulatorErrorCode::Checkpoint => c"Can't read checkpoint"
+        }
+    }
+}
+
+fn set_last_error(code: SimulatorErrorCode, message: impl Into<String>) {
+    let message = CString::new(message.into()).unwrap_or_default();
+    LAST_ERROR.with(|error| *error.borrow_mut() = Some((code, message)));
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|error| *error.borrow_mut() = None);
+}
+
+/// Record why a simulator couldn't be made, returning null to pass on.
+fn creation_failed(error: SimulatorError) -> *mut Simulator {
+    let code = match error {
+        SimulatorError::SaveStateError => SimulatorErrorCode::SaveState,
+        SimulatorError::UnknownGame { .. } => SimulatorErrorCode::UnknownGame,
+        SimulatorError::ImportError { .. } => SimulatorErrorCode::Import,
+        SimulatorError::CheckpointError => SimulatorErrorCode::Checkpoint
+    };
+    set_last_error(code, error.to_string());
+    std::ptr::null_mut()
+}
+
+/// Record an invalid argument, returning null to pass on.
+fn invalid_argument(message: impl Into<String>) -> *mut Simulator {
+    set_last_error(SimulatorErrorCode::InvalidArgument, message);
+    std::ptr::null_mut()
+}
+
+/// What the C API's `Simulator` pointers point to.
+///
+/// Hosts can call into it from any thread (e.g. reading results from a UI thread while another one started it), so
+/// everything that changes is behind a lock.
+pub struct Simulator {
+    state: Mutex<State>,
+
+    /// For checking on the simulator without the lock, which is held while it's being stopped
+    progress: Progress,
+
+    /// Threads calling the callbacks while running, which are joined without holding `state` since the callbacks can
+    /// call into the simulator
+    callback_threads: Mutex<Vec<JoinHandle<()>>>,
+
+    rate: Mutex<TrialRate>
+}
+
+struct State {
+    simulator: lorelei_simulator::Simulator,
+
+    /// Threads to use if `simulator_start` is given 0, from [`SimulatorOptions::thread_count`]
+    thread_count: Option<NonZeroUsize>,
+
+    progress_callback: Option<Callback<ProgressCallback>>,
+    trial_callback: Option<Callback<TrialCallback>>
+}
+
+// the header promises the handle can be used from any thread
+const _: () = {
+    const fn send_sync<T: Send + Sync>() {}
+    send_sync::<Simulator>()
+};
+
+impl Simulator {
+    fn new(simulator: lorelei_simulator::Simulator, thread_count: Option<NonZeroUsize>) -> Self {
+        let progress = simulator.progress();
+        let state = State { simulator, thread_count, progress_callback: None, trial_callback: None };
+        Self { state: Mutex::new(state), progress, callback_threads: Mutex::default(), rate: Mutex::default() }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    /// Stop the simulator and wait for the callbacks to stop being called.
+    fn stop(&self) {
+        self.state().simulator.stop();
+        self.join_callback_threads();
+    }
+
+    fn join_callback_threads(&self) {
+        let threads = std::mem::take(&mut *self.callback_threads.lock().unwrap());
+        for thread in threads {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Simulator {
+    fn drop(&mut self) {
+        // the callbacks can't be called once the caller thinks the simulator is gone
+        self.stop();
+    }
+}
+
+type ProgressCallback = extern "C" fn(sample_count: u64, trials_per_second: f64, user_data: *mut c_void);
+type TrialCallback = extern "C" fn(index: u8, is_item: bool, frames: u32, user_data: *mut c_void);
+type WatchCallback = extern "C" fn(address: u16, value: u8, is_write: bool, pc: u16, user_data: *mut c_void);
+type LogCallback = extern "C" fn(level: SimulatorLogLevel, message: *const c_char, user_data: *mut c_void);
+
+/// A callback and the pointer to give it.
+#[derive(Copy, Clone)]
+struct Callback<F> {
+    function: F,
+    user_data: *mut c_void
+}
+
+// The caller is responsible for the user data being safe to use from the callbacks' threads, as the header says.
+unsafe impl<F: Send> Send for Callback<F> {}
+
+// Watchpoint callbacks are called from all of the simulator's threads at once, which the header also says.
+unsafe impl<F: Sync> Sync for Callback<F> {}
+
+/// Call the progress callback every [`PROGRESS_INTERVAL`] until the simulator stops, and once more after.
+fn report_progress(progress: Progress, callback: Callback<ProgressCallback>) {
+    let mut rate = TrialRate::default();
+    rate.update(progress.sample_count());
+    loop {
+        let finished = progress.wait(PROGRESS_INTERVAL);
+        let count = progress.sample_count();
+        (callback.function)(count, rate.update(count).unwrap_or(0.0), callback.user_data);
+        if finished {
+            return;
+        }
+    }
+}
+
+/// Call the trial callback with each trial in the order they finish, until the simulator stops and they've all been
+/// given to it.
+fn report_trials(progress: Progress, trials: Receiver<Trial>, callback: Callback<TrialCallback>) {
+    let call = |trial: Trial| {
+        let (index, is_item) = match trial.outcome {
+            Outcome::Move(index) => (index, false),
+            Outcome::Item(index) => (index, true)
+        };
+        (callback.function)(index, is_item, trial.frames, callback.user_data);
+    };
+    loop {
+        match trials.recv_timeout(TRIAL_POLL_INTERVAL) {
+            Ok(trial) => call(trial),
+            // the simulator's threads send their last trials before they stop, so these are the last ones
+            Err(RecvTimeoutError::Timeout) if !progress.is_running() => {
+                trials.try_iter().for_each(call);
+                return;
+            },
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return
+        }
+    }
+}
+
+#[repr(C)]
+pub struct SimulatorResult {
+    index: u8,
+    is_item: bool,
+    count: u64
+}
+
+#[repr(C)]
+pub struct SimulatorSnapshot {
+    sample_count: u64,
+    elapsed_ms: u64,
+    result_count: usize,
+    results: *mut SimulatorResult
+}
+
+#[repr(C)]
+pub struct SimulatorAddress {
+    name: *const c_char,
+    address: u16
+}
+
+#[repr(C)]
+pub struct SimulatorOptions {
+    version: u32,
+    trials: u64,
+    seed: *const u64,
+    game: *const c_char,
+    thread_count: usize,
+    precision: f64,
+    timeout_ms: u64,
+    max_duration_ms: u64,
+    addresses: *const SimulatorAddress,
+    address_count: usize
+}
+
+impl Default for SimulatorOptions {
+    fn default() -> Self {
+        Self {
+            version: OPTIONS_VERSION,
+            trials: 0,
+            seed: null(),
+            game: null(),
+            thread_count: 0,
+            precision: 0.0,
+            timeout_ms: 0,
+            max_duration_ms: 0,
+            addresses: null(),
+            address_count: 0
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_abi_version() -> u32 {
+    ABI_VERSION
+}
 
 #[no_mangle]
 pub unsafe extern "C" fn simulator_new(
@@ -11,6 +284,7 @@ pub unsafe extern "C" fn simulator_new(
     save_state_size: usize,
     number_of_trials: *const usize
 ) -> *mut Simulator {
+    clear_last_error();
     let rom = std::slice::from_raw_parts(rom, rom_size);
     let save_state = std::slice::from_raw_parts(save_state, save_state_size);
     let number_of_trials = if number_of_trials.is_null() {
@@ -19,12 +293,91 @@ pub unsafe extern "C" fn simulator_new(
     else {
         Some(*number_of_trials as u64)
     };
-    match Simulator::new_from_slices(
+    match lorelei_simulator::Simulator::new_from_slices(
         rom, save_state, number_of_trials
     ) {
-        Ok(n) => Box::into_raw(Box::new(n)),
-        Err(_) => std::ptr::null_mut()
+        Ok(n) => Box::into_raw(Box::new(Simulator::new(n, None))),
+        Err(e) => creation_failed(e)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_options_init(options: &mut SimulatorOptions) {
+    *options = SimulatorOptions::default();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_new_ex(
+    rom: *const u8,
+    rom_size: usize,
+    save_state: *const u8,
+    save_state_size: usize,
+    options: *const SimulatorOptions
+) -> *mut Simulator {
+    clear_last_error();
+    let rom = std::slice::from_raw_parts(rom, rom_size);
+    let save_state = std::slice::from_raw_parts(save_state, save_state_size);
+    new_with_options(options, |trials, game| lorelei_simulator::Simulator::new_from_vec(rom.to_vec(), save_state.to_vec(), trials, game))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_new_from_checkpoint(
+    rom: *const u8,
+    rom_size: usize,
+    checkpoint: *const u8,
+    checkpoint_size: usize,
+    options: *const SimulatorOptions
+) -> *mut Simulator {
+    clear_last_error();
+    let rom = std::slice::from_raw_parts(rom, rom_size);
+    let checkpoint = std::slice::from_raw_parts(checkpoint, checkpoint_size);
+    new_with_options(options, |trials, game| lorelei_simulator::Simulator::new_from_checkpoint(rom.to_vec(), checkpoint, trials, game))
+}
+
+/// Make a simulator with `create` and set it up with `options` (or the defaults if null).
+unsafe fn new_with_options(
+    options: *const SimulatorOptions,
+    create: impl FnOnce(Option<u64>, Option<Game>) -> Result<lorelei_simulator::Simulator, SimulatorError>
+) -> *mut Simulator {
+    let defaults = SimulatorOptions::default();
+    let options = options.as_ref().unwrap_or(&defaults);
+    if options.version == 0 || options.version > OPTIONS_VERSION {
+        return invalid_argument(format!("Unsupported options version {} (this library supports up to {OPTIONS_VERSION})", options.version));
     }
+
+    let game = if options.game.is_null() {
+        None
+    }
+    else {
+        let name = CStr::from_ptr(options.game).to_string_lossy();
+        match Game::from_short_name(&name) {
+            Some(game) => Some(game),
+            None => return invalid_argument(format!("Unknown game {name}; expected red, blue, yellow, gold, silver, or crystal"))
+        }
+    };
+    let trials = if options.trials == 0 { None } else { Some(options.trials) };
+    let mut simulator = match create(trials, game) {
+        Ok(simulator) => simulator,
+        Err(e) => return creation_failed(e)
+    };
+
+    if options.address_count != 0 {
+        let mut addresses = simulator.addresses();
+        for address in std::slice::from_raw_parts(options.addresses, options.address_count) {
+            let name = CStr::from_ptr(address.name).to_string_lossy();
+            if !addresses.set(&name, address.address) {
+                return invalid_argument(format!("Unknown address {name}"));
+            }
+        }
+        simulator.set_addresses(addresses);
+    }
+
+    simulator.set_seed(options.seed.as_ref().copied());
+    simulator.set_precision(Some(options.precision / 100.0).filter(|&p| p > 0.0));
+    simulator.set_timeout(Some(Duration::from_millis(options.timeout_ms)).filter(|t| !t.is_zero()));
+    simulator.set_max_duration(Some(Duration::from_millis(options.max_duration_ms)).filter(|t| !t.is_zero()));
+
+    Box::into_raw(Box::new(Simulator::new(simulator, NonZeroUsize::new(options.thread_count))))
 }
 
 #[no_mangle]
@@ -35,29 +388,250 @@ pub unsafe extern "C" fn simulator_free(simulator: *mut Simulator) {
 }
 
 #[no_mangle]
-pub extern "C" fn simulator_start(simulator: &mut Simulator, thread_count: usize) {
-    let threads = if thread_count == 0 {
-        std::thread::available_parallelism().unwrap_or(NonZeroUsize::new(1).unwrap())
+pub extern "C" fn simulator_start(simulator: &Simulator, thread_count: usize) -> SimulatorErrorCode {
+    let already_running = || {
+        set_last_error(SimulatorErrorCode::AlreadyRunning, "The simulator is already running");
+        SimulatorErrorCode::AlreadyRunning
+    };
+    if simulator.progress.is_running() {
+        return already_running();
     }
-    else {
-        NonZeroUsize::new(thread_count).unwrap()
+    clear_last_error();
+
+    // the threads from the last run have nothing left to report
+    simulator.join_callback_threads();
+
+    let mut state = simulator.state();
+    // another thread may have started it in the meantime
+    if state.simulator.is_running() {
+        return already_running();
+    }
+
+    let threads = match NonZeroUsize::new(thread_count).or(state.thread_count) {
+        Some(threads) => threads,
+        None => std::thread::available_parallelism().unwrap_or(NonZeroUsize::new(1).unwrap())
+    };
+    let trials = match state.trial_callback {
+        Some(_) => {
+            let (sender, receiver) = channel();
+            state.simulator.set_trial_listener(Some(sender));
+            Some(receiver)
+        },
+        None => {
+            state.simulator.set_trial_listener(None);
+            None
+        }
     };
-    simulator.start(threads)
+    state.simulator.start(threads);
+    *simulator.rate.lock().unwrap() = TrialRate::default();
+
+    let mut callback_threads = simulator.callback_threads.lock().unwrap();
+    if let Some(callback) = state.progress_callback {
+        let progress = simulator.progress.clone();
+        callback_threads.push(std::thread::spawn(move || report_progress(progress, callback)));
+    }
+    if let (Some(callback), Some(trials)) = (state.trial_callback, trials) {
+        let progress = simulator.progress.clone();
+        callback_threads.push(std::thread::spawn(move || report_trials(progress, trials, callback)));
+    }
+    SimulatorErrorCode::Ok
 }
 
 #[no_mangle]
-pub extern "C" fn simulator_stop(simulator: &mut Simulator) {
+pub extern "C" fn simulator_stop(simulator: &Simulator) {
     simulator.stop()
 }
 
+#[no_mangle]
+pub extern "C" fn simulator_pause(simulator: &Simulator) {
+    simulator.state().simulator.pause()
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_resume(simulator: &Simulator) {
+    simulator.state().simulator.resume()
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_is_paused(simulator: &Simulator) -> bool {
+    simulator.state().simulator.is_paused()
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_set_progress_callback(simulator: &Simulator, callback: Option<ProgressCallback>, user_data: *mut c_void) {
+    simulator.state().progress_callback = callback.map(|function| Callback { function, user_data });
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_set_trial_callback(simulator: &Simulator, callback: Option<TrialCallback>, user_data: *mut c_void) {
+    simulator.state().trial_callback = callback.map(|function| Callback { function, user_data });
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorLogLevel {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5
+}
+
+/// Passes the library's log messages to the callback from `simulator_set_log_callback`.
+struct CallbackLogger {
+    callback: RwLock<Option<Callback<LogCallback>>>
+}
+
+static LOGGER: CallbackLogger = CallbackLogger { callback: RwLock::new(None) };
+
+impl log::Log for CallbackLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level() && metadata.target().starts_with("lorelei_simulator")
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let Some(callback) = *self.callback.read().unwrap() else {
+            return;
+        };
+        let level = match record.level() {
+            log::Level::Error => SimulatorLogLevel::Error,
+            log::Level::Warn => SimulatorLogLevel::Warn,
+            log::Level::Info => SimulatorLogLevel::Info,
+            log::Level::Debug => SimulatorLogLevel::Debug,
+            log::Level::Trace => SimulatorLogLevel::Trace
+        };
+        let message = CString::new(record.args().to_string().replace('\0', "")).unwrap();
+        (callback.function)(level, message.as_ptr(), callback.user_data);
+    }
+
+    fn flush(&self) {}
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_set_log_callback(callback: Option<LogCallback>, max_level: c_int, user_data: *mut c_void) -> SimulatorErrorCode {
+    clear_last_error();
+    let level = match max_level {
+        0 => log::LevelFilter::Off,
+        1 => log::LevelFilter::Error,
+        2 => log::LevelFilter::Warn,
+        3 => log::LevelFilter::Info,
+        4 => log::LevelFilter::Debug,
+        5 => log::LevelFilter::Trace,
+        _ => {
+            invalid_argument(format!("Unknown log level {max_level}"));
+            return SimulatorErrorCode::InvalidArgument;
+        }
+    };
+
+    // this fails if the program already has a logger (e.g. from other Rust code), in which case that one gets the
+    // messages instead
+    static INSTALLED: OnceLock<bool> = OnceLock::new();
+    if !*INSTALLED.get_or_init(|| log::set_logger(&LOGGER).is_ok()) {
+        invalid_argument("Another logger is already installed");
+        return SimulatorErrorCode::InvalidArgument;
+    }
+
+    *LOGGER.callback.write().unwrap() = callback.map(|function| Callback { function, user_data });
+    log::set_max_level(if callback.is_some() { level } else { log::LevelFilter::Off });
+    SimulatorErrorCode::Ok
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorWatchKind {
+    Read = 0,
+    Write = 1,
+    ReadWrite = 2
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_add_watchpoint(
+    simulator: &Simulator,
+    address: u16,
+    kind: c_int,
+    callback: Option<WatchCallback>,
+    user_data: *mut c_void
+) -> SimulatorErrorCode {
+    clear_last_error();
+    // this takes an int so a bad kind can be rejected instead of being undefined behavior
+    let kind = match kind {
+        k if k == SimulatorWatchKind::Read as c_int => WatchKind::Read,
+        k if k == SimulatorWatchKind::Write as c_int => WatchKind::Write,
+        k if k == SimulatorWatchKind::ReadWrite as c_int => WatchKind::ReadWrite,
+        _ => {
+            invalid_argument(format!("Unknown watchpoint kind {kind}"));
+            return SimulatorErrorCode::InvalidArgument;
+        }
+    };
+    let Some(function) = callback else {
+        invalid_argument("callback is null");
+        return SimulatorErrorCode::InvalidArgument;
+    };
+    let callback = Callback { function, user_data };
+    simulator.state().simulator.add_watchpoint(Watchpoint {
+        address,
+        kind,
+        callback: Arc::new(move |access: MemoryAccess| {
+            // borrow the whole callback so the closure doesn't capture just the pointer, which isn't Send or Sync
+            let callback = &callback;
+            (callback.function)(access.address, access.value, access.write, access.pc, callback.user_data)
+        })
+    });
+    SimulatorErrorCode::Ok
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_clear_watchpoints(simulator: &Simulator) {
+    simulator.state().simulator.clear_watchpoints()
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_wait(simulator: &Simulator, timeout_ms: u64) -> bool {
+    simulator.progress.wait(Duration::from_millis(timeout_ms))
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_sample_count(simulator: &Simulator) -> u64 {
+    simulator.progress.sample_count()
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_trial_rate(simulator: &Simulator) -> f64 {
+    let count = simulator.progress.sample_count();
+    simulator.rate.lock().unwrap().update(count).unwrap_or(0.0)
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_reset_results(simulator: &Simulator) {
+    simulator.state().simulator.reset_results();
+    *simulator.rate.lock().unwrap() = TrialRate::default();
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_set_trials(simulator: &Simulator, trials: u64) {
+    simulator.state().simulator.set_trials(Some(trials).filter(|&t| t != 0));
+}
+
 #[no_mangle]
 pub extern "C" fn simulator_is_running(simulator: &Simulator) -> bool {
-    simulator.is_running()
+    simulator.progress.is_running()
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn simulator_results(simulator: &Simulator, indices: *mut u8, counts: *mut u64, size: &mut usize) {
-    let result = simulator.results();
+    let result: Vec<(u8, u64)> = simulator
+        .state()
+        .simulator
+        .results()
+        .into_iter()
+        .filter_map(|(outcome, count)| match outcome {
+            Outcome::Move(index) => Some((index, count)),
+            Outcome::Item(_) => None
+        })
+        .collect();
 
     let mut indices = std::slice::from_raw_parts_mut(indices, *size).iter_mut();
     let mut counts = std::slice::from_raw_parts_mut(counts, *size).iter_mut();
@@ -74,34 +648,433 @@ pub unsafe extern "C" fn simulator_results(simulator: &Simulator, indices: *mut
 
 
 #[no_mangle]
-pub extern "C" fn simulator_move_name(index: u8) -> *const c_char {
-    const MOVES: [[u8; 16]; 256] = {
-        use lorelei_simulator::move_name;
+pub extern "C" fn simulator_snapshot(simulator: &Simulator) -> *mut SimulatorSnapshot {
+    let snapshot = simulator.state().simulator.snapshot();
+    let mut results: Vec<(Outcome, u64)> = snapshot.results.into_iter().collect();
+    results.sort_by_key(|&(outcome, _)| outcome);
+    let results: Box<[SimulatorResult]> = results
+        .into_iter()
+        .map(|(outcome, count)| match outcome {
+            Outcome::Move(index) => SimulatorResult { index, is_item: false, count },
+            Outcome::Item(index) => SimulatorResult { index, is_item: true, count }
+        })
+        .collect();
+    Box::into_raw(Box::new(SimulatorSnapshot {
+        sample_count: snapshot.sample_count,
+        elapsed_ms: snapshot.elapsed.as_millis().try_into().unwrap_or(u64::MAX),
+        result_count: results.len(),
+        results: Box::into_raw(results) as *mut SimulatorResult
+    }))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_snapshot_free(snapshot: *mut SimulatorSnapshot) {
+    if !snapshot.is_null() {
+        let snapshot = Box::from_raw(snapshot);
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(snapshot.results, snapshot.result_count)));
+    }
+}
 
-        let mut data = [[0u8; 16]; 256];
-        let mut index = 1usize;
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorOutcomeKind {
+    Move = 0,
+    Item = 1
+}
 
-        while let Some(n) = move_name(index as u8) {
-            let bytes = n.as_bytes();
-            let mut char = 0usize;
-            loop {
-                if bytes.len() == char {
-                    break;
-                }
-                data[index][char] = bytes[char];
-                char += 1;
+/// Results taken all at once for [`simulator_results_iter_next`] to hand out one at a time.
+pub struct SimulatorResultsIter {
+    results: std::vec::IntoIter<(Outcome, u64)>
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_results_iter_new(simulator: &Simulator) -> *mut SimulatorResultsIter {
+    let mut results: Vec<(Outcome, u64)> = simulator.state().simulator.results().into_iter().collect();
+    results.sort_by_key(|&(outcome, _)| outcome);
+    Box::into_raw(Box::new(SimulatorResultsIter { results: results.into_iter() }))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_results_iter_next(
+    iter: &mut SimulatorResultsIter,
+    kind: *mut SimulatorOutcomeKind,
+    index: *mut u8,
+    count: *mut u64
+) -> bool {
+    let Some((outcome, result_count)) = iter.results.next() else {
+        return false;
+    };
+    let (result_kind, result_index) = match outcome {
+        Outcome::Move(index) => (SimulatorOutcomeKind::Move, index),
+        Outcome::Item(index) => (SimulatorOutcomeKind::Item, index)
+    };
+    if let Some(kind) = kind.as_mut() {
+        *kind = result_kind;
+    }
+    if let Some(index) = index.as_mut() {
+        *index = result_index;
+    }
+    if let Some(count) = count.as_mut() {
+        *count = result_count;
+    }
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_results_iter_free(iter: *mut SimulatorResultsIter) {
+    if !iter.is_null() {
+        drop(Box::from_raw(iter))
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorStatus {
+    None = 0,
+    Sleep = 1,
+    Poison = 2,
+    Burn = 3,
+    Freeze = 4,
+    Paralysis = 5
+}
+
+#[repr(C)]
+pub struct SimulatorStats {
+    attack: u16,
+    defense: u16,
+    speed: u16,
+    special_attack: u16,
+    special_defense: u16
+}
+
+#[repr(C)]
+pub struct SimulatorStatStages {
+    attack: i8,
+    defense: i8,
+    speed: i8,
+    special_attack: i8,
+    special_defense: i8,
+    accuracy: i8,
+    evasion: i8
+}
+
+#[repr(C)]
+pub struct SimulatorBattleMon {
+    species_index: u8,
+    dex: u8,
+    species_name: *const c_char,
+    item: u8,
+    level: u8,
+    hp: u16,
+    max_hp: u16,
+    status: SimulatorStatus,
+    sleep_turns: u8,
+    types: [u8; 2],
+    moves: [u8; 4],
+    pp: [u8; 4],
+    pp_ups: [u8; 4],
+    dvs: u16,
+    stats: SimulatorStats
+}
+
+#[repr(C)]
+pub struct SimulatorBattleContext {
+    trainer_battle: bool,
+    player: SimulatorBattleMon,
+    player_stat_stages: SimulatorStatStages,
+    enemy: SimulatorBattleMon,
+    enemy_stat_stages: SimulatorStatStages,
+    badges: u16
+}
+
+impl From<battle::BattleMon> for SimulatorBattleMon {
+    fn from(mon: battle::BattleMon) -> Self {
+        let (status, sleep_turns) = match mon.status {
+            StatusCondition::None => (SimulatorStatus::None, 0),
+            StatusCondition::Sleep(turns) => (SimulatorStatus::Sleep, turns),
+            StatusCondition::Poison => (SimulatorStatus::Poison, 0),
+            StatusCondition::Burn => (SimulatorStatus::Burn, 0),
+            StatusCondition::Freeze => (SimulatorStatus::Freeze, 0),
+            StatusCondition::Paralysis => (SimulatorStatus::Paralysis, 0)
+        };
+        let dex = mon.species.map_or(0, Species::dex);
+        let stats = mon.stats;
+        Self {
+            species_index: mon.species_index,
+            dex,
+            species_name: species_name(dex),
+            item: mon.item.unwrap_or(0),
+            level: mon.level,
+            hp: mon.hp,
+            max_hp: mon.max_hp,
+            status,
+            sleep_turns,
+            types: mon.types,
+            moves: mon.moves,
+            pp: mon.pp.map(|pp| pp & 0x3F),
+            pp_ups: mon.pp.map(|pp| pp >> 6),
+            dvs: mon.dvs,
+            stats: SimulatorStats {
+                attack: stats.attack,
+                defense: stats.defense,
+                speed: stats.speed,
+                special_attack: stats.special_attack,
+                special_defense: stats.special_defense
             }
-            index += 1;
         }
+    }
+}
+
+impl From<battle::StatStages> for SimulatorStatStages {
+    fn from(stages: battle::StatStages) -> Self {
+        Self {
+            attack: stages.attack,
+            defense: stages.defense,
+            speed: stages.speed,
+            special_attack: stages.special_attack,
+            special_defense: stages.special_defense,
+            accuracy: stages.accuracy,
+            evasion: stages.evasion
+        }
+    }
+}
 
-        data
+/// # Safety
+///
+/// `context` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn simulator_battle_context(simulator: &Simulator, context: *mut SimulatorBattleContext) -> SimulatorErrorCode {
+    clear_last_error();
+    if context.is_null() {
+        invalid_argument("context is null");
+        return SimulatorErrorCode::InvalidArgument;
+    }
+    let Some(battle) = simulator.state().simulator.battle_context() else {
+        set_last_error(SimulatorErrorCode::NotInBattle, "The save state isn't in a battle");
+        return SimulatorErrorCode::NotInBattle;
+    };
+    *context = SimulatorBattleContext {
+        trainer_battle: battle.trainer_battle,
+        player: battle.player.into(),
+        player_stat_stages: battle.player_stat_stages.into(),
+        enemy: battle.enemy.into(),
+        enemy_stat_stages: battle.enemy_stat_stages.into(),
+        badges: battle.badges
     };
+    SimulatorErrorCode::Ok
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SimulatorPatchKind {
+    HpPercent = 0,
+    Status = 1,
+    Move = 2,
+    Memory = 3
+}
 
-    let data = &MOVES[index as usize];
-    if data[0] == 0 {
-        null()
+#[repr(C)]
+pub struct SimulatorPatch {
+    kind: c_int,
+    enemy: bool,
+    value: u8,
+    slot: u8,
+    address: u16
+}
+
+impl SimulatorPatch {
+    fn to_battle_patch(&self) -> Option<BattlePatch> {
+        let side = if self.enemy { Side::Enemy } else { Side::Player };
+        let patch = match self.kind {
+            k if k == SimulatorPatchKind::HpPercent as c_int => BattlePatch::HpPercent { side, percent: self.value },
+            k if k == SimulatorPatchKind::Status as c_int => BattlePatch::Status { side, status: self.value },
+            k if k == SimulatorPatchKind::Move as c_int && self.slot < 4 => BattlePatch::Move { side, slot: self.slot, move_index: self.value },
+            k if k == SimulatorPatchKind::Memory as c_int => BattlePatch::Memory { address: self.address, value: self.value },
+            _ => return None
+        };
+        Some(patch)
     }
-    else {
-        data.as_ptr() as *const c_char
+}
+
+/// # Safety
+///
+/// `patches` must point to `patch_count` patches, or can be null if `patch_count` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn simulator_patch_battle(simulator: &Simulator, patches: *const SimulatorPatch, patch_count: usize) -> SimulatorErrorCode {
+    clear_last_error();
+    let patches = if patch_count == 0 { &[] } else { std::slice::from_raw_parts(patches, patch_count) };
+    let Some(patches) = patches.iter().map(SimulatorPatch::to_battle_patch).collect::<Option<Vec<_>>>() else {
+        invalid_argument("Unknown patch kind or move slot");
+        return SimulatorErrorCode::InvalidArgument;
+    };
+
+    let mut state = simulator.state();
+    if state.simulator.is_running() {
+        set_last_error(SimulatorErrorCode::AlreadyRunning, "Can't patch the battle while running");
+        return SimulatorErrorCode::AlreadyRunning;
     }
+    if !state.simulator.patch_battle(&patches) {
+        set_last_error(SimulatorErrorCode::NotInBattle, "The save state isn't in a battle");
+        return SimulatorErrorCode::NotInBattle;
+    }
+    SimulatorErrorCode::Ok
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_clear_patches(simulator: &Simulator) -> SimulatorErrorCode {
+    clear_last_error();
+    let mut state = simulator.state();
+    if state.simulator.is_running() {
+        set_last_error(SimulatorErrorCode::AlreadyRunning, "Can't clear patches while running");
+        return SimulatorErrorCode::AlreadyRunning;
+    }
+    state.simulator.clear_patches();
+    SimulatorErrorCode::Ok
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_results_json(simulator: &Simulator) -> *mut c_char {
+    let state = simulator.state();
+    let simulator = &state.simulator;
+    let mut json = Report::new(simulator).to_json();
+    if let JsonValue::Object(entries) = &mut json {
+        entries.push(("running".to_owned(), JsonValue::Bool(simulator.is_running())));
+    }
+
+    // JSON escapes control characters, so there can't be a null in it
+    CString::new(json.to_string()).unwrap().into_raw()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string))
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_checkpoint(simulator: &Simulator, size: &mut usize) -> *mut u8 {
+    let checkpoint = simulator.state().simulator.checkpoint().into_boxed_slice();
+    *size = checkpoint.len();
+    Box::into_raw(checkpoint).cast()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn simulator_checkpoint_free(checkpoint: *mut u8, size: usize) {
+    if !checkpoint.is_null() {
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(checkpoint, size)))
+    }
+}
+
+#[repr(C)]
+pub struct SimulatorMoveInfo {
+    move_type: u8,
+    type_name: *const c_char,
+    power: u8,
+    accuracy: u8,
+    pp: u8
+}
+
+fn generation_from_number(number: u8) -> Option<Generation> {
+    match number {
+        1 => Some(Generation::One),
+        2 => Some(Generation::Two),
+        _ => None
+    }
+}
+
+/// Make C strings out of a table of names indexed by a byte, to keep in a static so they live forever.
+fn c_string_table(name: impl Fn(u8) -> Option<&'static str>) -> Vec<Option<CString>> {
+    (0..=u8::MAX).map(|index| name(index).map(|name| CString::new(name).unwrap())).collect()
+}
+
+fn table_entry(table: &[Option<CString>], index: u8) -> *const c_char {
+    table[index as usize].as_ref().map_or(null(), |name| name.as_ptr())
+}
+
+/// Get the name of the type with the given index as a C string that lives forever.
+fn type_name(type_index: u8) -> *const c_char {
+    static NAMES: OnceLock<Vec<Option<CString>>> = OnceLock::new();
+    // Gen 2 has every type Gen 1 has, at the same indices
+    table_entry(NAMES.get_or_init(|| c_string_table(|index| Type::from_u8(Generation::Two, index).map(Type::name))), type_index)
+}
+
+/// Get the name of the species with the given National Pokédex number as a C string that lives forever.
+fn species_name(dex: u8) -> *const c_char {
+    static NAMES: OnceLock<Vec<Option<CString>>> = OnceLock::new();
+    table_entry(NAMES.get_or_init(|| c_string_table(|dex| Species::from_dex(dex).map(Species::name))), dex)
+}
+
+/// # Safety
+///
+/// `info` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn simulator_move_info(index: u8, generation: u8, info: *mut SimulatorMoveInfo) -> SimulatorErrorCode {
+    clear_last_error();
+    if info.is_null() {
+        invalid_argument("info is null");
+        return SimulatorErrorCode::InvalidArgument;
+    }
+    let Some(generation) = generation_from_number(generation) else {
+        invalid_argument(format!("Unknown generation {generation}"));
+        return SimulatorErrorCode::InvalidArgument;
+    };
+    let Some(move_info) = lorelei_simulator::move_info(generation, index) else {
+        invalid_argument(format!("No move with index {index} in generation {}", generation.number()));
+        return SimulatorErrorCode::InvalidArgument;
+    };
+    let move_type = move_info.move_type as u8;
+    *info = SimulatorMoveInfo {
+        move_type,
+        type_name: type_name(move_type),
+        power: move_info.power.unwrap_or(0),
+        accuracy: move_info.accuracy.unwrap_or(0),
+        pp: move_info.pp
+    };
+    SimulatorErrorCode::Ok
+}
+
+/// Get the size of the null terminated copy of `index`'s English name, copying it into `buffer` only if all of it
+/// fits.
+///
+/// # Safety
+///
+/// `buffer` must be valid for writes of `buffer_length` bytes, or null if `buffer_length` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn simulator_move_name(index: u8, generation: u8, buffer: *mut c_char, buffer_length: usize) -> usize {
+    clear_last_error();
+    let Some(generation) = generation_from_number(generation) else {
+        invalid_argument(format!("Unknown generation {generation}"));
+        return 0;
+    };
+    let Some(name) = lorelei_simulator::move_info(generation, index).and(lorelei_simulator::move_name(index)) else {
+        invalid_argument(format!("No move with index {index} in generation {}", generation.number()));
+        return 0;
+    };
+    let required = name.len() + 1;
+    if buffer.is_null() || buffer_length < required {
+        return required;
+    }
+    let buffer = std::slice::from_raw_parts_mut(buffer as *mut u8, required);
+    buffer[..name.len()].copy_from_slice(name.as_bytes());
+    buffer[name.len()] = 0;
+    required
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_last_error_code() -> SimulatorErrorCode {
+    LAST_ERROR.with(|error| error.borrow().as_ref().map_or(SimulatorErrorCode::Ok, |(code, _)| *code))
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_last_error_message() -> *const c_char {
+    // the message stays in the thread-local until the next error, so the pointer stays valid until then
+    LAST_ERROR.with(|error| error.borrow().as_ref().map_or(null(), |(_, message)| message.as_ptr()))
+}
+
+#[no_mangle]
+pub extern "C" fn simulator_error_code_message(code: c_int) -> *const c_char {
+    // this takes an int since codes from a newer header wouldn't be valid SimulatorErrorCodes here
+    let code = SimulatorErrorCode::ALL.into_iter().find(|&c| c as c_int == code);
+    code.map_or(c"Unknown error", SimulatorErrorCode::message).as_ptr()
 }
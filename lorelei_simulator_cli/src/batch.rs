@@ -0,0 +1,294 @@
+//! Simulating many save states one after another, e.g. one for every major fight in a route.
+
+use std::io::{BufWriter, stdout, Write};
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use console::Term;
+use lorelei_simulator::input::InputStrategy;
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::{Outcome, Simulator};
+use crate::report::{csv_field, Report, ReportFormat, FORMAT_VERSION};
+use crate::{watch, GameArgs, InputArgs, LoadError, StateSource};
+
+pub struct BatchArgs {
+    pub rom: PathBuf,
+
+    /// Save states, directories of them, or file names with `*` and `?` wildcards
+    pub states: Vec<PathBuf>,
+
+    pub trials: NonZeroU64,
+    pub jobs: Option<NonZeroUsize>,
+    pub mash: Option<InputStrategy>,
+    pub overrides: GameArgs,
+    pub format: ReportFormat,
+
+    /// Directory to write each save state's results in, along with an index
+    pub report_dir: Option<PathBuf>
+}
+
+pub fn batch(args: BatchArgs) {
+    let states = expand(&args.states);
+    if states.is_empty() {
+        eprintln!("No save states found");
+        return;
+    }
+
+    let input = InputArgs {
+        rom: Some(args.rom.clone()),
+        save_state: None,
+        slot: None,
+        battery: None,
+        input_script: None,
+        warm_state: None,
+        mash: args.mash.clone(),
+        overrides: args.overrides.clone()
+    };
+    let thread_count = args.jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap());
+
+    let bail = Arc::new(AtomicBool::new(false));
+    let bail_copy = bail.clone();
+    let _ = ctrlc::set_handler(move || { bail_copy.swap(true, Ordering::Relaxed); } );
+
+    let mut reports = Vec::with_capacity(states.len());
+    for (i, path) in states.iter().enumerate() {
+        if bail.load(Ordering::Relaxed) {
+            break;
+        }
+        let mut simulator = match input.open(&args.rom, &StateSource::SaveState(path.clone()), Some(args.trials.get())) {
+            Ok(simulator) => simulator,
+            // there's no point going on without the ROM
+            Err(e @ LoadError::Read { what: "ROM", .. }) => {
+                eprintln!("{e}");
+                return;
+            },
+            Err(e) => {
+                eprintln!("Skipping {}: {e}", path.display());
+                continue;
+            }
+        };
+
+        let progress = format!("[{}/{}] {}", i + 1, states.len(), path.display());
+        run_to_completion(&mut simulator, thread_count, &bail, &progress, args.trials);
+
+        let report = Report::new(&simulator);
+        let mut writer = BufWriter::new(stdout().lock());
+        if args.format == ReportFormat::Json {
+            // one line per state, so the output can be read line by line
+            let mut json = report.to_json();
+            if let JsonValue::Object(entries) = &mut json {
+                entries.insert(0, ("state".to_owned(), JsonValue::from(path.display().to_string())));
+            }
+            let _ = writeln!(writer, "{json}");
+        }
+        else {
+            let _ = writeln!(writer, "{} ({} trials)", path.display(), report.total);
+            let _ = writeln!(writer);
+            let _ = report.write(&mut writer, args.format);
+            let _ = writeln!(writer);
+        }
+        drop(writer);
+        reports.push((path, report));
+    }
+    let labels: Vec<(String, &Report)> = reports.iter().map(|(path, report)| (state_name(path), report)).collect();
+
+    if let Some(directory) = &args.report_dir {
+        match write_report_dir(directory, &reports) {
+            Ok(()) => println!("Saved the results in {}", directory.display()),
+            Err(e) => eprintln!("Failed to write the results to {}: {e}", directory.display())
+        }
+    }
+
+    if args.format != ReportFormat::Json && reports.len() > 1 {
+        let mut writer = BufWriter::new(stdout().lock());
+        let _ = writeln!(writer, "SUMMARY");
+        let _ = writeln!(writer);
+        let _ = write_matrix(&mut writer, "state", &labels, args.format);
+    }
+}
+
+/// Run the simulator until it finishes or CTRL-C is pressed, showing the progress after `label` if in a terminal.
+pub fn run_to_completion(simulator: &mut Simulator, thread_count: NonZeroUsize, bail: &AtomicBool, label: &str, trials: NonZeroU64) {
+    simulator.start(thread_count);
+    let mut output = Term::stdout();
+    while simulator.is_running() {
+        std::thread::sleep(Duration::from_millis(250));
+        if bail.load(Ordering::Relaxed) {
+            simulator.stop();
+        }
+        if output.is_term() {
+            let count: u64 = simulator.results().values().sum();
+            output.clear_line().unwrap();
+            let _ = write!(&mut output, "{label}: {count}/{trials}");
+        }
+    }
+    if output.is_term() {
+        output.clear_line().unwrap();
+    }
+}
+
+fn state_name(path: &Path) -> String {
+    path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().into_owned()
+}
+
+/// Write each save state's results in a directory named after it, with an index of them and the summary.
+fn write_report_dir(directory: &Path, reports: &[(&PathBuf, Report)]) -> std::io::Result<()> {
+    std::fs::create_dir_all(directory)?;
+
+    let mut names: Vec<String> = Vec::with_capacity(reports.len());
+    let mut entries = Vec::with_capacity(reports.len());
+    for (path, report) in reports {
+        // Save states with the same name in different directories get numbered.
+        let base = state_name(path);
+        let mut name = base.clone();
+        let mut number = 1;
+        while names.contains(&name) {
+            number += 1;
+            name = format!("{base}-{number}");
+        }
+
+        let state_directory = directory.join(&name);
+        report.save(&state_directory.join("results.json"), ReportFormat::Json)?;
+        report.save(&state_directory.join("results.md"), ReportFormat::Markdown)?;
+        entries.push(JsonValue::object([
+            ("state", JsonValue::from(path.display().to_string())),
+            ("directory", JsonValue::from(name.as_str())),
+            ("trials", JsonValue::from(report.total))
+        ]));
+        names.push(name);
+    }
+
+    std::fs::write(directory.join("index.json"), format!("{:#}\n", JsonValue::object([
+        ("format_version", JsonValue::from(FORMAT_VERSION)),
+        ("states", JsonValue::Array(entries))
+    ])))?;
+    let labels: Vec<(String, &Report)> = reports.iter().map(|(path, report)| (path.display().to_string(), report)).collect();
+    let mut summary = Vec::new();
+    write_matrix(&mut summary, "state", &labels, ReportFormat::Csv)?;
+    std::fs::write(directory.join("summary.csv"), summary)
+}
+
+/// Expand directories and wildcards into the save states they match.
+fn expand(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut states = Vec::new();
+    for path in paths {
+        let pattern = path.file_name().and_then(|n| n.to_str()).filter(|n| n.contains(['*', '?']));
+        if let Some(pattern) = pattern {
+            let directory = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+            let mut matches: Vec<PathBuf> = std::fs::read_dir(directory)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_name().to_str().is_some_and(|name| wildcard_match(pattern, name)))
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect();
+            matches.sort();
+            states.extend(matches);
+        }
+        else if path.is_dir() {
+            states.extend(watch::save_states_in(path));
+        }
+        else {
+            states.push(path.clone());
+        }
+    }
+    states
+}
+
+/// Match a file name against a pattern where `*` is any number of characters and `?` is one.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Backtrack to just after the last `*` on a mismatch.
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                },
+                None => return false
+            }
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Write one row per labelled report and one column per move, with `header` above the labels.
+pub fn write_matrix(writer: &mut impl Write, header: &str, reports: &[(String, &Report)], format: ReportFormat) -> std::io::Result<()> {
+    let mut columns: Vec<(Outcome, &str)> = reports
+        .iter()
+        .flat_map(|(_, report)| report.rows.iter().map(|row| (row.outcome, row.name.as_str())))
+        .collect();
+    columns.sort_by_key(|&(outcome, _)| outcome);
+    columns.dedup_by_key(|&mut (outcome, _)| outcome);
+
+    let percent = |report: &Report, outcome: Outcome| {
+        let count = report.rows.iter().find(|row| row.outcome == outcome).map_or(0, |row| row.count);
+        if report.total == 0 { 0.0 } else { 100.0 * count as f64 / report.total as f64 }
+    };
+
+    match format {
+        ReportFormat::Csv => {
+            write!(writer, "{}", csv_field(header))?;
+            for (_, name) in &columns {
+                write!(writer, ",{}", csv_field(name))?;
+            }
+            writeln!(writer)?;
+            for (label, report) in reports {
+                write!(writer, "{}", csv_field(label))?;
+                for &(outcome, _) in &columns {
+                    write!(writer, ",{:.2}", percent(report, outcome))?;
+                }
+                writeln!(writer)?;
+            }
+        },
+        ReportFormat::Markdown => {
+            write!(writer, "| {} |", header.replace('|', "\\|"))?;
+            for (_, name) in &columns {
+                write!(writer, " {} |", name.replace('|', "\\|"))?;
+            }
+            writeln!(writer)?;
+            writeln!(writer, "|:--|{}", "--:|".repeat(columns.len()))?;
+            for (label, report) in reports {
+                write!(writer, "| {} |", label.replace('|', "\\|"))?;
+                for &(outcome, _) in &columns {
+                    write!(writer, " {:.2}% |", percent(report, outcome))?;
+                }
+                writeln!(writer)?;
+            }
+        },
+        _ => {
+            let label_width = reports.iter().map(|(label, _)| label.chars().count()).chain([header.chars().count()]).max().unwrap_or(0);
+            write!(writer, "{:label_width$}", header.to_uppercase())?;
+            for (_, name) in &columns {
+                write!(writer, " {name:>width$}", width = name.chars().count().max(7))?;
+            }
+            writeln!(writer)?;
+            for (label, report) in reports {
+                write!(writer, "{label:label_width$}")?;
+                for &(outcome, name) in &columns {
+                    let width = name.chars().count().max(7);
+                    write!(writer, " {:>width$}", format!("{:.1}%", percent(report, outcome)))?;
+                }
+                writeln!(writer)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+//! Simulating states sent from BizHawk.
+//!
+//! BizHawk connects to us when started with `--socket_ip` and `--socket_port`, and the Lua script sends the
+//! state with `comm.socketServerSend`. Messages both ways are the length in bytes as decimal text, a space, and the
+//! message itself; results go back as the same table the CLI prints.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use lorelei_simulator::Simulator;
+use crate::report::{Report, ReportFormat};
+
+pub const DEFAULT_PORT: u16 = 9999;
+
+/// How long to wait for the AI to decide before giving up
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest message accepted, which is much bigger than a hex encoded Game Boy Color's memory
+const MAX_MESSAGE_SIZE: usize = 1 << 20;
+
+pub fn serve(rom_path: &Path, port: u16, trials: NonZeroU64, jobs: Option<NonZeroUsize>) {
+    let Ok(rom) = std::fs::read(rom_path) else {
+        eprintln!("Failed to read ROM {}", rom_path.display());
+        return;
+    };
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(n) => n,
+        Err(e) => {
+            eprintln!("Failed to listen on port {port}: {e}");
+            return;
+        }
+    };
+    let thread_count = jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap());
+
+    println!("Waiting for BizHawk on port {port}... start it with --socket_ip=127.0.0.1 --socket_port={port} and run bizhawk/lorelei.lua");
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        println!("BizHawk connected");
+        if let Err(e) = handle(stream, &rom, trials, thread_count) {
+            println!("BizHawk disconnected: {e}");
+        }
+        else {
+            println!("BizHawk disconnected");
+        }
+    }
+}
+
+/// Simulate every state BizHawk sends until it disconnects.
+fn handle(stream: TcpStream, rom: &[u8], trials: NonZeroU64, thread_count: NonZeroUsize) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    while let Some(state) = read_message(&mut reader)? {
+        let response = simulate(rom, state, trials, thread_count);
+        print!("{response}");
+        writer.write_all(format!("{} {response}", response.len()).as_bytes())?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Read one message, or `None` if the connection was closed.
+fn read_message(reader: &mut impl BufRead) -> std::io::Result<Option<Vec<u8>>> {
+    let mut length = Vec::new();
+    if reader.read_until(b' ', &mut length)? == 0 {
+        return Ok(None);
+    }
+    let length = std::str::from_utf8(&length)
+        .ok()
+        .and_then(|l| l.trim().parse::<usize>().ok())
+        .filter(|&l| l <= MAX_MESSAGE_SIZE)
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "bad message length"))?;
+
+    let mut message = vec![0; length];
+    reader.read_exact(&mut message)?;
+    Ok(Some(message))
+}
+
+fn simulate(rom: &[u8], state: Vec<u8>, trials: NonZeroU64, thread_count: NonZeroUsize) -> String {
+    let mut simulator = match Simulator::new_from_vec(rom.to_vec(), state, Some(trials.get()), None) {
+        Ok(n) => n,
+        Err(e) => return format!("Failed to load simulator: {e}\n")
+    };
+
+    let mut response = Vec::new();
+    if let Some(opponent) = simulator.opponent() {
+        let _ = writeln!(response, "VS {opponent}");
+    }
+    simulator.start(thread_count);
+    let start = Instant::now();
+    while simulator.is_running() {
+        std::thread::sleep(Duration::from_millis(100));
+        if start.elapsed() > TIMEOUT && simulator.results().is_empty() {
+            simulator.stop();
+            let _ = writeln!(response, "No response in {} seconds; send the state just before the AI decides", TIMEOUT.as_secs());
+            return String::from_utf8_lossy(&response).into_owned();
+        }
+    }
+    let _ = Report::new(&simulator).write(&mut response, ReportFormat::Table);
+    String::from_utf8_lossy(&response).into_owned()
+}
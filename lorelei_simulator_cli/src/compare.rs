@@ -0,0 +1,115 @@
+//! Simulating two save states of the same battle to see how the AI's choices change between them.
+
+use std::io::{BufWriter, stdout, Write};
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use console::Term;
+use lorelei_simulator::input::InputStrategy;
+use lorelei_simulator::{significantly_different, Outcome};
+use crate::report::Report;
+use crate::{GameArgs, InputArgs, StateSource};
+
+pub fn compare(rom: &Path, states: [&Path; 2], trials: NonZeroU64, jobs: Option<NonZeroUsize>, mash: Option<InputStrategy>, overrides: GameArgs) {
+    let input = InputArgs {
+        rom: Some(rom.to_owned()),
+        save_state: None,
+        slot: None,
+        battery: None,
+        input_script: None,
+        warm_state: None,
+        mash,
+        overrides
+    };
+    let open = |path: &Path| match input.open(rom, &StateSource::SaveState(path.to_owned()), Some(trials.get())) {
+        Ok(simulator) => Some(simulator),
+        Err(e) => {
+            eprintln!("{e}");
+            None
+        }
+    };
+    let Some(mut a) = open(states[0]) else {
+        return;
+    };
+    let Some(mut b) = open(states[1]) else {
+        return;
+    };
+    if a.game_name() != b.game_name() {
+        eprintln!("The save states are for different games ({} and {})", a.game_name(), b.game_name());
+        return;
+    }
+
+    let bail = Arc::new(AtomicBool::new(false));
+    let bail_copy = bail.clone();
+    let _ = ctrlc::set_handler(move || { bail_copy.swap(true, Ordering::Relaxed); } );
+
+    // Split the threads between the two, giving the first any odd one out.
+    let thread_count = jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap()).get();
+    let a_threads = NonZeroUsize::new(thread_count.div_ceil(2)).unwrap();
+    let b_threads = NonZeroUsize::new(thread_count / 2).unwrap_or(NonZeroUsize::MIN);
+    a.start(a_threads);
+    b.start(b_threads);
+
+    println!("Simulating both... press CTRL-C to stop!");
+    let mut output = Term::stdout();
+    while a.is_running() || b.is_running() {
+        std::thread::sleep(Duration::from_millis(250));
+        if bail.load(Ordering::Relaxed) {
+            a.stop();
+            b.stop();
+        }
+        if output.is_term() {
+            let count = |simulator: &lorelei_simulator::Simulator| simulator.results().values().sum::<u64>();
+            output.clear_line().unwrap();
+            let _ = write!(&mut output, "A: {}/{trials} | B: {}/{trials}", count(&a), count(&b));
+        }
+    }
+    if output.is_term() {
+        output.clear_line().unwrap();
+    }
+    drop(output);
+
+    let a = Report::new(&a);
+    let b = Report::new(&b);
+    let mut writer = BufWriter::new(stdout().lock());
+    let _ = writeln!(writer);
+    let _ = writeln!(writer, "A: {} ({} trials)", states[0].display(), a.total);
+    let _ = writeln!(writer, "B: {} ({} trials)", states[1].display(), b.total);
+    let _ = writeln!(writer);
+    let _ = write_table(&mut writer, &a, &b);
+}
+
+fn write_table(writer: &mut impl Write, a: &Report, b: &Report) -> std::io::Result<()> {
+    let mut outcomes: Vec<Outcome> = a.rows.iter().chain(&b.rows).map(|row| row.outcome).collect();
+    outcomes.sort();
+    outcomes.dedup();
+
+    writeln!(writer, "MOVE               A        B    CHANGE")?;
+    writeln!(writer, "=========================================")?;
+    let mut any_significant = false;
+    for outcome in outcomes {
+        let a_row = a.rows.iter().find(|row| row.outcome == outcome);
+        let b_row = b.rows.iter().find(|row| row.outcome == outcome);
+        let name = a_row.or(b_row).map(|row| row.name.as_str()).unwrap_or_default();
+        let a_count = a_row.map_or(0, |row| row.count);
+        let b_count = b_row.map_or(0, |row| row.count);
+        let percent = |count: u64, total: u64| if total == 0 { 0.0 } else { 100.0 * count as f64 / total as f64 };
+        let (a_percent, b_percent) = (percent(a_count, a.total), percent(b_count, b.total));
+
+        let significant = significantly_different(a_count, a.total, b_count, b.total);
+        any_significant |= significant;
+        writeln!(
+            writer,
+            "{name:-12} {a_percent:7.2}% {b_percent:7.2}% {:+8.2}{}",
+            b_percent - a_percent,
+            if significant { " *" } else { "" }
+        )?;
+    }
+    if any_significant {
+        writeln!(writer)?;
+        writeln!(writer, "* The change is big enough that it's unlikely to be chance (95% confidence)")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,285 @@
+//! Shell completion scripts, generated from the command line definition so they always cover every flag.
+//!
+//! Anything that takes a value without a fixed list of them is completed as a file name, since most of them are.
+
+use std::io::Write;
+
+#[derive(Copy, Clone, PartialEq, Debug, clap::ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish
+}
+
+/// A command or subcommand, flattened out of clap's definition.
+struct CommandInfo {
+    /// Names from the top-level command down to this one
+    path: Vec<String>,
+    options: Vec<OptionInfo>,
+    subcommands: Vec<(String, String)>,
+
+    /// Values the positional arguments can have, if they only take certain ones
+    positional_values: Vec<String>,
+
+    /// Whether any positional argument takes a file (or anything without a list of values)
+    positional_files: bool
+}
+
+struct OptionInfo {
+    shorts: Vec<char>,
+    longs: Vec<String>,
+    help: String,
+    takes_value: bool,
+    repeatable: bool,
+
+    /// Values it can take, or empty for a file name
+    values: Vec<String>
+}
+
+/// Write the completion script for `shell`.
+pub fn write(writer: &mut impl Write, shell: Shell, mut command: clap::Command) -> std::io::Result<()> {
+    // this adds the help flags and subcommand and copies global flags into the subcommands
+    command.build();
+    let mut commands = Vec::new();
+    collect(&command, Vec::new(), &mut commands);
+    match shell {
+        Shell::Bash => write_bash(writer, &commands),
+        Shell::Zsh => write_zsh(writer, &commands),
+        Shell::Fish => write_fish(writer, &commands)
+    }
+}
+
+fn collect(command: &clap::Command, mut path: Vec<String>, commands: &mut Vec<CommandInfo>) {
+    path.push(command.get_name().to_owned());
+    let mut info = CommandInfo { path, options: Vec::new(), subcommands: Vec::new(), positional_values: Vec::new(), positional_files: false };
+    for arg in command.get_arguments().filter(|arg| !arg.is_hide_set()) {
+        let values: Vec<String> = arg
+            .get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| value.get_name().to_owned())
+            .collect();
+        if arg.is_positional() {
+            info.positional_files |= values.is_empty();
+            info.positional_values.extend(values);
+            continue;
+        }
+        info.options.push(OptionInfo {
+            shorts: arg.get_short_and_visible_aliases().unwrap_or_default(),
+            longs: arg.get_long_and_visible_aliases().unwrap_or_default().into_iter().map(str::to_owned).collect(),
+            help: arg.get_help().map(|help| help.to_string().lines().next().unwrap_or_default().to_owned()).unwrap_or_default(),
+            takes_value: arg.get_action().takes_values(),
+            repeatable: matches!(arg.get_action(), clap::ArgAction::Count | clap::ArgAction::Append),
+            values
+        });
+    }
+    for subcommand in command.get_subcommands().filter(|subcommand| !subcommand.is_hide_set()) {
+        let about = subcommand.get_about().map(|about| about.to_string()).unwrap_or_default();
+        info.subcommands.push((subcommand.get_name().to_owned(), about));
+    }
+    let path = info.path.clone();
+    commands.push(info);
+
+    // the help subcommand only takes the names of the others, which are already there
+    for subcommand in command.get_subcommands().filter(|subcommand| !subcommand.is_hide_set() && subcommand.get_name() != "help") {
+        collect(subcommand, path.clone(), commands);
+    }
+}
+
+fn write_bash(writer: &mut impl Write, commands: &[CommandInfo]) -> std::io::Result<()> {
+    let name = &commands[0].path[0];
+    let function = format!("_{name}");
+
+    writeln!(writer, "{function}() {{")?;
+    writeln!(writer, "    local cur prev path options words i")?;
+    writeln!(writer, "    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"")?;
+    writeln!(writer, "    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"")?;
+    writeln!(writer, "    path=\"{name}\"")?;
+    writeln!(writer)?;
+
+    // find which subcommand is being completed
+    writeln!(writer, "    for ((i = 1; i < COMP_CWORD; i++)); do")?;
+    writeln!(writer, "        case \"$path,${{COMP_WORDS[i]}}\" in")?;
+    for command in commands {
+        for (subcommand, _) in &command.subcommands {
+            writeln!(writer, "            {},{subcommand}) path=\"{}__{subcommand}\" ;;", command.path.join("__"), command.path.join("__"))?;
+        }
+    }
+    writeln!(writer, "        esac")?;
+    writeln!(writer, "    done")?;
+    writeln!(writer)?;
+
+    writeln!(writer, "    case \"$path\" in")?;
+    for command in commands {
+        writeln!(writer, "        {})", command.path.join("__"))?;
+        let flags: Vec<String> = command.options.iter().flat_map(option_names).collect();
+        writeln!(writer, "            options=\"{}\"", flags.join(" "))?;
+        let mut words: Vec<&str> = command.subcommands.iter().map(|(name, _)| name.as_str()).collect();
+        words.extend(command.positional_values.iter().map(String::as_str));
+        writeln!(writer, "            words=\"{}\"", words.join(" "))?;
+        let value_options: Vec<&OptionInfo> = command.options.iter().filter(|option| option.takes_value).collect();
+        if !value_options.is_empty() {
+            writeln!(writer, "            case \"$prev\" in")?;
+            for option in value_options {
+                let reply = if option.values.is_empty() {
+                    "$(compgen -f -- \"$cur\")".to_owned()
+                }
+                else {
+                    format!("$(compgen -W \"{}\" -- \"$cur\")", option.values.join(" "))
+                };
+                writeln!(writer, "                {})", option_names(option).join("|"))?;
+                writeln!(writer, "                    COMPREPLY=({reply})")?;
+                writeln!(writer, "                    return")?;
+                writeln!(writer, "                    ;;")?;
+            }
+            writeln!(writer, "            esac")?;
+        }
+        if command.positional_files {
+            writeln!(writer, "            [[ \"$cur\" != -* ]] && COMPREPLY=($(compgen -f -- \"$cur\"))")?;
+        }
+        writeln!(writer, "            ;;")?;
+    }
+    writeln!(writer, "    esac")?;
+    writeln!(writer)?;
+
+    writeln!(writer, "    if [[ \"$cur\" == -* ]]; then")?;
+    writeln!(writer, "        COMPREPLY=($(compgen -W \"$options\" -- \"$cur\"))")?;
+    writeln!(writer, "    else")?;
+    writeln!(writer, "        COMPREPLY+=($(compgen -W \"$words\" -- \"$cur\"))")?;
+    writeln!(writer, "    fi")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(writer, "complete -o filenames -F {function} {name}")
+}
+
+fn write_zsh(writer: &mut impl Write, commands: &[CommandInfo]) -> std::io::Result<()> {
+    let name = &commands[0].path[0];
+    writeln!(writer, "#compdef {name}")?;
+
+    for command in commands {
+        writeln!(writer)?;
+        writeln!(writer, "_{}() {{", command.path.join("__"))?;
+        writeln!(writer, "    local curcontext=\"$curcontext\" state line")?;
+        writeln!(writer, "    _arguments -C -s \\")?;
+        for option in &command.options {
+            let repeat = if option.repeatable { "*" } else { "" };
+            let help = zsh_quote(&option.help.replace('[', "\\[").replace(']', "\\]"));
+            let value = if !option.takes_value {
+                String::new()
+            }
+            else if option.values.is_empty() {
+                ": :_files".to_owned()
+            }
+            else {
+                format!(": :({})", option.values.join(" "))
+            };
+            for short in &option.shorts {
+                let suffix = if option.takes_value { "+" } else { "" };
+                writeln!(writer, "        '{repeat}-{short}{suffix}[{help}]{value}' \\")?;
+            }
+            for long in &option.longs {
+                let suffix = if option.takes_value { "=" } else { "" };
+                writeln!(writer, "        '{repeat}--{long}{suffix}[{help}]{value}' \\")?;
+            }
+        }
+        if !command.subcommands.is_empty() {
+            writeln!(writer, "        ': :->command' \\")?;
+            writeln!(writer, "        '*:: :->arguments'")?;
+            writeln!(writer)?;
+            writeln!(writer, "    case $state in")?;
+            writeln!(writer, "        command)")?;
+            writeln!(writer, "            local -a subcommands")?;
+            writeln!(writer, "            subcommands=(")?;
+            for (subcommand, about) in &command.subcommands {
+                writeln!(writer, "                '{subcommand}:{}'", zsh_quote(about))?;
+            }
+            writeln!(writer, "            )")?;
+            writeln!(writer, "            _describe subcommand subcommands")?;
+            if command.positional_files {
+                writeln!(writer, "            _files")?;
+            }
+            writeln!(writer, "            ;;")?;
+            writeln!(writer, "        arguments)")?;
+            writeln!(writer, "            case $line[1] in")?;
+            for (subcommand, _) in &command.subcommands {
+                writeln!(writer, "                {subcommand}) _{}__{subcommand} ;;", command.path.join("__"))?;
+            }
+            if command.positional_files {
+                writeln!(writer, "                *) _files ;;")?;
+            }
+            writeln!(writer, "            esac")?;
+            writeln!(writer, "            ;;")?;
+            writeln!(writer, "    esac")?;
+        }
+        else if !command.positional_values.is_empty() {
+            writeln!(writer, "        '*: :({})'", command.positional_values.join(" "))?;
+        }
+        else if command.positional_files {
+            writeln!(writer, "        '*: :_files'")?;
+        }
+        else {
+            // end the line continuation
+            writeln!(writer)?;
+        }
+        writeln!(writer, "}}")?;
+    }
+
+    writeln!(writer)?;
+    writeln!(writer, "_{name} \"$@\"")
+}
+
+fn write_fish(writer: &mut impl Write, commands: &[CommandInfo]) -> std::io::Result<()> {
+    let name = &commands[0].path[0];
+    for command in commands {
+        // fish can only tell which subcommands were given, so a command is picked out by its parents being given and
+        // its subcommands not being given
+        let mut conditions: Vec<String> = command.path[1..].iter().map(|parent| format!("__fish_seen_subcommand_from {parent}")).collect();
+        if !command.subcommands.is_empty() {
+            let subcommands: Vec<&str> = command.subcommands.iter().map(|(name, _)| name.as_str()).collect();
+            conditions.push(format!("not __fish_seen_subcommand_from {}", subcommands.join(" ")));
+        }
+        let condition = if conditions.is_empty() { String::new() } else { format!(" -n {}", fish_quote(&conditions.join("; and "))) };
+
+        for option in &command.options {
+            let mut line = format!("complete -c {name}{condition}");
+            for short in &option.shorts {
+                line += &format!(" -s {short}");
+            }
+            for long in &option.longs {
+                line += &format!(" -l {long}");
+            }
+            if option.takes_value {
+                if option.values.is_empty() {
+                    line += " -r";
+                }
+                else {
+                    line += &format!(" -x -a {}", fish_quote(&option.values.join(" ")));
+                }
+            }
+            if !option.help.is_empty() {
+                line += &format!(" -d {}", fish_quote(&option.help));
+            }
+            writeln!(writer, "{line}")?;
+        }
+        for (subcommand, about) in &command.subcommands {
+            writeln!(writer, "complete -c {name}{condition} -f -a {subcommand} -d {}", fish_quote(about))?;
+        }
+        if !command.positional_values.is_empty() {
+            writeln!(writer, "complete -c {name}{condition} -f -a {}", fish_quote(&command.positional_values.join(" ")))?;
+        }
+    }
+    Ok(())
+}
+
+fn option_names(option: &OptionInfo) -> Vec<String> {
+    option.shorts.iter().map(|short| format!("-{short}")).chain(option.longs.iter().map(|long| format!("--{long}"))).collect()
+}
+
+/// Escape text to go in single quotes in zsh.
+fn zsh_quote(text: &str) -> String {
+    text.replace('\'', "'\\''")
+}
+
+fn fish_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,235 @@
+//! Defaults read from a config file, so long command lines don't need repeating.
+//!
+//! ```toml
+//! jobs = 6
+//! format = "markdown"
+//! status_file = "C:/stream/lorelei.txt"
+//!
+//! [profiles.practice]
+//! trials = 20000
+//! precision = 1.0
+//! timeout = 45
+//! ```
+//!
+//! Game configs (for `--game-config`) describe ROM hacks and retitled ROMs:
+//!
+//! ```toml
+//! title = "PM_CRYSTAL_HACK"
+//! game = "crystal"
+//!
+//! [addresses]
+//! decision = 0xC6E4
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use clap::ValueEnum;
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::{Addresses, Game};
+use crate::profile::Profile;
+use crate::report::ReportFormat;
+use crate::toml;
+
+/// Settings from the config file. Anything given on the command line takes priority.
+#[derive(Default)]
+pub struct Config {
+    /// See `--jobs`
+    pub jobs: Option<NonZeroUsize>,
+
+    /// See `--format`
+    pub format: Option<ReportFormat>,
+
+    /// See `--status-file`
+    pub status_file: Option<PathBuf>,
+
+    /// Profiles for `--profile`, which can replace the built-in ones
+    pub profiles: Vec<(String, Profile)>,
+
+    /// Game configs used for ROMs with the titles they give
+    pub game_configs: Vec<GameConfig>
+}
+
+/// How to simulate a ROM the simulator doesn't know as-is, from `--game-config`.
+#[derive(Clone, Default)]
+pub struct GameConfig {
+    /// Title in the ROM's header this is for, so it can be picked automatically
+    pub title: Option<String>,
+
+    /// See `--game`
+    pub game: Option<Game>,
+
+    /// Addresses to change, by the names [`Addresses::set`] takes
+    pub addresses: Vec<(String, u16)>
+}
+
+/// Why the config file couldn't be loaded.
+pub enum ConfigError {
+    Read(std::io::Error),
+    Toml(toml::TomlError),
+    Invalid(String)
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Read(e) => write!(f, "{e}"),
+            ConfigError::Toml(e) => write!(f, "{e}"),
+            ConfigError::Invalid(e) => write!(f, "{e}")
+        }
+    }
+}
+
+impl Config {
+    /// Where the config file is if one isn't given with `--config`.
+    pub fn default_path() -> Option<PathBuf> {
+        let directory = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        }
+        else {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        };
+        directory.map(|directory| directory.join("lorelei-simulator").join("config.toml"))
+    }
+
+    /// Load the config file at `path`.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(ConfigError::Read)?;
+        let config = toml::parse(&text).map_err(ConfigError::Toml)?;
+
+        // game configs are listed relative to the config file
+        Self::from_toml(&config, path.parent().unwrap_or(Path::new(""))).map_err(ConfigError::Invalid)
+    }
+
+    /// Look up a profile, preferring ones from the config file over the built-in ones.
+    pub fn profile(&self, name: &str) -> Option<Profile> {
+        self.profiles
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, profile)| profile.clone())
+            .or_else(|| Profile::builtin(name))
+    }
+
+    fn from_toml(config: &JsonValue, directory: &Path) -> Result<Self, String> {
+        let JsonValue::Object(entries) = config else {
+            unreachable!("TOML documents are always tables")
+        };
+
+        let mut result = Self::default();
+        for (key, value) in entries {
+            match key.as_str() {
+                "jobs" => result.jobs = Some(read_jobs(value).ok_or("jobs should be a number above 0")?),
+                "format" => {
+                    let format = value.as_str().and_then(|f| ReportFormat::from_str(f, true).ok());
+                    result.format = Some(format.ok_or("format should be table, csv, markdown, or json")?);
+                },
+                "status_file" => result.status_file = Some(PathBuf::from(value.as_str().ok_or("status_file should be a path")?)),
+                "profiles" => {
+                    let JsonValue::Object(profiles) = value else {
+                        return Err("profiles should be a table of profiles, e.g. [profiles.practice]".to_owned());
+                    };
+                    for (name, profile) in profiles {
+                        let profile = read_profile(profile).map_err(|e| format!("profile {name}: {e}"))?;
+                        result.profiles.push((name.clone(), profile));
+                    }
+                },
+                "game_configs" => {
+                    let paths = value.as_array().ok_or("game_configs should be a list of paths")?;
+                    for path in paths {
+                        let path = directory.join(path.as_str().ok_or("game_configs should be a list of paths")?);
+                        let game_config = GameConfig::load(&path).map_err(|e| format!("game config {}: {e}", path.display()))?;
+                        if game_config.title.is_none() {
+                            return Err(format!("game config {} needs a title to be listed in game_configs", path.display()));
+                        }
+                        result.game_configs.push(game_config);
+                    }
+                },
+                key => return Err(format!("unknown setting {key}"))
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl GameConfig {
+    /// Load the game config at `path`.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(ConfigError::Read)?;
+        let config = toml::parse(&text).map_err(ConfigError::Toml)?;
+        Self::from_toml(&config).map_err(ConfigError::Invalid)
+    }
+
+    fn from_toml(config: &JsonValue) -> Result<Self, String> {
+        let JsonValue::Object(entries) = config else {
+            unreachable!("TOML documents are always tables")
+        };
+
+        let mut result = Self::default();
+        for (key, value) in entries {
+            match key.as_str() {
+                "title" => result.title = Some(value.as_str().ok_or("title should be a string")?.to_owned()),
+                "game" => {
+                    let game = value.as_str().and_then(Game::from_short_name);
+                    result.game = Some(game.ok_or("game should be red, blue, yellow, gold, silver, or crystal")?);
+                },
+                "addresses" => {
+                    let JsonValue::Object(addresses) = value else {
+                        return Err("addresses should be a table, e.g. [addresses]".to_owned());
+                    };
+                    for (name, address) in addresses {
+                        if !is_address_name(name) {
+                            return Err(format!("unknown address {name}"));
+                        }
+                        let address = address.as_u64().and_then(|a| u16::try_from(a).ok()).ok_or_else(|| format!("{name} should be an address, e.g. 0xC6E4"))?;
+                        result.addresses.push((name.clone(), address));
+                    }
+                },
+                key => return Err(format!("unknown setting {key}"))
+            }
+        }
+        Ok(result)
+    }
+
+    /// Change `addresses` to the ones given here.
+    pub fn apply(&self, addresses: &mut Addresses) {
+        for (name, address) in &self.addresses {
+            addresses.set(name, *address);
+        }
+    }
+}
+
+/// Whether [`Addresses::set`] takes this name.
+pub fn is_address_name(name: &str) -> bool {
+    name == "decision" || Addresses::NAMES.contains(&name)
+}
+
+fn read_jobs(value: &JsonValue) -> Option<NonZeroUsize> {
+    value.as_u64().and_then(|jobs| usize::try_from(jobs).ok()).and_then(NonZeroUsize::new)
+}
+
+fn read_profile(profile: &JsonValue) -> Result<Profile, String> {
+    let JsonValue::Object(entries) = profile else {
+        return Err("should be a table".to_owned());
+    };
+
+    let mut result = Profile::default();
+    for (key, value) in entries {
+        match key.as_str() {
+            "trials" => result.trials = Some(value.as_u64().and_then(NonZeroU64::new).ok_or("trials should be a number above 0")?),
+            "precision" => {
+                let precision = value.as_f64().filter(|&p| p > 0.0 && p <= 100.0);
+                result.precision = Some(precision.ok_or("precision should be a percentage above 0, e.g. 0.5")?);
+            },
+            "timeout" => {
+                let timeout = value.as_u64().filter(|&t| t > 0).map(Duration::from_secs);
+                result.timeout = Some(timeout.ok_or("timeout should be a number of seconds above 0")?);
+            },
+            "jobs" => result.jobs = Some(read_jobs(value).ok_or("jobs should be a number above 0")?),
+            key => return Err(format!("unknown setting {key}"))
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,96 @@
+//! Appending snapshots of the results to a CSV file while running, for plotting how they converge.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use lorelei_simulator::Simulator;
+use crate::report::{csv_field, kind_and_index, Report};
+
+/// How often to take a snapshot.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SnapshotInterval {
+    Seconds(u64),
+    Trials(u64)
+}
+
+impl FromStr for SnapshotInterval {
+    type Err = &'static str;
+
+    /// Parse a number of trials (e.g. `1000`) or seconds (e.g. `10s`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, seconds) = match s.strip_suffix('s') {
+            Some(number) => (number, true),
+            None => (s, false)
+        };
+        match number.parse::<u64>() {
+            Ok(0) | Err(_) => Err("expected a number of trials, or of seconds ending in s (e.g. 10s)"),
+            Ok(n) if seconds => Ok(Self::Seconds(n)),
+            Ok(n) => Ok(Self::Trials(n))
+        }
+    }
+}
+
+pub struct ConvergenceLog {
+    writer: BufWriter<File>,
+    interval: SnapshotInterval,
+
+    /// Seconds or trials when the next snapshot is due
+    next: u64
+}
+
+impl ConvergenceLog {
+    /// Open the file for appending, writing the header if it's new.
+    pub fn open(path: &Path, interval: SnapshotInterval) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let is_new = file.metadata()?.len() == 0;
+        let mut writer = BufWriter::new(file);
+        if is_new {
+            writeln!(writer, "elapsed_seconds,trials,kind,index,name,count,percent")?;
+            writer.flush()?;
+        }
+        let next = match interval {
+            SnapshotInterval::Seconds(n) | SnapshotInterval::Trials(n) => n
+        };
+        Ok(Self { writer, interval, next })
+    }
+
+    /// Take a snapshot if one is due.
+    pub fn update(&mut self, simulator: &Simulator, elapsed: Duration) -> std::io::Result<()> {
+        let report = Report::new(simulator);
+        let (progress, step) = match self.interval {
+            SnapshotInterval::Seconds(n) => (elapsed.as_secs(), n),
+            SnapshotInterval::Trials(n) => (report.total, n)
+        };
+        if progress < self.next {
+            return Ok(());
+        }
+        self.next = (progress / step + 1) * step;
+        self.snapshot(&report, elapsed)
+    }
+
+    /// Take a snapshot now, e.g. when finished.
+    pub fn finish(&mut self, simulator: &Simulator, elapsed: Duration) -> std::io::Result<()> {
+        self.snapshot(&Report::new(simulator), elapsed)
+    }
+
+    fn snapshot(&mut self, report: &Report, elapsed: Duration) -> std::io::Result<()> {
+        if report.total == 0 {
+            return Ok(());
+        }
+        let seconds = elapsed.as_secs_f64();
+        for row in &report.rows {
+            let (kind, index) = kind_and_index(row.outcome);
+            writeln!(
+                self.writer,
+                "{seconds:.1},{},{kind},{index},{},{},{:.4}",
+                report.total,
+                csv_field(&row.name),
+                row.count,
+                100.0 * row.fraction(report.total)
+            )?;
+        }
+        self.writer.flush()
+    }
+}
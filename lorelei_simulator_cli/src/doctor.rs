@@ -0,0 +1,166 @@
+use std::path::Path;
+use lorelei_simulator::{Generation, Outcome, Severity, SimulatorError, StateIssue};
+use lorelei_simulator::battle::BattleMon;
+use crate::inspect::species_name;
+use lorelei_simulator::report::display_name;
+use crate::{InputArgs, LoadError, StateSource};
+
+/// How many frames to run looking for the AI's decision (one minute of game time).
+const PROBE_FRAMES: u32 = 60 * 60;
+
+struct Report {
+    problems: usize
+}
+
+impl Report {
+    fn ok(&self, message: &str) {
+        println!("[ OK ] {message}");
+    }
+
+    fn info(&self, message: &str) {
+        println!("       {message}");
+    }
+
+    fn warning(&self, message: &str) {
+        println!("[WARN] {message}");
+    }
+
+    fn problem(&mut self, message: &str, suggestion: &str) {
+        self.problems += 1;
+        println!("[FAIL] {message}");
+        println!("       -> {suggestion}");
+    }
+}
+
+pub fn doctor(input: &InputArgs) {
+    let Some((rom_path, source)) = input.resolve() else {
+        return;
+    };
+
+    let mut report = Report { problems: 0 };
+    check(&mut report, input, &rom_path, &source);
+
+    println!();
+    match report.problems {
+        0 => println!("No problems found."),
+        1 => println!("1 problem found."),
+        n => println!("{n} problems found.")
+    }
+}
+
+fn check(report: &mut Report, input: &InputArgs, rom_path: &Path, source: &StateSource) {
+    let simulator = match input.open(rom_path, source, None) {
+        Ok(n) => n,
+        Err(e @ LoadError::Read { .. }) => {
+            report.problem(&e.to_string(), "Check that the path is correct.");
+            return;
+        },
+        Err(e @ LoadError::InputScript(_)) => {
+            report.problem(&e.to_string(), "Fix the input script; see the README for its format.");
+            return;
+        },
+        Err(e @ LoadError::Simulator(SimulatorError::SaveStateError)) => {
+            report.problem(&e.to_string(), "Use a SameBoy, BGB, Gambatte, mGBA, or other BESS-compatible save state made with this ROM.");
+            return;
+        },
+        Err(e @ LoadError::Simulator(SimulatorError::UnknownGame { .. })) => {
+            report.problem(&e.to_string(), "Use an English Pokémon Red, Blue, Yellow, Gold, Silver, or Crystal ROM, or for a ROM hack, give the game it's based on with --game.");
+            return;
+        },
+        Err(e @ LoadError::GameConfig { .. }) => {
+            report.problem(&e.to_string(), "Fix the game config; see the README for its format.");
+            return;
+        },
+        Err(e @ LoadError::Simulator(SimulatorError::ImportError { .. })) => {
+            report.problem(&e.to_string(), "Make the save state in SameBoy instead, or make a new one with an up-to-date emulator.");
+            return;
+        },
+        Err(e @ LoadError::Simulator(SimulatorError::CheckpointError)) => {
+            report.problem(&e.to_string(), "Start over from the save state.");
+            return;
+        }
+    };
+
+    report.ok(&format!("Game: {} ({})", simulator.game_name(), simulator.language().name()));
+    report.ok(&format!("Save state: {} ({})", simulator.model_name(), simulator.save_state_format()));
+
+    let addresses = simulator.addresses();
+    report.info(&format!(
+        "Addresses: selected move 0x{:04X}, move number 0x{:04X}, RNG 0x{:04X}/0x{:04X}",
+        addresses.enemy_selected_move,
+        addresses.enemy_move_num,
+        addresses.random_low,
+        addresses.random_high
+    ));
+    if let Some(ai_item) = addresses.ai_item {
+        report.info(&format!("           AI item 0x{ai_item:04X}"));
+    }
+
+    match simulator.battle_context() {
+        Some(context) => {
+            let name = |mon: &BattleMon| species_name(mon.species, mon.species_index);
+            let battle_type = if context.trainer_battle { "Trainer" } else { "Wild" };
+            report.ok(&format!(
+                "{battle_type} battle: {} L{} vs {} L{}",
+                name(&context.enemy),
+                context.enemy.level,
+                name(&context.player),
+                context.player.level
+            ));
+        },
+        None => {
+            let suggestion = match source {
+                StateSource::SaveState(_) | StateSource::WarmState(_) => "Make the save state during the battle you want to simulate.",
+                StateSource::Battery { .. } => "Make the input script go further, until the battle you want to simulate."
+            };
+            report.problem("The save state is not in a battle", suggestion);
+            return;
+        }
+    }
+
+    let probe = simulator.probe(PROBE_FRAMES);
+    let seconds = |frames: u32| frames as f64 / 60.0;
+
+    match probe.rng_frame {
+        Some(frame) => report.ok(&format!("The game read the RNG after {frame} frames ({:.1} s)", seconds(frame))),
+        None => report.problem(
+            &format!("The game never read the RNG in {:.0} seconds", seconds(PROBE_FRAMES)),
+            "The save state is probably in a menu or paused; make it closer to the AI's decision."
+        )
+    }
+
+    match (probe.decision_frame, probe.outcome) {
+        (Some(frame), Some(outcome)) => {
+            let what = match outcome {
+                Outcome::Move(_) => "selected",
+                Outcome::Item(_) => "used"
+            };
+            report.ok(&format!(
+                "The AI {what} {} after {frame} frames ({:.1} s)",
+                display_name(&simulator, outcome),
+                seconds(frame)
+            ));
+        },
+        _ => {
+            let suggestion = match simulator.generation() {
+                Generation::One => "In Gen 1, make the save state in the FIGHT menu with the cursor on a move.",
+                Generation::Two => "In Gen 2, make the save state during a Pokémon's cry or at the very end of a turn."
+            };
+            report.problem(&format!("The AI did not make a decision in {:.0} seconds", seconds(PROBE_FRAMES)), suggestion);
+        }
+    }
+
+    for issue in simulator.validate_state() {
+        let suggestion = match issue {
+            // already covered above
+            StateIssue::NotInBattle | StateIssue::NoDecision { .. } => continue,
+            StateIssue::MenuOpen => "Make the save state before the FIGHT menu appears (e.g. at the very end of the previous turn).",
+            StateIssue::EnemyLockedIn => "Make the save state on a turn where the enemy can pick its move.",
+            StateIssue::WildBattle | StateIssue::MidAnimation { .. } => ""
+        };
+        match issue.severity() {
+            Severity::Warning => report.warning(&issue.to_string()),
+            Severity::Error => report.problem(&issue.to_string(), suggestion)
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Printing the results so far when asked with SIGUSR1 (or SIGINFO, i.e. CTRL-T, on BSD and macOS), so a run in the
+//! background can be checked on without stopping it.
+
+#[cfg(unix)]
+mod platform {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+    extern "C" fn handle(_signal: libc::c_int) {
+        // only async-signal-safe things can be done here, so the results are printed by the main loop
+        REQUESTED.store(true, Ordering::Relaxed);
+    }
+
+    pub fn install() {
+        let handler = handle as extern "C" fn(libc::c_int) as libc::sighandler_t;
+
+        // SAFETY: the handler only stores to an atomic
+        unsafe {
+            libc::signal(libc::SIGUSR1, handler);
+
+            #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd", target_os = "netbsd", target_os = "dragonfly"))]
+            libc::signal(libc::SIGINFO, handler);
+        }
+    }
+
+    pub fn requested() -> bool {
+        REQUESTED.swap(false, Ordering::Relaxed)
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    pub fn install() {}
+
+    pub fn requested() -> bool {
+        false
+    }
+}
+
+/// Start listening for the signal. Until this is called, SIGUSR1 stops the simulator as usual.
+pub fn install() {
+    platform::install()
+}
+
+/// Whether the results were asked for since this was last called.
+pub fn requested() -> bool {
+    platform::requested()
+}
@@ -0,0 +1,126 @@
+//! Global hotkeys, so the simulator can be started over without switching away from the emulator.
+
+use std::fmt::{Display, Formatter};
+
+#[cfg(windows)]
+mod windows;
+#[cfg(windows)]
+use windows as platform;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod x11;
+#[cfg(all(unix, not(target_os = "macos")))]
+use x11 as platform;
+
+#[cfg(not(any(windows, all(unix, not(target_os = "macos")))))]
+mod platform {
+    use super::{HotkeyError, HotkeySpec};
+
+    pub struct Grab;
+
+    impl Grab {
+        pub fn new(_spec: &HotkeySpec) -> Result<Self, HotkeyError> {
+            Err(HotkeyError::Unsupported)
+        }
+
+        pub fn pressed(&mut self) -> bool {
+            false
+        }
+    }
+}
+
+/// A key combination, e.g. `Ctrl+Shift+F9`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HotkeySpec {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub super_key: bool,
+
+    /// Name of the key, e.g. `F9` or `R`
+    pub key: String
+}
+
+impl HotkeySpec {
+    /// Parse a key combination, e.g. `Ctrl+Shift+F9`.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut result = Self { ctrl: false, alt: false, shift: false, super_key: false, key: String::new() };
+        let mut parts: Vec<&str> = spec.split('+').map(str::trim).collect();
+        let key = parts.pop().unwrap_or_default();
+        if key.is_empty() {
+            return Err("missing key".to_owned());
+        }
+        for modifier in parts {
+            let flag = match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut result.ctrl,
+                "alt" => &mut result.alt,
+                "shift" => &mut result.shift,
+                "super" | "win" | "meta" => &mut result.super_key,
+                _ => return Err(format!("unknown modifier {modifier}"))
+            };
+            *flag = true;
+        }
+        result.key = key.to_owned();
+        Ok(result)
+    }
+}
+
+impl Display for HotkeySpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (held, name) in [(self.ctrl, "Ctrl+"), (self.alt, "Alt+"), (self.shift, "Shift+"), (self.super_key, "Super+")] {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+        f.write_str(&self.key)
+    }
+}
+
+/// Error returned when a hotkey can't be registered.
+#[derive(Clone, PartialEq, Debug)]
+pub enum HotkeyError {
+    /// Global hotkeys aren't implemented for this platform
+    #[cfg_attr(any(windows, all(unix, not(target_os = "macos"))), allow(dead_code))]
+    Unsupported,
+
+    /// There is no display to grab keys from (e.g. over SSH)
+    NoDisplay,
+
+    /// The key name isn't known
+    UnknownKey(String),
+
+    /// Another program already has the hotkey
+    InUse
+}
+
+impl Display for HotkeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("global hotkeys aren't supported on this platform"),
+            Self::NoDisplay => f.write_str("can't connect to the display"),
+            Self::UnknownKey(key) => write!(f, "unknown key {key}"),
+            Self::InUse => f.write_str("another program is already using it")
+        }
+    }
+}
+
+/// A registered global hotkey. It's unregistered when dropped.
+pub struct Hotkey {
+    spec: HotkeySpec,
+    grab: platform::Grab
+}
+
+impl Hotkey {
+    pub fn register(spec: &HotkeySpec) -> Result<Self, HotkeyError> {
+        Ok(Self { spec: spec.clone(), grab: platform::Grab::new(spec)? })
+    }
+
+    pub fn spec(&self) -> &HotkeySpec {
+        &self.spec
+    }
+
+    /// Check if the hotkey was pressed since the last time this was called.
+    pub fn pressed(&mut self) -> bool {
+        self.grab.pressed()
+    }
+}
@@ -0,0 +1,99 @@
+//! Hotkeys on Windows, using RegisterHotKey.
+
+use std::ffi::{c_int, c_uint, c_void};
+use super::{HotkeyError, HotkeySpec};
+
+const HOTKEY_ID: c_int = 1;
+const WM_HOTKEY: c_uint = 0x0312;
+const PM_REMOVE: c_uint = 0x0001;
+
+const MOD_ALT: c_uint = 0x0001;
+const MOD_CONTROL: c_uint = 0x0002;
+const MOD_SHIFT: c_uint = 0x0004;
+const MOD_WIN: c_uint = 0x0008;
+const MOD_NOREPEAT: c_uint = 0x4000;
+
+#[repr(C)]
+#[allow(dead_code)] // filled in by PeekMessageW
+struct Msg {
+    hwnd: *mut c_void,
+    message: c_uint,
+    w_param: usize,
+    l_param: isize,
+    time: u32,
+    pt_x: i32,
+    pt_y: i32,
+    l_private: u32
+}
+
+#[link(name = "user32")]
+extern "system" {
+    fn RegisterHotKey(hwnd: *mut c_void, id: c_int, modifiers: c_uint, vk: c_uint) -> c_int;
+    fn UnregisterHotKey(hwnd: *mut c_void, id: c_int) -> c_int;
+    fn PeekMessageW(msg: *mut Msg, hwnd: *mut c_void, filter_min: c_uint, filter_max: c_uint, remove: c_uint) -> c_int;
+}
+
+/// The hotkey is tied to the thread that registered it, so this has to be polled from that thread.
+pub struct Grab;
+
+impl Grab {
+    pub fn new(spec: &HotkeySpec) -> Result<Self, HotkeyError> {
+        let vk = virtual_key(&spec.key).ok_or_else(|| HotkeyError::UnknownKey(spec.key.clone()))?;
+        let mut modifiers = MOD_NOREPEAT;
+        for (held, flag) in [(spec.ctrl, MOD_CONTROL), (spec.alt, MOD_ALT), (spec.shift, MOD_SHIFT), (spec.super_key, MOD_WIN)] {
+            if held {
+                modifiers |= flag;
+            }
+        }
+
+        // SAFETY: a null window posts WM_HOTKEY to this thread's message queue
+        if unsafe { RegisterHotKey(std::ptr::null_mut(), HOTKEY_ID, modifiers, vk) } == 0 {
+            return Err(HotkeyError::InUse);
+        }
+        Ok(Self)
+    }
+
+    pub fn pressed(&mut self) -> bool {
+        let mut pressed = false;
+        let mut msg = Msg { hwnd: std::ptr::null_mut(), message: 0, w_param: 0, l_param: 0, time: 0, pt_x: 0, pt_y: 0, l_private: 0 };
+
+        // SAFETY: msg is a valid MSG
+        while unsafe { PeekMessageW(&mut msg, std::ptr::null_mut(), WM_HOTKEY, WM_HOTKEY, PM_REMOVE) } != 0 {
+            pressed = true;
+        }
+        pressed
+    }
+}
+
+impl Drop for Grab {
+    fn drop(&mut self) {
+        // SAFETY: the hotkey was registered by this thread
+        unsafe { UnregisterHotKey(std::ptr::null_mut(), HOTKEY_ID) };
+    }
+}
+
+fn virtual_key(name: &str) -> Option<c_uint> {
+    let name = name.to_ascii_uppercase();
+    if let [c] = name.as_bytes() {
+        return c.is_ascii_alphanumeric().then_some(*c as c_uint);
+    }
+    if let Some(n) = name.strip_prefix('F').and_then(|n| n.parse::<c_uint>().ok()) {
+        return (1..=24).contains(&n).then_some(0x70 + n - 1);
+    }
+    let vk = match name.as_str() {
+        "SPACE" => 0x20,
+        "ENTER" | "RETURN" => 0x0D,
+        "TAB" => 0x09,
+        "ESC" | "ESCAPE" => 0x1B,
+        "PAUSE" => 0x13,
+        "PAGEUP" | "PRIOR" => 0x21,
+        "PAGEDOWN" | "NEXT" => 0x22,
+        "END" => 0x23,
+        "HOME" => 0x24,
+        "INSERT" => 0x2D,
+        "DELETE" => 0x2E,
+        "SCROLLLOCK" | "SCROLL_LOCK" => 0x91,
+        _ => return None
+    };
+    Some(vk)
+}
@@ -0,0 +1,180 @@
+//! Hotkeys on X11 (including XWayland), using XGrabKey on the root window.
+//!
+//! Xlib is loaded at runtime so the simulator still runs on systems without it.
+
+use std::ffi::{c_char, c_int, c_long, c_uint, c_ulong, c_void, CString};
+use std::sync::atomic::{AtomicBool, Ordering};
+use super::{HotkeyError, HotkeySpec};
+
+type Display = c_void;
+type ErrorHandler = unsafe extern "C" fn(*mut Display, *mut c_void) -> c_int;
+
+const KEY_PRESS: c_int = 2;
+const GRAB_MODE_ASYNC: c_int = 1;
+
+const SHIFT_MASK: c_uint = 1 << 0;
+const LOCK_MASK: c_uint = 1 << 1;
+const CONTROL_MASK: c_uint = 1 << 2;
+const MOD1_MASK: c_uint = 1 << 3; // Alt
+const MOD2_MASK: c_uint = 1 << 4; // Num Lock
+const MOD4_MASK: c_uint = 1 << 6; // Super
+
+/// Set by the error handler while grabbing
+static GRAB_FAILED: AtomicBool = AtomicBool::new(false);
+
+#[repr(C)]
+union XEvent {
+    kind: c_int,
+    pad: [c_long; 24]
+}
+
+struct Xlib {
+    library: *mut c_void,
+    open_display: unsafe extern "C" fn(*const c_char) -> *mut Display,
+    close_display: unsafe extern "C" fn(*mut Display) -> c_int,
+    default_root_window: unsafe extern "C" fn(*mut Display) -> c_ulong,
+    string_to_keysym: unsafe extern "C" fn(*const c_char) -> c_ulong,
+    keysym_to_keycode: unsafe extern "C" fn(*mut Display, c_ulong) -> u8,
+    grab_key: unsafe extern "C" fn(*mut Display, c_int, c_uint, c_ulong, c_int, c_int, c_int) -> c_int,
+    ungrab_key: unsafe extern "C" fn(*mut Display, c_int, c_uint, c_ulong) -> c_int,
+    set_error_handler: unsafe extern "C" fn(Option<ErrorHandler>) -> Option<ErrorHandler>,
+    sync: unsafe extern "C" fn(*mut Display, c_int) -> c_int,
+    pending: unsafe extern "C" fn(*mut Display) -> c_int,
+    next_event: unsafe extern "C" fn(*mut Display, *mut XEvent) -> c_int
+}
+
+impl Xlib {
+    #[allow(clippy::missing_transmute_annotations)] // the types come from the fields
+    fn load() -> Option<Self> {
+        // SAFETY: the symbols are cast to their signatures in Xlib.h
+        unsafe {
+            let library = libc::dlopen(c"libX11.so.6".as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+            if library.is_null() {
+                return None;
+            }
+            macro_rules! symbol {
+                ($name:literal) => {{
+                    let symbol = libc::dlsym(library, $name.as_ptr());
+                    if symbol.is_null() {
+                        libc::dlclose(library);
+                        return None;
+                    }
+                    std::mem::transmute::<*mut c_void, _>(symbol)
+                }};
+            }
+            Some(Self {
+                library,
+                open_display: symbol!(c"XOpenDisplay"),
+                close_display: symbol!(c"XCloseDisplay"),
+                default_root_window: symbol!(c"XDefaultRootWindow"),
+                string_to_keysym: symbol!(c"XStringToKeysym"),
+                keysym_to_keycode: symbol!(c"XKeysymToKeycode"),
+                grab_key: symbol!(c"XGrabKey"),
+                ungrab_key: symbol!(c"XUngrabKey"),
+                set_error_handler: symbol!(c"XSetErrorHandler"),
+                sync: symbol!(c"XSync"),
+                pending: symbol!(c"XPending"),
+                next_event: symbol!(c"XNextEvent")
+            })
+        }
+    }
+}
+
+pub struct Grab {
+    xlib: Xlib,
+    display: *mut Display,
+    root: c_ulong,
+    keycode: c_int,
+    modifiers: c_uint
+}
+
+impl Grab {
+    pub fn new(spec: &HotkeySpec) -> Result<Self, HotkeyError> {
+        let xlib = Xlib::load().ok_or(HotkeyError::NoDisplay)?;
+
+        // SAFETY: the display is checked before use and closed when the grab is dropped
+        unsafe {
+            let display = (xlib.open_display)(std::ptr::null());
+            if display.is_null() {
+                libc::dlclose(xlib.library);
+                return Err(HotkeyError::NoDisplay);
+            }
+
+            let mut grab = Self { root: (xlib.default_root_window)(display), xlib, display, keycode: 0, modifiers: 0 };
+
+            // keysym names are case sensitive (e.g. "F9" but "r"), so try both
+            let keycode = [spec.key.clone(), spec.key.to_lowercase()]
+                .into_iter()
+                .filter_map(|name| CString::new(name).ok())
+                .map(|name| (grab.xlib.string_to_keysym)(name.as_ptr()))
+                .find(|&keysym| keysym != 0)
+                .map(|keysym| (grab.xlib.keysym_to_keycode)(display, keysym))
+                .unwrap_or(0);
+            if keycode == 0 {
+                return Err(HotkeyError::UnknownKey(spec.key.clone()));
+            }
+            grab.keycode = keycode as c_int;
+
+            for (held, mask) in [(spec.ctrl, CONTROL_MASK), (spec.alt, MOD1_MASK), (spec.shift, SHIFT_MASK), (spec.super_key, MOD4_MASK)] {
+                if held {
+                    grab.modifiers |= mask;
+                }
+            }
+
+            // A failed grab is reported asynchronously, and the default handler exits the program.
+            GRAB_FAILED.store(false, Ordering::Relaxed);
+            let previous_handler = (grab.xlib.set_error_handler)(Some(on_error));
+            grab.for_each_lock_state(|xlib, display, keycode, modifiers, root| {
+                (xlib.grab_key)(display, keycode, modifiers, root, 0, GRAB_MODE_ASYNC, GRAB_MODE_ASYNC);
+            });
+            (grab.xlib.sync)(display, 0);
+            (grab.xlib.set_error_handler)(previous_handler);
+
+            if GRAB_FAILED.load(Ordering::Relaxed) {
+                return Err(HotkeyError::InUse);
+            }
+            Ok(grab)
+        }
+    }
+
+    pub fn pressed(&mut self) -> bool {
+        let mut pressed = false;
+
+        // SAFETY: the display is open
+        unsafe {
+            while (self.xlib.pending)(self.display) > 0 {
+                let mut event = XEvent { pad: [0; 24] };
+                (self.xlib.next_event)(self.display, &mut event);
+                pressed |= event.kind == KEY_PRESS;
+            }
+        }
+        pressed
+    }
+
+    /// Num Lock and Caps Lock count as modifiers, so the key has to be grabbed with every combination of them.
+    unsafe fn for_each_lock_state(&self, f: impl Fn(&Xlib, *mut Display, c_int, c_uint, c_ulong)) {
+        for locks in [0, LOCK_MASK, MOD2_MASK, LOCK_MASK | MOD2_MASK] {
+            f(&self.xlib, self.display, self.keycode, self.modifiers | locks, self.root);
+        }
+    }
+}
+
+impl Drop for Grab {
+    fn drop(&mut self) {
+        // SAFETY: the display is open and gets closed here, and nothing from the library is used afterwards
+        unsafe {
+            if self.keycode != 0 {
+                self.for_each_lock_state(|xlib, display, keycode, modifiers, root| {
+                    (xlib.ungrab_key)(display, keycode, modifiers, root);
+                });
+            }
+            (self.xlib.close_display)(self.display);
+            libc::dlclose(self.xlib.library);
+        }
+    }
+}
+
+unsafe extern "C" fn on_error(_display: *mut Display, _event: *mut c_void) -> c_int {
+    GRAB_FAILED.store(true, Ordering::Relaxed);
+    0
+}
@@ -0,0 +1,108 @@
+use std::io::{BufWriter, stdout, Write};
+use lorelei_simulator::battle::{badge_names, BattleMon, StatStages};
+use lorelei_simulator::{Generation, Outcome, Simulator, Species, Type};
+use lorelei_simulator::report::display_name;
+use crate::InputArgs;
+
+pub fn inspect(input: &InputArgs) {
+    let Some(simulator) = input.load(None) else {
+        return;
+    };
+
+    let Some(context) = simulator.battle_context() else {
+        eprintln!("The save state is not in a battle");
+        return;
+    };
+
+    let mut writer = BufWriter::new(stdout().lock());
+    let _ = writeln!(writer, "{}", simulator.game_name());
+    let opponent = match simulator.opponent() {
+        Some(opponent) => format!("VS {opponent}"),
+        None => "Wild battle".to_owned()
+    };
+    let _ = writeln!(writer, "{opponent}");
+
+    let badges = badge_names(simulator.generation(), context.badges);
+    if badges.is_empty() {
+        let _ = writeln!(writer, "Badges: none");
+    }
+    else {
+        let _ = writeln!(writer, "Badges: {}", badges.join(", "));
+    }
+
+    let _ = writeln!(writer);
+    write_mon(&mut writer, &simulator, "ENEMY", &context.enemy, &context.enemy_stat_stages);
+    let _ = writeln!(writer);
+    write_mon(&mut writer, &simulator, "PLAYER", &context.player, &context.player_stat_stages);
+
+    if let Some(party) = simulator.enemy_party() {
+        let _ = writeln!(writer);
+        let _ = writeln!(writer, "ENEMY PARTY:");
+        for mon in party {
+            let species = species_name(mon.species, mon.species_index);
+            let state = if mon.hp == 0 { "fainted".to_owned() } else { format!("HP {}/{}", mon.hp, mon.max_hp) };
+            let _ = writeln!(writer, "  {species:-10} L{:<3} {state}", mon.level);
+        }
+    }
+}
+
+fn write_mon(writer: &mut impl Write, simulator: &Simulator, side: &str, mon: &BattleMon, stages: &StatStages) {
+    let generation = simulator.generation();
+
+    let species = species_name(mon.species, mon.species_index);
+    let status = mon.status.abbreviation().map(|s| format!(", {s}")).unwrap_or_default();
+    let _ = writeln!(writer, "{side}: {species} L{} (HP {}/{}{status})", mon.level, mon.hp, mon.max_hp);
+
+    let mut types = Vec::new();
+    for t in mon.types {
+        let name = Type::from_u8(generation, t).map(|t| t.name().to_owned()).unwrap_or_else(|| format!("UNK (0x{t:02X})"));
+        if !types.contains(&name) {
+            types.push(name);
+        }
+    }
+    let _ = writeln!(writer, "  Types:  {}", types.join("/"));
+
+    let stats = &mon.stats;
+    let special = match generation {
+        Generation::One => format!("SPC {}", stats.special_attack),
+        Generation::Two => format!("SATK {} SDEF {}", stats.special_attack, stats.special_defense)
+    };
+    let _ = writeln!(writer, "  Stats:  ATK {} DEF {} SPD {} {special}", stats.attack, stats.defense, stats.speed);
+
+    let mut changed_stages = vec![("ATK", stages.attack), ("DEF", stages.defense), ("SPD", stages.speed)];
+    match generation {
+        Generation::One => changed_stages.push(("SPC", stages.special_attack)),
+        Generation::Two => changed_stages.extend([("SATK", stages.special_attack), ("SDEF", stages.special_defense)])
+    }
+    changed_stages.extend([("ACC", stages.accuracy), ("EVA", stages.evasion)]);
+    changed_stages.retain(|&(_, stage)| stage != 0);
+    if changed_stages.is_empty() {
+        let _ = writeln!(writer, "  Stages: none");
+    }
+    else {
+        let stages: Vec<String> = changed_stages.iter().map(|(name, stage)| format!("{name} {stage:+}")).collect();
+        let _ = writeln!(writer, "  Stages: {}", stages.join(" "));
+    }
+
+    if let Some(item) = mon.item.filter(|&i| i != 0) {
+        let name = simulator.outcome_name(Outcome::Item(item)).map(str::to_owned).unwrap_or_else(|| format!("UNK (0x{item:02X})"));
+        let _ = writeln!(writer, "  Item:   {name}");
+    }
+
+    let _ = writeln!(writer, "  Moves:");
+    for (&m, &pp) in mon.moves.iter().zip(mon.pp.iter()) {
+        if m == 0 {
+            continue;
+        }
+        let name = display_name(simulator, Outcome::Move(m));
+        let _ = writeln!(writer, "    {name:-12} PP {:2}", pp & 0x3F);
+    }
+}
+
+/// Get the name of a species, or its raw index if it's unknown.
+pub fn species_name(species: Option<Species>, index: u8) -> String {
+    match species {
+        Some(s) => s.name().to_owned(),
+        None => format!("UNK (0x{index:02X})")
+    }
+}
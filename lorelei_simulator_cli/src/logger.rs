@@ -0,0 +1,35 @@
+//! Printing diagnostic messages from the simulator to stderr, for `-v`.
+
+use std::time::Instant;
+use log::{LevelFilter, Log, Metadata, Record};
+
+struct Logger {
+    start: Instant
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            let elapsed = self.start.elapsed();
+            eprintln!("[{:4}.{:03}] {}", elapsed.as_secs(), elapsed.subsec_millis(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Start logging: 0 for warnings only, 1 for `-v`, and 2 or more for `-vv`.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        _ => LevelFilter::Debug
+    };
+    if log::set_logger(Box::leak(Box::new(Logger { start: Instant::now() }))).is_ok() {
+        log::set_max_level(level);
+    }
+}
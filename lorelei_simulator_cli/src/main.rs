@@ -1,81 +1,1034 @@
-use std::borrow::Cow;
-use std::fs::read;
+use std::fmt::{Display, Formatter};
+use std::fs::{read, write};
 use std::io::{BufWriter, stdout, Write};
 use std::num::{NonZeroU64, NonZeroUsize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use clap::Parser;
 use console::Term;
-use lorelei_simulator::{move_name, Simulator};
+use config::{Config, ConfigError, GameConfig};
+use lorelei_simulator::input::{InputScript, InputScriptError, InputStrategy};
+use lorelei_simulator::report::display_name;
+use lorelei_simulator::{Game, Outcome, Simulator, SimulatorError, StopCause, TrialRate, PRECISION_CHECK_TRIALS};
+use convergence::{ConvergenceLog, SnapshotInterval};
+use hotkey::{Hotkey, HotkeySpec};
+use profile::{Profile, BUILTIN_PROFILES};
+use report::{Report, ReportFormat, SortOrder};
+use trial_log::TrialLog;
+use watch::FileWatcher;
+use webhook::{report_message, Webhook};
 
-fn main() {
-    #[derive(clap::Parser)]
-    struct Args {
+mod batch;
+mod bizhawk;
+mod compare;
+mod completions;
+mod config;
+mod convergence;
+mod doctor;
+mod dump;
+mod hotkey;
+mod inspect;
+mod logger;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod notify;
+mod playback;
+mod png;
+mod profile;
+mod report;
+mod status;
+mod sweep;
+mod toml;
+mod trial_log;
+mod retroarch;
+mod rpc;
+mod serve;
+mod watch;
+mod webhook;
+
+/// How often to print progress when it can't be updated in place
+const PLAIN_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Width of the live progress bar when there's a number of trials to reach, in characters
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Exit status when the move given with `--stop-on-move` reaches its threshold, which is different from errors
+const MOVE_REACHED_EXIT_CODE: u8 = 3;
+
+/// How long to look for the first read of the RNG with `--warmup-only`
+const WARM_UP_MAX_FRAMES: u32 = 60 * 60;
+
+/// How long to wait for the AI's decision when taking a screenshot of it
+const SCREENSHOT_MAX_FRAMES: u32 = 60 * 60;
+
+#[derive(clap::Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count, global = true, help = "Print what the simulator is doing to help with problems (-vv for more)")]
+    verbose: u8,
+
+    #[arg(long = "config", value_name = "FILE", global = true, help = "Read defaults from this config file instead of ~/.config/lorelei-simulator/config.toml")]
+    config: Option<PathBuf>
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Print the battle in a save state without simulating anything
+    Inspect {
+        #[command(flatten)]
+        input: InputArgs
+    },
+
+    /// Check that a ROM and save state will work and suggest fixes if not
+    Doctor {
+        #[command(flatten)]
+        input: InputArgs
+    },
+
+    /// Run one trial and save each frame as a PNG to check what the simulator is doing
+    Playback {
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[arg(long = "frames", value_name = "DIR", help = "Directory to save the frames in")]
+        frames: PathBuf,
+
+        #[arg(long = "max-seconds", default_value_t = 60, help = "Stop after this many seconds of game time if the AI hasn't decided")]
+        max_seconds: u32
+    },
+
+    /// Simulate two save states of the same battle and show how the AI's choices differ
+    Compare {
         rom: PathBuf,
-        save_state: PathBuf,
+
+        /// Save state to compare against
+        a: PathBuf,
+
+        /// Save state to compare with it
+        b: PathBuf,
+
+        #[arg(short = 't', long = "trials", default_value = "10000", help = "Number of trials to calculate for each save state")]
+        trials: NonZeroU64,
+
+        #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use, split between the save states - by default, use all available CPU threads")]
+        jobs: Option<NonZeroUsize>,
+
+        #[arg(long = "mash", value_name = "BUTTONS", value_parser = parse_input_strategy, help = "Buttons to mash while waiting for the AI (see the main command's --mash)")]
+        mash: Option<InputStrategy>,
+
+        #[command(flatten)]
+        overrides: GameArgs
+    },
+
+    /// Simulate many save states one after another, e.g. every major fight in a route
+    Batch {
+        rom: PathBuf,
+
+        /// Save states, directories of them, or names with * and ? wildcards (e.g. "states/*.s?")
+        #[arg(required = true)]
+        states: Vec<PathBuf>,
+
+        #[arg(short = 't', long = "trials", default_value = "10000", help = "Number of trials to calculate for each save state")]
+        trials: NonZeroU64,
 
         #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use - by default, use all available CPU threads")]
         jobs: Option<NonZeroUsize>,
 
-        #[arg(short = 't', long = "trials", help = "Number of trials to calculate - by default, it will keep going until you press CTRL-C")]
+        #[arg(long = "mash", value_name = "BUTTONS", value_parser = parse_input_strategy, help = "Buttons to mash while waiting for the AI (see the main command's --mash)")]
+        mash: Option<InputStrategy>,
+
+        #[command(flatten)]
+        overrides: GameArgs,
+
+        #[arg(long = "format", value_enum, help = "Format of each save state's results (json prints a line for each) - by default, a table")]
+        format: Option<ReportFormat>,
+
+        #[arg(long = "report-dir", value_name = "DIR", help = "Also save each save state's results in a directory named after it, with an index and a summary")]
+        report_dir: Option<PathBuf>
+    },
+
+    /// Simulate a save state with every combination of changes in a sweep file (see the README)
+    Sweep {
+        rom: PathBuf,
+        save_state: PathBuf,
+
+        /// Sweep file (TOML) describing what to change
+        file: PathBuf,
+
+        #[arg(short = 't', long = "trials", help = "Number of trials to calculate for each combination - by default, what the sweep file says, or 10000")]
         trials: Option<NonZeroU64>,
 
-        #[arg(short = 'q', long = "quiet", help = "Don't output anything until finished")]
-        quiet: bool
+        #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use - by default, use all available CPU threads")]
+        jobs: Option<NonZeroUsize>,
+
+        #[arg(long = "mash", value_name = "BUTTONS", value_parser = parse_input_strategy, help = "Buttons to mash while waiting for the AI (see the main command's --mash)")]
+        mash: Option<InputStrategy>,
+
+        #[command(flatten)]
+        overrides: GameArgs,
+
+        #[arg(long = "format", value_enum, help = "Format of the results (json prints a line for each combination) - by default, a table")]
+        format: Option<ReportFormat>
+    },
+
+    /// Simulate states sent by BizHawk's Lua console (see bizhawk/lorelei.lua)
+    Bizhawk {
+        rom: PathBuf,
+
+        #[arg(short = 'p', long = "port", default_value_t = bizhawk::DEFAULT_PORT, help = "Port to listen on for BizHawk")]
+        port: u16,
+
+        #[arg(short = 't', long = "trials", default_value = "10000", help = "Number of trials to calculate for each state")]
+        trials: NonZeroU64,
+
+        #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use - by default, use all available CPU threads")]
+        jobs: Option<NonZeroUsize>
+    },
+
+    /// Run an HTTP server for web frontends and remote use to upload states to and get results from (see the README)
+    Serve {
+        #[arg(default_value = serve::DEFAULT_ADDRESS, help = "Address to listen on - anyone who can connect can use it, so only listen on other machines' networks if you trust them")]
+        address: String,
+
+        #[arg(long = "rom", help = "ROM to start with, so it doesn't have to be uploaded")]
+        rom: Option<PathBuf>,
+
+        #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use when a run doesn't say - by default, use all available CPU threads")]
+        jobs: Option<NonZeroUsize>
+    },
+
+    /// Take JSON-RPC requests on stdin and answer them on stdout, for editor plugins and launchers (see the README)
+    Rpc {
+        #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use when a run doesn't say - by default, use all available CPU threads")]
+        jobs: Option<NonZeroUsize>
+    },
+
+    /// Print results saved with --format json
+    Show {
+        file: PathBuf,
+
+        #[arg(long = "format", value_enum, help = "Format to print the results in - by default, a table")]
+        format: Option<ReportFormat>,
+
+        #[arg(long = "sort", value_enum, default_value_t, help = "Order to print the results in")]
+        sort: SortOrder
+    },
+
+    /// Work with the built-in data tables
+    Data {
+        #[command(subcommand)]
+        command: DataCommand
+    },
+
+    /// Print a completion script for bash, zsh, or fish (see the README for where to put it)
+    Completions {
+        #[arg(value_enum)]
+        shell: completions::Shell
     }
+}
 
-    let args = Args::parse();
-    let trials = args.trials.map(|t| t.get());
+impl Command {
+    /// Give the game configs listed in the config file to the commands that load ROMs.
+    fn include_game_configs(&mut self, config: &Config) {
+        match self {
+            Command::Inspect { input } | Command::Doctor { input } | Command::Playback { input, .. } => {
+                input.overrides.included = config.game_configs.clone();
+            },
+            Command::Compare { overrides, .. } | Command::Batch { overrides, .. } | Command::Sweep { overrides, .. } => {
+                overrides.included = config.game_configs.clone();
+            },
+            Command::Bizhawk { .. } | Command::Serve { .. } | Command::Rpc { .. } | Command::Show { .. } | Command::Data { .. } | Command::Completions { .. } => ()
+        }
+    }
+}
 
-    let Ok(rom) = read(&args.rom) else {
-        eprintln!("Failed to read ROM {}", args.rom.display());
-        return;
+#[derive(clap::Subcommand)]
+enum DataCommand {
+    /// Print the move, species, and item tables as JSON
+    Dump {
+        #[arg(long = "pretty", help = "Indent the output")]
+        pretty: bool
+    }
+}
+
+/// Where to find the ROM and save state.
+#[derive(clap::Args)]
+struct InputArgs {
+    #[arg(required = true)]
+    rom: Option<PathBuf>,
+
+    #[arg(required_unless_present_any = ["slot", "battery", "warm_state"], help = "Save state file, or a directory to use the newest save state in (or to look for slots in if --slot is given)")]
+    save_state: Option<PathBuf>,
+
+    #[arg(short = 's', long = "slot", value_parser = clap::value_parser!(u8).range(0..=9), help = "Use SameBoy's save state slot (0-9) for the ROM - by default, it looks in the ROM's directory")]
+    slot: Option<u8>,
+
+    #[arg(long = "battery", value_name = "SAV", conflicts_with_all = ["save_state", "slot"], requires = "input_script", help = "Boot the game with a battery save (.sav) instead of loading a save state")]
+    battery: Option<PathBuf>,
+
+    #[arg(long = "input-script", value_name = "FILE", help = "Input script to run before simulating - with --battery, this has to get from the title screen into the battle")]
+    input_script: Option<PathBuf>,
+
+    #[arg(long = "warm-state", value_name = "FILE", conflicts_with_all = ["save_state", "slot", "battery"], help = "Start from a state saved with --export-warm-state instead of a save state")]
+    warm_state: Option<PathBuf>,
+
+    #[arg(long = "mash", value_name = "BUTTONS", value_parser = parse_input_strategy, help = "Buttons to mash while waiting for the AI, e.g. B, A+B:2/4 (2 frames on, 4 off), or none - by default, navigate (mash A, moving the battle menu's cursor to FIGHT first)")]
+    mash: Option<InputStrategy>,
+
+    #[command(flatten)]
+    overrides: GameArgs
+}
+
+/// What game the ROM is and where things are in its memory, for ROM hacks and retitled ROMs.
+#[derive(clap::Args, Clone, Default)]
+struct GameArgs {
+    #[arg(long = "game", value_parser = parse_game, help = "Simulate the ROM as this game instead of going by its title, e.g. for ROM hacks: red, blue, yellow, gold, silver, or crystal")]
+    game: Option<Game>,
+
+    #[arg(long = "addr", value_name = "NAME=ADDRESS", value_parser = parse_address, value_delimiter = ',', help = "Use a different memory address, e.g. decision=0xC6E4 (can be given more than once)")]
+    addresses: Vec<(String, u16)>,
+
+    #[arg(long = "game-config", value_name = "FILE", help = "Read the game and addresses to use from a TOML file (see the README)")]
+    game_config: Option<PathBuf>,
+
+    /// Game configs from the config file, used if the ROM has their title
+    #[arg(skip)]
+    included: Vec<GameConfig>
+}
+
+impl GameArgs {
+    /// Combine the game config (from --game-config, or the config file if one has the ROM's title) with --game and
+    /// --addr.
+    fn resolve(&self, rom: &[u8]) -> Result<GameConfig, LoadError> {
+        let mut config = match &self.game_config {
+            Some(path) => GameConfig::load(path).map_err(|error| LoadError::GameConfig { path: path.clone(), error })?,
+            None => {
+                let title = lorelei_simulator::rom_title(rom);
+                self.included.iter().find(|config| config.title.as_ref() == Some(&title)).cloned().unwrap_or_default()
+            }
+        };
+        config.game = self.game.or(config.game);
+        config.addresses.extend(self.addresses.iter().cloned());
+        Ok(config)
+    }
+}
+
+impl RunArgs {
+    /// Fill in anything not given on the command line from `profile`.
+    fn apply_profile(&mut self, profile: Profile) {
+        self.trials = self.trials.or(profile.trials);
+        self.precision = self.precision.or(profile.precision);
+        self.timeout = self.timeout.or(profile.timeout.and_then(|t| NonZeroU64::new(t.as_secs())));
+        self.jobs = self.jobs.or(profile.jobs);
+    }
+
+    /// Fill in anything not given on the command line from the config file.
+    fn apply_config(&mut self, config: &Config) {
+        self.jobs = self.jobs.or(config.jobs);
+        self.format = self.format.or(config.format);
+        self.status_file = self.status_file.take().or_else(|| config.status_file.clone());
+    }
+}
+
+fn parse_input_strategy(strategy: &str) -> Result<InputStrategy, &'static str> {
+    InputStrategy::parse(strategy).ok_or("expected none, navigate, or buttons joined with + and an optional ON/OFF frame cadence")
+}
+
+fn parse_precision(precision: &str) -> Result<f64, &'static str> {
+    match precision.trim_end_matches('%').parse::<f64>() {
+        Ok(p) if p > 0.0 && p <= 100.0 => Ok(p),
+        _ => Err("expected a percentage above 0, e.g. 0.5")
+    }
+}
+
+/// Parse a duration like 90, 90s, 10m, or 1h30m (seconds if there's no unit).
+fn parse_duration(duration: &str) -> Result<Duration, &'static str> {
+    const ERROR: &str = "expected a duration above 0, e.g. 90s, 10m, or 1h30m";
+    let seconds = match duration.parse::<u64>() {
+        Ok(seconds) => seconds,
+        Err(_) => {
+            let mut total = 0u64;
+            let mut number = String::new();
+            for c in duration.chars() {
+                if c.is_ascii_digit() {
+                    number.push(c);
+                    continue;
+                }
+                let unit = match c {
+                    'h' => 60 * 60,
+                    'm' => 60,
+                    's' => 1,
+                    _ => return Err(ERROR)
+                };
+                let value: u64 = number.parse().map_err(|_| ERROR)?;
+                total = value.checked_mul(unit).and_then(|v| total.checked_add(v)).ok_or(ERROR)?;
+                number.clear();
+            }
+            if !number.is_empty() {
+                return Err(ERROR);
+            }
+            total
+        }
     };
+    if seconds == 0 {
+        return Err(ERROR);
+    }
+    Ok(Duration::from_secs(seconds))
+}
 
-    let Ok(save_state) = read(&args.save_state) else {
-        eprintln!("Failed to read save state {}", args.save_state.display());
-        return;
+fn parse_move_threshold(threshold: &str) -> Result<MoveThreshold, String> {
+    let Some((name, fraction)) = MoveThreshold::parse(threshold) else {
+        return Err("expected a move and how likely it should be, e.g. BLIZZARD>=0.15 or BLIZZARD>=15%".to_owned());
+    };
+    let move_index = lorelei_simulator::move_id_from_name(name).ok_or_else(|| format!("unknown move {name}"))?;
+    Ok(MoveThreshold { move_index, fraction })
+}
+
+fn parse_game(game: &str) -> Result<Game, &'static str> {
+    Game::from_short_name(game).ok_or("expected red, blue, yellow, gold, silver, or crystal")
+}
+
+fn parse_address(address: &str) -> Result<(String, u16), String> {
+    let Some((name, value)) = address.split_once('=') else {
+        return Err("expected a name and an address, e.g. decision=0xC6E4".to_owned());
+    };
+    let name = name.trim();
+    if !config::is_address_name(name) {
+        return Err(format!("unknown address {name} (the addresses are decision, {})", lorelei_simulator::Addresses::NAMES.join(", ")));
+    }
+    let value = value.trim();
+    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u16::from_str_radix(hex, 16),
+        None => value.parse()
+    };
+    parsed.map(|value| (name.to_owned(), value)).map_err(|_| format!("{value} isn't an address; use hexadecimal like 0xC6E4"))
+}
+
+fn parse_hotkey(hotkey: &str) -> Result<HotkeySpec, String> {
+    HotkeySpec::parse(hotkey)
+}
+
+/// A move and how likely it has to be for `--stop-on-move`.
+#[derive(Clone)]
+struct MoveThreshold {
+    move_index: u8,
+    fraction: f64
+}
+
+impl MoveThreshold {
+    /// Split a threshold into the move's name and the fraction.
+    fn parse(threshold: &str) -> Option<(&str, f64)> {
+        let (name, value) = threshold.split_once(">=")?;
+        let name = name.trim();
+        let value = value.trim();
+        let fraction = match value.strip_suffix('%') {
+            Some(percent) => percent.trim_end().parse::<f64>().ok()? / 100.0,
+            None => value.parse::<f64>().ok()?
+        };
+        if name.is_empty() || !(fraction > 0.0 && fraction <= 1.0) {
+            return None;
+        }
+        Some((name, fraction))
+    }
+
+    /// Whether the move's 95% confidence interval is entirely at or above the threshold.
+    fn is_reached(&self, report: &Report) -> bool {
+        report
+            .rows
+            .iter()
+            .find(|row| row.outcome == Outcome::Move(self.move_index))
+            .is_some_and(|row| row.interval(report.total).0 >= self.fraction)
+    }
+}
+
+impl Display for MoveThreshold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // rounded so 0.15 isn't shown as 15.000000000000002
+        let percent = (self.fraction * 100_000.0).round() / 1000.0;
+        write!(f, "{} is at least {percent}% likely", lorelei_simulator::move_name(self.move_index).unwrap_or("--"))
+    }
+}
+
+/// Where the save state comes from.
+enum StateSource {
+    SaveState(PathBuf),
+    Battery(PathBuf),
+    WarmState(PathBuf)
+}
+
+impl StateSource {
+    fn path(&self) -> &Path {
+        match self {
+            StateSource::SaveState(path) | StateSource::Battery(path) | StateSource::WarmState(path) => path
+        }
+    }
+}
+
+impl InputArgs {
+    /// Get the paths to the ROM and save state, printing an error if there is no save state in the slot.
+    fn resolve(&self) -> Option<(PathBuf, StateSource)> {
+        // clap enforces these when there is no subcommand
+        let rom = self.rom.clone().unwrap();
+
+        if let Some(battery) = &self.battery {
+            return Some((rom, StateSource::Battery(battery.clone())));
+        }
+
+        if let Some(warm_state) = &self.warm_state {
+            return Some((rom, StateSource::WarmState(warm_state.clone())));
+        }
+
+        let Some(slot) = self.slot else {
+            let save_state = self.save_state.clone().unwrap();
+            if !save_state.is_dir() {
+                return Some((rom, StateSource::SaveState(save_state)));
+            }
+            let Some((newest, _)) = watch::newest_save_state(&save_state) else {
+                eprintln!("No save states in {}", save_state.display());
+                return None;
+            };
+            eprintln!("Using {}", newest.display());
+            return Some((rom, StateSource::SaveState(newest)));
+        };
+
+        // SameBoy names slots after the ROM, e.g. "Pokemon Red.s1"
+        let directory = match self.save_state.as_deref() {
+            Some(d) => d.to_owned(),
+            None => rom.parent().map(Path::to_owned).unwrap_or_default()
+        };
+        let mut file_name = rom.file_stem().unwrap_or_default().to_owned();
+        file_name.push(format!(".s{slot}"));
+        let save_state = directory.join(file_name);
+
+        if !save_state.is_file() {
+            eprintln!("No save state in slot {slot} (looked for {})", save_state.display());
+            return None;
+        }
+        Some((rom, StateSource::SaveState(save_state)))
+    }
+
+    /// Get the file (or directory) to watch for new save states, printing an error if there isn't one.
+    fn watch_path(&self) -> Option<PathBuf> {
+        // keep following the newest save state if given a directory
+        match &self.save_state {
+            Some(directory) if directory.is_dir() && self.slot.is_none() => Some(directory.clone()),
+            _ => self.resolve().map(|(_, source)| source.path().to_owned())
+        }
+    }
+
+    /// Load a simulator, printing an error if it fails.
+    fn load(&self, trials: Option<u64>) -> Option<Simulator> {
+        self.load_with_source(trials).map(|(simulator, _)| simulator)
+    }
+
+    /// Load a simulator like [`InputArgs::load`], also returning where the save state came from.
+    fn load_with_source(&self, trials: Option<u64>) -> Option<(Simulator, StateSource)> {
+        let (rom, source) = self.resolve()?;
+        match self.open(&rom, &source, trials) {
+            Ok(n) => Some((n, source)),
+            Err(e) => {
+                eprintln!("{e}");
+                None
+            }
+        }
+    }
+
+    /// Load a simulator from a ROM and a save state or battery save.
+    fn open(&self, rom_path: &Path, source: &StateSource, trials: Option<u64>) -> Result<Simulator, LoadError> {
+        let read_file = |what: &'static str, path: &Path| read(path).map_err(|_| LoadError::Read { what, path: path.to_owned() });
+        let rom = read_file("ROM", rom_path)?;
+        let game = self.overrides.resolve(&rom)?;
+        let mut script = match &self.input_script {
+            Some(path) => {
+                let script = read_file("input script", path)?;
+                Some(InputScript::parse(&String::from_utf8_lossy(&script)).map_err(LoadError::InputScript)?)
+            },
+            None => None
+        };
+
+        let result = match source {
+            StateSource::SaveState(path) => Simulator::new_from_vec(rom, read_file("save state", path)?, trials, game.game),
+            // clap makes sure there's a script
+            StateSource::Battery(path) => {
+                let battery = read_file("battery save", path)?;
+                Simulator::new_from_battery_save(rom, &battery, &script.take().unwrap(), trials, game.game)
+            },
+            StateSource::WarmState(path) => Simulator::new_from_warm_state(rom, read_file("warm state", path)?, trials, game.game)
+        };
+        let mut simulator = result.map_err(LoadError::Simulator)?;
+        if !game.addresses.is_empty() {
+            let mut addresses = simulator.addresses();
+            game.apply(&mut addresses);
+            simulator.set_addresses(addresses);
+        }
+        if let Some(script) = script {
+            simulator.run_input_script(&script);
+        }
+        if let Some(strategy) = &self.mash {
+            simulator.set_input_strategy(strategy.clone());
+        }
+        Ok(simulator)
+    }
+}
+
+/// Why [`InputArgs::open`] failed.
+enum LoadError {
+    Read { what: &'static str, path: PathBuf },
+    InputScript(InputScriptError),
+    GameConfig { path: PathBuf, error: ConfigError },
+    Simulator(SimulatorError)
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Read { what, path } => write!(f, "Failed to read {what} {}", path.display()),
+            LoadError::InputScript(e) => write!(f, "Failed to parse input script: {e}"),
+            LoadError::GameConfig { path, error } => write!(f, "Failed to read game config {}: {error}", path.display()),
+            LoadError::Simulator(e) => write!(f, "Failed to load simulator: {e}")
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[arg(short = 'j', long = "jobs", help = "Number of CPU threads to use - by default, use all available CPU threads")]
+    jobs: Option<NonZeroUsize>,
+
+    #[arg(short = 't', long = "trials", help = "Number of trials to calculate - by default, it will keep going until you press CTRL-C")]
+    trials: Option<NonZeroU64>,
+
+    #[arg(long = "profile", value_name = "NAME", help = "Use a preset number of trials, precision, and timeout: quick, standard, or thorough")]
+    profile: Option<String>,
+
+    #[arg(long = "precision", value_name = "PERCENT", value_parser = parse_precision, help = "Stop once every move's 95% confidence interval is within this many percentage points, e.g. 0.5")]
+    precision: Option<f64>,
+
+    #[arg(long = "timeout", value_name = "SECONDS", help = "Give up if the AI hasn't decided after this many seconds")]
+    timeout: Option<NonZeroU64>,
+
+    #[arg(long = "max-duration", value_name = "DURATION", value_parser = parse_duration, help = "Stop after this long no matter how many trials are done, e.g. 10m or 1h30m")]
+    max_duration: Option<Duration>,
+
+    #[arg(long = "stop-on-move", value_name = "THRESHOLD", value_parser = parse_move_threshold, help = "Stop (ringing the bell and exiting with status 3) once a move is confidently at least this likely, e.g. \"BLIZZARD>=0.15\" or \"BLIZZARD>=15%\"")]
+    stop_on_move: Option<MoveThreshold>,
+
+    #[arg(short = 'q', long = "quiet", help = "Don't output anything until finished")]
+    quiet: bool,
+
+    #[arg(long = "porcelain", conflicts_with = "format", help = "Only print the results, as one tab-separated line per move or item (kind, index, name, count, and percentage) that won't change between versions, for scripts")]
+    porcelain: bool,
+
+    #[arg(long = "plain", visible_alias = "no-color", help = "Print progress as a line every few seconds instead of updating it in place, and draw bars with ASCII (the default if the output isn't a terminal)")]
+    plain: bool,
+
+    #[arg(long = "format", value_enum, help = "Format of the results printed when finished - by default, a table")]
+    format: Option<ReportFormat>,
+
+    #[arg(long = "sort", value_enum, default_value_t, help = "Order of the results printed when finished")]
+    sort: SortOrder,
+
+    #[arg(short = 'o', long = "output", value_name = "FILE", help = "Also write the results to a file in the same format")]
+    output: Option<PathBuf>,
+
+    #[arg(long = "append", requires = "output", help = "Add the results to the results file given with -o if it exists instead of overwriting it (it's always written as JSON)")]
+    append: bool,
+
+    #[arg(long = "resume", value_name = "FILE", conflicts_with = "append", help = "Continue from the results in a JSON results file (and the warm state saved with it) and save them back when finished; -t is then the total number of trials")]
+    resume: Option<PathBuf>,
+
+    #[arg(long = "trial-log", value_name = "FILE", help = "Write a line of JSON to a file for every trial as it finishes")]
+    trial_log: Option<PathBuf>,
+
+    #[arg(long = "tag", requires = "trial_log", help = "Label the trials in the trial log with this - by default, the save state's file name")]
+    tag: Option<String>,
+
+    #[arg(long = "convergence", value_name = "FILE", help = "Append a snapshot of the percentages to a CSV file now and then, for plotting how they converge")]
+    convergence: Option<PathBuf>,
+
+    #[arg(long = "convergence-every", value_name = "N", default_value = "10s", requires = "convergence", help = "How often to take a snapshot for --convergence: a number of trials, or seconds ending in s")]
+    convergence_every: SnapshotInterval,
+
+    #[arg(long = "status-file", value_name = "FILE", help = "Keep a file up to date with the current results, e.g. for stream overlays (JSON if it ends in .json, otherwise text)")]
+    status_file: Option<PathBuf>,
+
+    #[arg(long = "detect-items", help = "Also detect the AI using an item instead of its selected move (Gen 1 only; trials take longer)")]
+    detect_items: bool,
+
+    #[arg(long = "notify", help = "Show a desktop notification when finished or stopped by --precision, --stop-on-move, --max-duration, or --timeout")]
+    notify: bool,
+
+    #[arg(long = "webhook", value_name = "URL", help = "Post the results to this Discord webhook when finished")]
+    webhook: Option<String>,
+
+    #[arg(long = "webhook-every", value_name = "DURATION", requires = "webhook", value_parser = parse_duration, help = "Also post the results so far to the webhook this often, e.g. 30m")]
+    webhook_every: Option<Duration>,
+
+    #[arg(long = "watch", help = "Start over whenever the save state file changes, e.g. when you make a new one in your emulator")]
+    watch: bool,
+
+    #[arg(long = "hotkey", value_name = "KEY", value_parser = parse_hotkey, help = "Global hotkey (e.g. Ctrl+Shift+F9) that starts over with the newest save state, staying open between runs")]
+    hotkey: Option<HotkeySpec>,
+
+    #[arg(long = "retroarch", value_name = "ADDRESS", num_args = 0..=1, default_missing_value = retroarch::DEFAULT_ADDRESS, conflicts_with = "watch", help = "Have RetroArch save a state over its network command interface before each run (the save state path should be its states directory)")]
+    retroarch: Option<String>,
+
+    #[arg(long = "screenshot", value_name = "FILE", help = "Before simulating, run one trial and save what's on screen when the AI decides as a PNG")]
+    screenshot: Option<PathBuf>,
+
+    #[arg(long = "export-warm-state", value_name = "FILE", help = "When finished, save the state trials start from so --warm-state can skip the warm-up next time")]
+    export_warm_state: Option<PathBuf>,
+
+    #[arg(long = "warmup-only", requires = "export_warm_state", help = "Only do the warm-up and save it with --export-warm-state, without simulating anything")]
+    warmup_only: bool
+}
+
+fn main() -> ExitCode {
+    let mut cli = Cli::parse();
+    logger::init(cli.verbose);
+    let Some(config) = load_config(cli.config.as_deref()) else {
+        return ExitCode::FAILURE;
     };
+    if let Some(command) = cli.command.as_mut() {
+        command.include_game_configs(&config);
+    }
+    cli.run.input.overrides.included = config.game_configs.clone();
+
+    match cli.command {
+        Some(Command::Inspect { input }) => inspect::inspect(&input),
+        Some(Command::Doctor { input }) => doctor::doctor(&input),
+        Some(Command::Playback { input, frames, max_seconds }) => playback::playback(&input, &frames, max_seconds),
+        Some(Command::Compare { rom, a, b, trials, jobs, mash, overrides }) => {
+            compare::compare(&rom, [&a, &b], trials, jobs.or(config.jobs), mash, overrides)
+        },
+        Some(Command::Batch { rom, states, trials, jobs, mash, overrides, format, report_dir }) => {
+            let (jobs, format) = (jobs.or(config.jobs), format.or(config.format).unwrap_or_default());
+            batch::batch(batch::BatchArgs { rom, states, trials, jobs, mash, overrides, format, report_dir })
+        },
+        Some(Command::Sweep { rom, save_state, file, trials, jobs, mash, overrides, format }) => {
+            let (jobs, format) = (jobs.or(config.jobs), format.or(config.format).unwrap_or_default());
+            sweep::sweep(sweep::SweepArgs { rom, save_state, file, trials, jobs, mash, overrides, format })
+        },
+        Some(Command::Bizhawk { rom, port, trials, jobs }) => bizhawk::serve(&rom, port, trials, jobs.or(config.jobs)),
+        Some(Command::Serve { address, rom, jobs }) => serve::serve(&address, rom.as_deref(), jobs.or(config.jobs)),
+        Some(Command::Rpc { jobs }) => rpc::rpc(jobs.or(config.jobs)),
+        Some(Command::Show { file, format, sort }) => show(&file, format.or(config.format).unwrap_or_default(), sort),
+        Some(Command::Data { command: DataCommand::Dump { pretty } }) => dump_data(pretty),
+        Some(Command::Completions { shell }) => {
+            let _ = completions::write(&mut stdout().lock(), shell, <Cli as clap::CommandFactory>::command());
+        },
+        None => return run(cli.run, &config)
+    }
+    ExitCode::SUCCESS
+}
 
-    let mut simulator = match Simulator::new_from_vec(rom, save_state, trials) {
-        Ok(n) => n,
+/// Load the config file given with `--config`, or the default one if it exists, printing an error if it fails.
+fn load_config(path: Option<&Path>) -> Option<Config> {
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None => match Config::default_path() {
+            Some(path) if path.is_file() => path,
+            _ => return Some(Config::default())
+        }
+    };
+    match Config::load(&path) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            eprintln!("Failed to read config file {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+fn show(path: &Path, format: ReportFormat, sort: SortOrder) {
+    let mut report = match Report::load(path) {
+        Ok(report) => report,
         Err(e) => {
-            eprintln!("Failed to load simulator: {e}");
+            eprintln!("Failed to read {}: {e}", path.display());
             return;
         }
     };
+    report.sort(sort);
 
-    let thread_count = args
-        .jobs
-        .unwrap_or_else(|| std::thread::available_parallelism().unwrap());
+    let mut writer = BufWriter::new(stdout().lock());
+    if format == ReportFormat::Table {
+        let _ = writeln!(writer, "{} ({:016x}), {} trial{}", report.game, report.state_hash, report.total, if report.total == 1 { "" } else { "s" });
+        let _ = writeln!(writer);
+    }
+    let _ = report.write(&mut writer, format);
+}
 
-    simulator.start(thread_count);
+fn dump_data(pretty: bool) {
+    let tables = lorelei_simulator::data_tables_json();
+    let mut writer = BufWriter::new(stdout().lock());
+    if pretty {
+        let _ = writeln!(writer, "{tables:#}");
+    }
+    else {
+        let _ = writeln!(writer, "{tables}");
+    }
+}
+
+fn run(mut args: RunArgs, config: &Config) -> ExitCode {
+    if let Some(name) = &args.profile {
+        let Some(profile) = config.profile(name) else {
+            let mut names: Vec<&str> = BUILTIN_PROFILES.to_vec();
+            names.extend(config.profiles.iter().map(|(name, _)| name.as_str()).filter(|name| !BUILTIN_PROFILES.contains(name)));
+            eprintln!("Unknown profile {name} (the profiles are {})", names.join(", "));
+            return ExitCode::FAILURE;
+        };
+        args.apply_profile(profile);
+    }
+    args.apply_config(config);
 
     let bail = {
         let bail = Arc::new(AtomicBool::new(false));
         let bail_copy = bail.clone();
+
+        // this also handles SIGTERM and SIGHUP, so results are still saved when the system stops the simulator
         let _ = ctrlc::set_handler(move || { bail_copy.swap(true, Ordering::Relaxed); } );
         bail
     };
 
-    if !args.quiet {
+    let mut restart = Restart { watcher: None, hotkey: None };
+    if args.watch {
+        let Some(path) = args.input.watch_path() else {
+            return ExitCode::FAILURE;
+        };
+        restart.watcher = Some(FileWatcher::new(&path));
+    }
+    if let Some(spec) = &args.hotkey {
+        match Hotkey::register(spec) {
+            Ok(hotkey) => restart.hotkey = Some(hotkey),
+            Err(e) => {
+                eprintln!("Can't register {spec} as a hotkey: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let mut move_reached = false;
+    loop {
+        let reason = match run_once(&args, &bail, &mut restart, &mut move_reached) {
+            Some(reason) => reason,
+            None if !restart.is_resident() || bail.load(Ordering::Relaxed) => {
+                return if move_reached { ExitCode::from(MOVE_REACHED_EXIT_CODE) } else { ExitCode::SUCCESS };
+            },
+            None => {
+                println!("{}... press CTRL-C to stop!", restart.waiting_message());
+                loop {
+                    std::thread::sleep(Duration::from_millis(250));
+                    if bail.load(Ordering::Relaxed) {
+                        return ExitCode::SUCCESS;
+                    }
+                    if let Some(reason) = restart.check() {
+                        break reason;
+                    }
+                }
+            }
+        };
+        println!();
+        println!("{reason}; starting over");
+    }
+}
+
+/// Describe the rate, with how long is left if `remaining` trials are wanted.
+fn speed_text(rate: f64, remaining: Option<u64>) -> String {
+    match remaining {
+        Some(remaining) if rate > 0.0 => {
+            let seconds = (remaining as f64 / rate).ceil() as u64;
+            format!("{rate:.0}/s, ETA {}:{:02}", seconds / 60, seconds % 60)
+        },
+        _ => format!("{rate:.0}/s")
+    }
+}
+
+/// What makes the simulator start over instead of exiting.
+struct Restart {
+    watcher: Option<FileWatcher>,
+    hotkey: Option<Hotkey>
+}
+
+impl Restart {
+    const fn is_resident(&self) -> bool {
+        self.watcher.is_some() || self.hotkey.is_some()
+    }
+
+    /// Check if it's time to start over, returning why.
+    fn check(&mut self) -> Option<&'static str> {
+        if self.hotkey.as_mut().is_some_and(Hotkey::pressed) {
+            Some("Hotkey pressed")
+        }
+        else if self.watcher.as_mut().is_some_and(FileWatcher::changed) {
+            Some("Save state changed")
+        }
+        else {
+            None
+        }
+    }
+
+    fn waiting_message(&self) -> String {
+        match (&self.watcher, &self.hotkey) {
+            (Some(_), Some(hotkey)) => format!("Waiting for the save state to change or {} to be pressed", hotkey.spec()),
+            (None, Some(hotkey)) => format!("Waiting for {} to be pressed", hotkey.spec()),
+            _ => "Waiting for the save state to change".to_owned()
+        }
+    }
+}
+
+/// Simulate until finished or cancelled. Returns why if it stopped early to start over, and sets `move_reached` if it
+/// stopped because of `--stop-on-move`.
+fn run_once(args: &RunArgs, bail: &AtomicBool, restart: &mut Restart, move_reached: &mut bool) -> Option<&'static str> {
+    let trials = args.trials.map(|t| t.get());
+
+    if let Some(address) = &args.retroarch {
+        let path = args.input.watch_path()?;
+        println!("Asking RetroArch for a save state...");
+        if let Err(e) = retroarch::save_state(address, &path) {
+            eprintln!("{e}");
+            return None;
+        }
+    }
+
+    let (mut simulator, source) = args.input.load_with_source(trials)?;
+
+    if args.detect_items && !simulator.set_detect_items(true) {
+        eprintln!("Item detection is not supported for this game");
+        return None;
+    }
+
+    if let Some(path) = &args.resume {
+        if !resume(&mut simulator, path) {
+            return None;
+        }
+    }
+
+    if let Some(path) = &args.screenshot {
+        save_decision_screenshot(&simulator, path);
+    }
+
+    if args.warmup_only {
+        // clap makes sure there's somewhere to save it
+        let path = args.export_warm_state.as_deref().unwrap();
+        match simulator.warm_up(WARM_UP_MAX_FRAMES) {
+            Some(frames) => {
+                if export_warm_state(&simulator, path) && !args.porcelain {
+                    println!("Saved the warm state to {} (the game first read the RNG after {frames} frames)", path.display());
+                }
+            },
+            None => eprintln!("The game didn't read the RNG within {} seconds of game time; run doctor to find out why", WARM_UP_MAX_FRAMES / 60)
+        }
+        return None;
+    }
+
+    let mut trial_log = None;
+    if let Some(path) = &args.trial_log {
+        let tag = args.tag.clone().unwrap_or_else(|| source.path().file_name().unwrap_or_default().to_string_lossy().into_owned());
+        match TrialLog::create(path, tag) {
+            Ok((log, listener)) => {
+                simulator.set_trial_listener(Some(listener));
+                trial_log = Some(log);
+            },
+            Err(e) => {
+                eprintln!("Failed to create {}: {e}", path.display());
+                return None;
+            }
+        }
+    }
+
+    let mut convergence = None;
+    if let Some(path) = &args.convergence {
+        match ConvergenceLog::open(path, args.convergence_every) {
+            Ok(log) => convergence = Some(log),
+            Err(e) => {
+                eprintln!("Failed to open {}: {e}", path.display());
+                return None;
+            }
+        }
+    }
+
+    let mut status_file = args.status_file.as_deref();
+
+    let thread_count = args
+        .jobs
+        .unwrap_or_else(|| std::thread::available_parallelism().unwrap());
+
+    simulator.set_precision(args.precision.map(|precision| precision / 100.0));
+    simulator.set_timeout(args.timeout.map(|timeout| Duration::from_secs(timeout.get())));
+    simulator.set_max_duration(args.max_duration);
+    simulator.start(thread_count);
+    dump::install();
+
+    let quiet = args.quiet || args.porcelain;
+    if !quiet {
+        if let Some(opponent) = simulator.opponent() {
+            println!("VS {opponent}");
+        }
         println!("Simulating... press CTRL-C to stop!");
     }
 
     let mut output = Term::stdout();
     let start = Instant::now();
 
+    // Updating the progress in place garbles logs, so only do it in a terminal.
+    let live = !quiet && !args.plain && output.is_term();
+
+    // Block characters for the bars end up garbled in logs and redirected output.
+    let ascii = args.plain || !output.is_term();
+    let mut next_plain_progress = start + PLAIN_PROGRESS_INTERVAL;
+    let mut rate = TrialRate::default();
+
+    // Why a rule like --precision stopped the simulator, for --notify
+    let mut stop_reason = None;
+
+    let mut webhook = args.webhook.clone().map(Webhook::new);
+    let mut next_webhook_post = args.webhook_every.map(|every| start + every);
+
     loop {
         std::thread::sleep(Duration::from_millis(250));
 
+        if let Some(reason) = restart.check() {
+            simulator.stop();
+            return Some(reason);
+        }
+
         let bailing = bail.load(Ordering::Relaxed);
         if bailing {
             simulator.stop();
         }
 
-        if !args.quiet {
+        if let Some(log) = trial_log.as_mut() {
+            if let Err(e) = log.write_pending(&simulator) {
+                eprintln!("Failed to write to the trial log: {e}");
+                trial_log = None;
+            }
+        }
+        if let Some(log) = convergence.as_mut() {
+            if let Err(e) = log.update(&simulator, start.elapsed()) {
+                eprintln!("Failed to write a convergence snapshot: {e}");
+                convergence = None;
+            }
+        }
+        if let Some(path) = status_file {
+            if let Err(e) = status::write(path, &Report::new(&simulator), start.elapsed(), simulator.is_running()) {
+                eprintln!("Failed to write {}: {e}", path.display());
+                status_file = None;
+            }
+        }
+
+        if live {
             output.clear_line().unwrap();
         }
 
@@ -91,17 +1044,117 @@ fn main() {
             sample_size += *i.1
         };
 
+        if let (Some(webhook), Some(every), Some(next)) = (&webhook, args.webhook_every, next_webhook_post) {
+            if Instant::now() >= next && sample_size > 0 && simulator.is_running() {
+                next_webhook_post = Some(next + every);
+                webhook.post(report_message(&format!("{sample_size} trials so far in {min}:{sec:02}"), &Report::new(&simulator)));
+            }
+        }
+
+        if dump::requested() {
+            let mut report = Report::new(&simulator);
+            report.sort(args.sort);
+            report.ascii = ascii;
+            let mut writer = BufWriter::new(stdout().lock());
+            let _ = writeln!(writer, "{sample_size} trial{s} so far in {min}:{sec:02}", s=if sample_size == 1 { "" } else { "s" });
+            let _ = writeln!(writer);
+            let _ = report.write(&mut writer, args.format.unwrap_or_default());
+            let _ = writeln!(writer);
+        }
+
+        let speed = rate.update(sample_size).map(|rate| speed_text(rate, args.trials.map(|t| t.get().saturating_sub(sample_size))));
+
+        if let Some(threshold) = &args.stop_on_move {
+            if sample_size >= PRECISION_CHECK_TRIALS && simulator.is_running() && threshold.is_reached(&Report::new(&simulator)) {
+                simulator.stop();
+                *move_reached = true;
+                let reason = format!("{threshold} (95% confidence)");
+                if !args.porcelain {
+                    println!("\x07{reason}");
+                }
+                stop_reason = Some(reason);
+            }
+        }
+
         if !simulator.is_running() {
+            let reason = match simulator.stop_cause() {
+                Some(StopCause::Timeout) => {
+                    let timeout = args.timeout.unwrap();
+                    eprintln!("The AI didn't decide within {timeout} seconds, so giving up; run doctor to find out why");
+                    if args.notify {
+                        notify::send("Gave up", &format!("The AI didn't decide within {timeout} seconds"));
+                    }
+                    if let Some(webhook) = webhook.take() {
+                        webhook.finish(format!("Gave up: the AI didn't decide within {timeout} seconds"));
+                    }
+                    return None;
+                },
+                Some(StopCause::MaxDuration) => {
+                    let max_seconds = args.max_duration.unwrap().as_secs();
+                    Some(format!("Stopping after {}:{:02} as asked", max_seconds / 60, max_seconds % 60))
+                },
+                Some(StopCause::Precision) => Some(format!("Every move's percentage is within ±{}% (95% confidence)", args.precision.unwrap())),
+                None => None
+            };
+            if let Some(reason) = reason {
+                if !args.porcelain {
+                    println!("{reason}");
+                }
+                stop_reason = Some(reason);
+            }
+
             if bailing && sample_size == 0 {
-                output.clear_line().unwrap();
-                println!("Cancelled; no trials recorded in {min}:{sec:02}");
-                return;
+                if !args.porcelain {
+                    println!("Cancelled; no trials recorded in {min}:{sec:02}");
+                }
+                return None;
+            }
+            let finished = format!("Finished {sample_size} trial{s} in {min}:{sec:02}", s=if sample_size == 1 { "" } else { "s" });
+            if !args.porcelain {
+                println!("{finished}");
+            }
+            // no need to tell someone who just pressed CTRL-C
+            if args.notify && !bailing {
+                let summary = Report::new(&simulator).summary();
+                let body: Vec<&str> = [stop_reason.as_deref(), summary.as_deref()].into_iter().flatten().collect();
+                notify::send(&finished, &body.join("\n"));
+            }
+            if let Some(webhook) = webhook.take() {
+                let mut report = Report::new(&simulator);
+                report.sort(args.sort);
+                let heading = match &stop_reason {
+                    Some(reason) => format!("{finished}\n{reason}"),
+                    None => finished
+                };
+                webhook.finish(report_message(&heading, &report));
             }
-            println!("Finished {sample_size} trial{s} in {min}:{sec:02}", s=if sample_size == 1 { "" } else { "s" });
             break;
         }
 
-        if args.quiet {
+        if quiet {
+            continue;
+        }
+
+        if !live {
+            if Instant::now() >= next_plain_progress {
+                next_plain_progress += PLAIN_PROGRESS_INTERVAL;
+                if sample_size == 0 {
+                    println!("No response in {seconds} seconds");
+                }
+                else {
+                    let mut report = Report::new(&simulator);
+                    report.sort(args.sort);
+                    let results: Vec<String> = report.rows
+                        .iter()
+                        .map(|row| format!("{} {:.2}%", row.name, 100.0 * row.fraction(report.total)))
+                        .collect();
+                    print!("{min:02}:{sec:02} {sample_size} trials | {}", results.join(" | "));
+                    match &speed {
+                        Some(speed) => println!(" | {speed}"),
+                        None => println!()
+                    }
+                }
+            }
             continue;
         }
 
@@ -121,15 +1174,38 @@ fn main() {
             continue;
         }
 
-        let mut items: Vec<(u8, u64)> = hashmap.iter().map(|(&a, &b)| (a, b)).collect();
-        items.sort_by(|a, b| a.0.cmp(&b.0));
+        if let Some(target) = args.trials {
+            let fraction = sample_size as f64 / target.get() as f64;
+            let mut line = format!(
+                "[{:<width$}] {sample_size}/{target} {:3.0}%",
+                report::bar(fraction, PROGRESS_BAR_WIDTH, false),
+                100.0 * fraction,
+                width = PROGRESS_BAR_WIDTH
+            );
+            if let Some(speed) = &speed {
+                line += &format!(" | {speed}");
+            }
+
+            // Fit in as many of the moves as there's room for.
+            let mut report = Report::new(&simulator);
+            report.sort(args.sort);
+            let results: Vec<String> = report.rows
+                .iter()
+                .map(|row| format!("{} {:.1}%", row.name, 100.0 * row.fraction(report.total)))
+                .collect();
+            line += " | ";
+            line += &results.join(" | ");
+            let columns = (output.size().1 as usize).saturating_sub(1);
+            let _ = write!(&mut output, "{}", console::truncate_str(&line, columns, "..."));
+            continue;
+        }
+
+        let mut items: Vec<(Outcome, u64)> = hashmap.iter().map(|(&a, &b)| (a, b)).collect();
+        items.sort_by_key(|a| a.0);
 
-        let items_str = items.iter().map(|(index, count)| {
+        let items_str = items.iter().map(|(outcome, count)| {
             let percent = 100.0 * *count as f64 / sample_size as f64;
-            let Some(move_name) = move_name(*index) else {
-                return (Cow::Owned(format!("UNK (0x{index:02X})")), count, percent);
-            };
-            (Cow::Borrowed(move_name), count, percent)
+            (display_name(&simulator, *outcome), count, percent)
         });
 
         let mut items_str = items_str.peekable();
@@ -177,35 +1253,165 @@ fn main() {
                 let _ = write!(&mut output, " | {name}: {percent:6.2}%");
             }
         }
+        else if columns < 140 {
+            let _ = write!(&mut output, "{sample_size:<7}");
+            for (name, _, percent) in items_str {
+                let _ = write!(&mut output, " | {name}: {percent:6.2}%");
+            }
+            let _ = write!(&mut output, " | {min:02}:{sec:02}");
+        }
         else {
             let _ = write!(&mut output, "{sample_size:<7}");
             for (name, _, percent) in items_str {
                 let _ = write!(&mut output, " | {name}: {percent:6.2}%");
             }
             let _ = write!(&mut output, " | {min:02}:{sec:02}");
+            if let Some(speed) = &speed {
+                let _ = write!(&mut output, " | {speed}");
+            }
         }
     }
 
     drop(output);
 
-    let hashmap = simulator.results();
-    let mut sample_size = 0;
-    for i in &hashmap {
-        sample_size += *i.1
-    };
+    if let Some(log) = trial_log.as_mut() {
+        if let Err(e) = log.write_pending(&simulator) {
+            eprintln!("Failed to write to the trial log: {e}");
+        }
+    }
+    if let Some(log) = convergence.as_mut() {
+        if let Err(e) = log.finish(&simulator, start.elapsed()) {
+            eprintln!("Failed to write a convergence snapshot: {e}");
+        }
+    }
 
+    let mut report = Report::new(&simulator);
+    report.sort(args.sort);
+    report.ascii = ascii;
     let mut writer = BufWriter::new(stdout().lock());
-    let _ = writeln!(writer);
-    let _ = writeln!(writer, "MOVE            COUNT        %");
-    let _ = writeln!(writer, "==============================");
+    if args.porcelain {
+        let _ = report.write_porcelain(&mut writer);
+    }
+    else {
+        let _ = writeln!(writer);
+        let _ = report.write(&mut writer, args.format.unwrap_or_default());
+        let _ = writeln!(writer);
+    }
+    drop(writer);
+
+    if let Some(path) = &args.resume {
+        match report.save(path, ReportFormat::Json) {
+            Ok(()) => println!("Saved the results to {} ({} trials in total)", path.display(), report.total),
+            Err(e) => eprintln!("Failed to write {}: {e}", path.display())
+        }
+        if simulator.warm_state().is_some() {
+            export_warm_state(&simulator, &resume_warm_state_path(path));
+        }
+    }
+
+    if let Some(path) = &args.output {
+        if args.append {
+            append_report(report, path);
+        }
+        else if let Err(e) = report.save(path, args.format.unwrap_or_default()) {
+            eprintln!("Failed to write {}: {e}", path.display());
+        }
+    }
+
+    if let Some(path) = &args.export_warm_state {
+        export_warm_state(&simulator, path);
+    }
+    None
+}
+
+/// Add the results in a results file to the simulator, and start from the warm state saved with them if there is one.
+///
+/// Prints an error and returns `false` if they're for a different ROM or save state. If the file doesn't exist yet,
+/// there's nothing to add.
+fn resume(simulator: &mut Simulator, path: &Path) -> bool {
+    if !path.exists() {
+        return true;
+    }
+    let previous = match Report::load(path) {
+        Ok(previous) => previous,
+        Err(e) => {
+            eprintln!("Failed to read {}: {e}", path.display());
+            return false;
+        }
+    };
+    if previous.state_hash != simulator.state_hash() {
+        eprintln!(
+            "{} has results for a different ROM or save state ({:016x}, not {:016x})",
+            path.display(),
+            previous.state_hash,
+            simulator.state_hash()
+        );
+        return false;
+    }
+
+    let warm_state_path = resume_warm_state_path(path);
+    if let Ok(warm_state) = read(&warm_state_path) {
+        if !simulator.set_warm_state(warm_state) {
+            eprintln!("Failed to load warm state {}, so warming up again", warm_state_path.display());
+        }
+    }
+    log::info!("Resuming from {} trials in {}", previous.total, path.display());
+    simulator.add_results(previous.rows.iter().map(|row| (row.outcome, row.count)));
+    true
+}
+
+/// Where `--resume` keeps the warm state for a results file, e.g. `results.warm` for `results.json`.
+fn resume_warm_state_path(path: &Path) -> PathBuf {
+    path.with_extension("warm")
+}
+
+fn append_report(mut report: Report, path: &Path) {
+    if path.exists() {
+        let previous = match Report::load(path) {
+            Ok(previous) => previous,
+            Err(e) => {
+                eprintln!("Failed to read {}, so the results weren't added to it: {e}", path.display());
+                return;
+            }
+        };
+        if previous.state_hash != report.state_hash {
+            eprintln!(
+                "{} has results for a different ROM or save state ({:016x}, not {:016x}), so the results weren't added to it",
+                path.display(),
+                previous.state_hash,
+                report.state_hash
+            );
+            return;
+        }
+        report.merge(previous);
+    }
 
-    let mut items: Vec<(u8, u64)> = hashmap.iter().map(|(&a, &b)| (a, b)).collect();
-    items.sort_by(|a, b| a.0.cmp(&b.0));
+    match report.save(path, ReportFormat::Json) {
+        Ok(()) => println!("Added the results to {} ({} trials in total)", path.display(), report.total),
+        Err(e) => eprintln!("Failed to write {}: {e}", path.display())
+    }
+}
 
-    for (m, cnt) in items {
-        let m = move_name(m).map(|m| m.to_owned()).unwrap_or(format!("UNK (0x{m:02X})"));
-        let _ = writeln!(writer, "{m:-12} {cnt:8} {:7.2}%", 100.0 * cnt as f64 / sample_size as f64);
+fn save_decision_screenshot(simulator: &Simulator, path: &Path) {
+    let Some(screenshot) = simulator.decision_screenshot(SCREENSHOT_MAX_FRAMES) else {
+        eprintln!("The AI didn't decide within a minute of game time, so no screenshot was saved");
+        return;
+    };
+    match png::write(path, screenshot.frame()) {
+        Ok(()) => println!("Saved a screenshot of the AI's decision to {}", path.display()),
+        Err(e) => eprintln!("Failed to write {}: {e}", path.display())
     }
+}
 
-    let _ = writeln!(writer);
+/// Save the warm state, printing an error and returning `false` if it fails.
+fn export_warm_state(simulator: &Simulator, path: &Path) -> bool {
+    let Some(warm_state) = simulator.warm_state() else {
+        eprintln!("No warm state to export; the game never read the RNG");
+        return false;
+    };
+    if write(path, warm_state).is_err() {
+        eprintln!("Failed to write warm state {}", path.display());
+        return false;
+    }
+    true
 }
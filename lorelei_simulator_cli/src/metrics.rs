@@ -0,0 +1,50 @@
+//! Prometheus metrics for `serve`, at `/metrics` when built with the `metrics` feature, so long runs can be watched
+//! with the usual monitoring tools.
+
+use std::fmt::Write;
+use lorelei_simulator::{Outcome, Simulator};
+use lorelei_simulator::report::display_name;
+
+/// Write the metrics in Prometheus's text format. `rate` is the number of trials per second, if it's known yet.
+pub fn write(simulator: &Simulator, rate: Option<f64>) -> String {
+    let mut text = String::new();
+    let mut metric = |name: &str, kind: &str, help: &str, samples: &[(String, f64)]| {
+        let _ = writeln!(text, "# HELP {name} {help}");
+        let _ = writeln!(text, "# TYPE {name} {kind}");
+        for (labels, value) in samples {
+            let _ = writeln!(text, "{name}{labels} {value}");
+        }
+    };
+
+    metric("lorelei_running", "gauge", "Whether the simulator is running", &[(String::new(), simulator.is_running() as u8 as f64)]);
+    metric("lorelei_trials_total", "counter", "Trials finished, including ones from earlier runs", &[(String::new(), simulator.sample_count() as f64)]);
+    let rate: Vec<(String, f64)> = rate.map(|rate| (String::new(), rate)).into_iter().collect();
+    metric("lorelei_trials_per_second", "gauge", "Trials finished per second over the last few seconds", &rate);
+
+    let mut results: Vec<(Outcome, u64)> = simulator.results().into_iter().collect();
+    results.sort_by_key(|&(outcome, _)| outcome);
+    let outcomes: Vec<(String, f64)> = results
+        .into_iter()
+        .map(|(outcome, count)| {
+            let (kind, index) = match outcome {
+                Outcome::Move(index) => ("move", index),
+                Outcome::Item(index) => ("item", index)
+            };
+            let name = label_value(&display_name(simulator, outcome));
+            (format!("{{kind=\"{kind}\",index=\"{index}\",name=\"{name}\"}}"), count as f64)
+        })
+        .collect();
+    metric("lorelei_outcome_trials_total", "counter", "Trials the AI made each choice in", &outcomes);
+
+    let threads = simulator.thread_statuses();
+    let thread_trials: Vec<(String, f64)> = threads.iter().enumerate().map(|(i, t)| (format!("{{thread=\"{i}\"}}"), t.trials as f64)).collect();
+    metric("lorelei_thread_trials_total", "counter", "Trials each thread finished since the simulator was started", &thread_trials);
+    let thread_running: Vec<(String, f64)> = threads.iter().enumerate().map(|(i, t)| (format!("{{thread=\"{i}\"}}"), t.running as u8 as f64)).collect();
+    metric("lorelei_thread_running", "gauge", "Whether each thread is still running", &thread_running);
+    text
+}
+
+/// Escape a label value for the text format.
+fn label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -0,0 +1,46 @@
+//! Desktop notifications, sent with what each platform has built in so nothing else needs to be installed.
+
+use std::process::{Command, Stdio};
+
+/// Show a desktop notification, printing an error if it can't be sent.
+pub fn send(title: &str, body: &str) {
+    let mut command = command(title, body);
+    let result = command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).status();
+    match result {
+        Ok(status) if status.success() => (),
+        Ok(status) => eprintln!("Failed to send a notification: {} exited with {status}", command.get_program().to_string_lossy()),
+        Err(e) => eprintln!("Failed to send a notification: {e}")
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn command(title: &str, body: &str) -> Command {
+    // pass the text as arguments so it doesn't have to be escaped for AppleScript
+    let mut command = Command::new("osascript");
+    command.args(["-e", "on run argv", "-e", "display notification (item 2 of argv) with title (item 1 of argv)", "-e", "end run", title, body]);
+    command
+}
+
+#[cfg(windows)]
+fn command(title: &str, body: &str) -> Command {
+    // Toasts have to come from a registered app, so this borrows PowerShell's. The text is passed in the environment
+    // so it doesn't have to be escaped.
+    const SCRIPT: &str = "\
+        [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null;\
+        $template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02);\
+        $text = $template.GetElementsByTagName('text');\
+        $text.Item(0).AppendChild($template.CreateTextNode($env:LORELEI_TITLE)) > $null;\
+        $text.Item(1).AppendChild($template.CreateTextNode($env:LORELEI_BODY)) > $null;\
+        $app = '{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe';\
+        [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($app).Show([Windows.UI.Notifications.ToastNotification]::new($template))";
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-NonInteractive", "-Command", SCRIPT]).env("LORELEI_TITLE", title).env("LORELEI_BODY", body);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn command(title: &str, body: &str) -> Command {
+    let mut command = Command::new("notify-send");
+    command.args(["--app-name=Lorelei Simulator", "--", title, body]);
+    command
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+use lorelei_simulator::report::display_name;
+use crate::{png, InputArgs};
+
+/// Frames per second of the Game Boy's LCD
+const FRAME_RATE: f64 = 59.7275;
+
+/// Run one trial and save every frame as a PNG so it can be checked by eye.
+pub fn playback(input: &InputArgs, directory: &Path, max_seconds: u32) {
+    let Some(simulator) = input.load(None) else {
+        return;
+    };
+    if let Err(e) = std::fs::create_dir_all(directory) {
+        eprintln!("Failed to create {}: {e}", directory.display());
+        return;
+    }
+
+    println!("Playing back one trial...");
+    let mut frames_written = 0u32;
+    let mut error = None;
+    let max_frames = (max_seconds as f64 * FRAME_RATE) as u32;
+    let probe = simulator.playback(max_frames, |frame| {
+        let path = directory.join(format!("frame_{frames_written:05}.png"));
+        if let Err(e) = png::write(&path, frame) {
+            error = Some((path, e));
+            return false;
+        }
+        frames_written += 1;
+        true
+    });
+
+    if let Some((path, e)) = error {
+        eprintln!("Failed to write {}: {e}", path.display());
+        return;
+    }
+
+    println!("Wrote {frames_written} frame{s} to {}", directory.display(), s = if frames_written == 1 { "" } else { "s" });
+    match (probe.outcome, probe.decision_frame) {
+        (Some(outcome), Some(frame)) => println!("The AI decided on {} at frame {frame}", display_name(&simulator, outcome)),
+        _ => println!("The AI didn't decide within {max_seconds} seconds")
+    }
+    println!("To watch it at normal speed, e.g.: ffmpeg -framerate {FRAME_RATE} -i {} playback.mp4", directory.join("frame_%05d.png").display());
+}
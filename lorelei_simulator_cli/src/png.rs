@@ -0,0 +1,208 @@
+//! Writing PNG files, for saving frames from the LCD.
+//!
+//! Image data is compressed with a simple LZ77 and deflate's fixed Huffman codes, which is plenty for Game Boy
+//! screens.
+
+use std::path::Path;
+use lorelei_simulator::Frame;
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1A\n";
+
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+const WINDOW_SIZE: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+/// How many earlier positions to try when looking for a match
+const MAX_CHAIN: usize = 64;
+
+/// Save a frame as a PNG file.
+pub fn write(path: &Path, frame: Frame<'_>) -> std::io::Result<()> {
+    std::fs::write(path, encode(frame))
+}
+
+/// Encode a frame as an RGB PNG.
+pub fn encode(frame: Frame<'_>) -> Vec<u8> {
+    let mut raw = Vec::with_capacity((frame.width * 3 + 1) * frame.height);
+    for row in frame.pixels.chunks_exact(frame.width).take(frame.height) {
+        raw.push(0); // no filter
+        for &pixel in row {
+            raw.extend_from_slice(&pixel.to_be_bytes()[1..]);
+        }
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(frame.width as u32).to_be_bytes());
+    header.extend_from_slice(&(frame.height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]); // 8-bit RGB, deflate, adaptive filtering, no interlacing
+
+    let mut png = SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_compress(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    bits.write(1, 1); // last block
+    bits.write(1, 2); // fixed Huffman codes
+
+    let mut matcher = Matcher { data, head: vec![usize::MAX; 1 << 15], previous: vec![usize::MAX; data.len()] };
+    let mut i = 0;
+    while i < data.len() {
+        let (length, distance) = matcher.find(i);
+        if length >= MIN_MATCH {
+            write_length(&mut bits, length, distance);
+            for j in i..i + length {
+                matcher.insert(j);
+            }
+            i += length;
+        }
+        else {
+            write_literal(&mut bits, data[i] as u16);
+            matcher.insert(i);
+            i += 1;
+        }
+    }
+    write_literal(&mut bits, 256);
+
+    let mut output = vec![0x78, 0x01];
+    output.extend_from_slice(&bits.finish());
+    output.extend_from_slice(&adler32(data).to_be_bytes());
+    output
+}
+
+/// Finds earlier copies of the data at a position by hashing every 3 bytes.
+struct Matcher<'a> {
+    data: &'a [u8],
+
+    /// The most recent position of each hash
+    head: Vec<usize>,
+
+    /// The position before each position with the same hash
+    previous: Vec<usize>
+}
+
+impl Matcher<'_> {
+    fn hash(&self, i: usize) -> usize {
+        (((self.data[i] as usize) << 10) ^ ((self.data[i + 1] as usize) << 5) ^ self.data[i + 2] as usize) & 0x7FFF
+    }
+
+    fn insert(&mut self, i: usize) {
+        if i + MIN_MATCH <= self.data.len() {
+            let hash = self.hash(i);
+            self.previous[i] = self.head[hash];
+            self.head[hash] = i;
+        }
+    }
+
+    /// Find the longest match for the data at `i`, returning its length and distance.
+    fn find(&self, i: usize) -> (usize, usize) {
+        if i + MIN_MATCH > self.data.len() {
+            return (0, 0);
+        }
+        let max_length = (self.data.len() - i).min(MAX_MATCH);
+        let mut best = (0, 0);
+        let mut candidate = self.head[self.hash(i)];
+        for _ in 0..MAX_CHAIN {
+            if candidate == usize::MAX || i - candidate > WINDOW_SIZE {
+                break;
+            }
+            let length = self.data[candidate..].iter().zip(&self.data[i..i + max_length]).take_while(|(a, b)| a == b).count();
+            if length > best.0 {
+                best = (length, i - candidate);
+                if length == max_length {
+                    break;
+                }
+            }
+            candidate = self.previous[candidate];
+        }
+        best
+    }
+}
+
+fn write_literal(bits: &mut BitWriter, symbol: u16) {
+    let (code, length) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xC0 + symbol - 280, 8)
+    };
+    bits.write_huffman(code as u32, length);
+}
+
+fn write_length(bits: &mut BitWriter, length: usize, distance: usize) {
+    let index = LENGTH_BASE.iter().rposition(|&base| base as usize <= length).unwrap();
+    write_literal(bits, 257 + index as u16);
+    bits.write((length - LENGTH_BASE[index] as usize) as u32, LENGTH_EXTRA[index]);
+
+    let index = DISTANCE_BASE.iter().rposition(|&base| base as usize <= distance).unwrap();
+    bits.write_huffman(index as u32, 5);
+    bits.write((distance - DISTANCE_BASE[index] as usize) as u32, DISTANCE_EXTRA[index]);
+}
+
+#[derive(Default)]
+struct BitWriter {
+    output: Vec<u8>,
+    buffer: u64,
+    count: u8
+}
+
+impl BitWriter {
+    /// Write `count` bits, least significant first.
+    fn write(&mut self, value: u32, count: u8) {
+        self.buffer |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.output.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes are stored most significant bit first, unlike everything else.
+    fn write_huffman(&mut self, code: u32, length: u8) {
+        self.write(code.reverse_bits() >> (32 - length), length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.output.push(self.buffer as u8);
+        }
+        self.output
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,39 @@
+//! Named presets for how long and how hard to simulate.
+
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::time::Duration;
+
+/// Settings bundled by `--profile`. Anything given on the command line takes priority.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    pub trials: Option<NonZeroU64>,
+
+    /// See `--precision`
+    pub precision: Option<f64>,
+
+    /// See `--timeout`
+    pub timeout: Option<Duration>,
+
+    /// Threads to use, or `None` for all of them
+    pub jobs: Option<NonZeroUsize>
+}
+
+pub const BUILTIN_PROFILES: [&str; 3] = ["quick", "standard", "thorough"];
+
+impl Profile {
+    /// Get a built-in profile.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let (trials, precision, timeout) = match name {
+            "quick" => (2_000, 2.0, 30),
+            "standard" => (50_000, 0.5, 60),
+            "thorough" => (1_000_000, 0.1, 120),
+            _ => return None
+        };
+        Some(Self {
+            trials: NonZeroU64::new(trials),
+            precision: Some(precision),
+            timeout: Some(Duration::from_secs(timeout)),
+            jobs: None
+        })
+    }
+}
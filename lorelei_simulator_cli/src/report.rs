@@ -0,0 +1,274 @@
+//! The final report, in each of the formats it can be written in.
+
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use lorelei_simulator::json::{JsonError, JsonValue};
+use lorelei_simulator::Simulator;
+use lorelei_simulator::report::Row;
+pub use lorelei_simulator::report::{kind_and_index, FORMAT_VERSION};
+
+/// Width of the bar for 100% in the table, in characters
+const BAR_WIDTH: usize = 20;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// The usual table
+    #[default]
+    Table,
+
+    /// Comma-separated values, with a header row
+    Csv,
+
+    /// A Markdown table, for GitHub issues, forum posts, and route documents
+    Markdown,
+
+    /// The versioned JSON format results are saved in
+    Json
+}
+
+/// Order of the rows in a report.
+#[derive(Copy, Clone, PartialEq, Debug, Default, clap::ValueEnum)]
+pub enum SortOrder {
+    /// Move (or item) index
+    #[default]
+    Index,
+
+    /// Most trials first
+    Count,
+
+    /// Highest percentage first (the same as count)
+    Percent,
+
+    /// Alphabetically by name
+    Name
+}
+
+/// Results of a run, ready to be written out.
+pub struct Report {
+    /// The counts, which this derefs to
+    pub results: lorelei_simulator::report::Report,
+
+    /// Draw the table's bars with `#` instead of block characters
+    pub ascii: bool
+}
+
+impl Deref for Report {
+    type Target = lorelei_simulator::report::Report;
+
+    fn deref(&self) -> &Self::Target {
+        &self.results
+    }
+}
+
+impl DerefMut for Report {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.results
+    }
+}
+
+impl Report {
+    pub fn new(simulator: &Simulator) -> Self {
+        Self { results: lorelei_simulator::report::Report::new(simulator), ascii: false }
+    }
+
+    /// Read a report back from the versioned JSON format.
+    pub fn from_json(json: &JsonValue) -> Result<Self, ReportError> {
+        let results = lorelei_simulator::report::Report::from_json(json).map_err(ReportError::Format)?;
+        Ok(Self { results, ascii: false })
+    }
+
+    /// Read a report from a file in the versioned JSON format.
+    pub fn load(path: &Path) -> Result<Self, ReportError> {
+        let text = std::fs::read_to_string(path).map_err(ReportError::Read)?;
+        Self::from_json(&JsonValue::parse(&text).map_err(ReportError::Json)?)
+    }
+
+    pub fn sort(&mut self, order: SortOrder) {
+        match order {
+            SortOrder::Index => self.rows.sort_by_key(|row| row.outcome),
+            SortOrder::Count | SortOrder::Percent => self.rows.sort_by_key(|row| (std::cmp::Reverse(row.count), row.outcome)),
+            SortOrder::Name => self.rows.sort_by(|a, b| a.name.cmp(&b.name).then(a.outcome.cmp(&b.outcome)))
+        }
+    }
+
+    /// Add another report's counts to this one.
+    ///
+    /// Both should be for the same state; this isn't checked.
+    pub fn merge(&mut self, other: Report) {
+        self.total += other.total;
+        for other_row in other.results.rows {
+            match self.rows.iter_mut().find(|row| row.outcome == other_row.outcome) {
+                Some(row) => row.count += other_row.count,
+                None => self.rows.push(other_row)
+            }
+        }
+        self.rows.sort_by_key(|row| row.outcome);
+    }
+
+    pub fn write(&self, writer: &mut impl Write, format: ReportFormat) -> std::io::Result<()> {
+        match format {
+            ReportFormat::Table => self.write_table(writer),
+            ReportFormat::Csv => self.write_csv(writer),
+            ReportFormat::Markdown => self.write_markdown(writer),
+            ReportFormat::Json => writeln!(writer, "{:#}", self.to_json())
+        }
+    }
+
+    /// Write the report to a file, making any directories it's in.
+    pub fn save(&self, path: &Path, format: ReportFormat) -> std::io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, format)?;
+        writer.flush()
+    }
+
+    /// Write one tab-separated line per row with the kind (`move` or `item`), index, name, count, and percentage, for
+    /// scripts.
+    ///
+    /// This shouldn't change between versions, unlike the table.
+    pub fn write_porcelain(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        for row in &self.rows {
+            let (kind, index) = kind_and_index(row.outcome);
+            writeln!(writer, "{kind}\t{index}\t{}\t{}\t{:.2}", row.name.replace(['\t', '\n'], " "), row.count, 100.0 * row.fraction(self.total))?;
+        }
+        Ok(())
+    }
+
+    fn write_table(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let intervals: Vec<(f64, f64)> = self.rows.iter().map(|row| row.interval(self.total)).collect();
+        let overlaps = |i: usize| {
+            let (low, high) = intervals[i];
+            intervals.iter().enumerate().any(|(j, &(other_low, other_high))| j != i && low <= other_high && other_low <= high)
+        };
+
+        writeln!(writer, "MOVE            COUNT        %        95% CI")?;
+        writeln!(writer, "==============================================")?;
+        let mut any_overlap = false;
+        for (i, row) in self.rows.iter().enumerate() {
+            let (low, high) = intervals[i];
+            let overlap = overlaps(i);
+            any_overlap |= overlap;
+            let line = format!(
+                "{:-12} {:8} {:7.2}%  {:6.2}-{:<6.2} {} {}",
+                row.name,
+                row.count,
+                100.0 * row.fraction(self.total),
+                100.0 * low,
+                100.0 * high,
+                if overlap { '*' } else { ' ' },
+                bar(row.fraction(self.total), BAR_WIDTH, self.ascii)
+            );
+            writeln!(writer, "{}", line.trim_end())?;
+        }
+
+        if let Some(summary) = self.summary() {
+            writeln!(writer)?;
+            writeln!(writer, "{summary}")?;
+        }
+        if any_overlap {
+            writeln!(writer)?;
+            writeln!(writer, "* Overlaps with another move's interval, so the difference between them may just be chance")?;
+        }
+        Ok(())
+    }
+
+    /// Get a line like `Most likely: ICE BEAM 41.3% ±0.9; top 2 cover 78.0%`, if there are any trials.
+    pub fn summary(&self) -> Option<String> {
+        let mut by_count: Vec<&Row> = self.rows.iter().filter(|row| row.count != 0).collect();
+        by_count.sort_by_key(|row| (std::cmp::Reverse(row.count), row.outcome));
+        let top = by_count.first()?;
+
+        let percent = 100.0 * top.fraction(self.total);
+        let margin = 100.0 * top.margin(self.total);
+        let mut summary = format!("Most likely: {} {percent:.1}% ±{margin:.1}", top.name);
+        if by_count.len() > 2 {
+            let top_two: u64 = by_count[..2].iter().map(|row| row.count).sum();
+            summary += &format!("; top 2 cover {:.1}%", 100.0 * top_two as f64 / self.total as f64);
+        }
+        Some(summary)
+    }
+
+    fn write_markdown(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        writeln!(writer, "| Move | Count | % | 95% CI |")?;
+        writeln!(writer, "|:-----|------:|--:|:------:|")?;
+        for row in &self.rows {
+            let (low, high) = row.interval(self.total);
+            writeln!(
+                writer,
+                "| {} | {} | {:.2}% | {:.2}–{:.2}% |",
+                row.name.replace('|', "\\|"),
+                row.count,
+                100.0 * row.fraction(self.total),
+                100.0 * low,
+                100.0 * high
+            )?;
+        }
+        writeln!(writer)?;
+        writeln!(writer, "*{} trial{}*", self.total, if self.total == 1 { "" } else { "s" })
+    }
+
+    fn write_csv(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        writeln!(writer, "kind,index,name,count,percent,ci_low,ci_high")?;
+        for row in &self.rows {
+            let (kind, index) = kind_and_index(row.outcome);
+            let (low, high) = row.interval(self.total);
+            writeln!(
+                writer,
+                "{kind},{index},{},{},{:.2},{:.2},{:.2}",
+                csv_field(&row.name),
+                row.count,
+                100.0 * row.fraction(self.total),
+                100.0 * low,
+                100.0 * high
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Read(std::io::Error),
+    Json(JsonError),
+    Format(lorelei_simulator::report::ReportError)
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(e) => write!(f, "{e}"),
+            Self::Json(e) => write!(f, "not valid JSON: {e}"),
+            Self::Format(e) => write!(f, "{e}")
+        }
+    }
+}
+
+/// Draw a bar `width` characters long for 100%, in eighths of a character (or whole characters if `ascii`).
+pub fn bar(fraction: f64, width: usize, ascii: bool) -> String {
+    if ascii {
+        return "#".repeat((fraction.clamp(0.0, 1.0) * width as f64).round() as usize);
+    }
+
+    const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let eighths = (fraction.clamp(0.0, 1.0) * (width * 8) as f64).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(EIGHTHS[eighths % 8]);
+    }
+    bar
+}
+
+/// Quote a CSV field if it needs it.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    }
+    else {
+        field.to_owned()
+    }
+}
@@ -0,0 +1,52 @@
+//! Asking RetroArch for save states over its network command interface.
+//!
+//! This has to be enabled in RetroArch (Settings > Network > Network Commands). It listens on UDP port 55355 by
+//! default.
+
+use std::fmt::{Display, Formatter};
+use std::net::{ToSocketAddrs, UdpSocket};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use crate::watch::FileWatcher;
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:55355";
+
+/// How long to wait for RetroArch to write the save state
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub enum RetroArchError {
+    Send(std::io::Error),
+    Timeout
+}
+
+impl Display for RetroArchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Send(e) => write!(f, "Failed to send a command to RetroArch: {e}"),
+            Self::Timeout => write!(f, "RetroArch didn't save a state within {} seconds; make sure network commands are enabled and the save state path is RetroArch's states directory", TIMEOUT.as_secs())
+        }
+    }
+}
+
+/// Have RetroArch save a state to its current slot, and wait for it to show up at `path` (a file or directory).
+pub fn save_state(address: &str, path: &Path) -> Result<(), RetroArchError> {
+    let mut watcher = FileWatcher::new(path);
+
+    let address = address
+        .to_socket_addrs()
+        .map_err(RetroArchError::Send)?
+        .next()
+        .ok_or_else(|| RetroArchError::Send(std::io::ErrorKind::AddrNotAvailable.into()))?;
+    let local = if address.is_ipv6() { "[::]:0" } else { "0.0.0.0:0" };
+    let socket = UdpSocket::bind(local).map_err(RetroArchError::Send)?;
+    socket.send_to(b"SAVE_STATE\n", address).map_err(RetroArchError::Send)?;
+
+    let start = Instant::now();
+    while !watcher.changed() {
+        if start.elapsed() > TIMEOUT {
+            return Err(RetroArchError::Timeout);
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Ok(())
+}
@@ -0,0 +1,266 @@
+//! Driving the simulator with JSON-RPC 2.0 on stdin and stdout with `rpc`, for editor plugins and launchers that
+//! would rather not manage a server.
+//!
+//! Each request and response is one line of JSON. The methods are:
+//!
+//! - `load` with `rom` and `save_state` (file paths) and optionally `game`, stopping the last run
+//! - `start` with optional `trials` (stop after this many more) and `jobs`
+//! - `stop`
+//! - `status`, which gives whether it's running, the number of trials, and the trials per second
+//! - `results`, which gives the results in the same format as the JSON status file
+//! - `battle_context`, which gives the Pokémon in battle, their stat stages, and the player's badges
+//!
+//! When a run finishes (or is stopped), a `finished` notification with the results is sent unless something else was
+//! loaded or started first.
+
+use std::io::{stdin, stdout, BufRead, Write};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use lorelei_simulator::battle::{badge_names, BattleMon, StatStages, StatusCondition};
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::{Game, Outcome, Simulator, TrialRate, Type};
+use crate::report::Report;
+use crate::inspect::species_name;
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// Code for errors from the simulator itself, e.g. a save state that can't be read
+const SIMULATOR_ERROR: i32 = -32000;
+
+/// What the requests share with the threads sending `finished`.
+#[derive(Default)]
+struct Session {
+    simulator: Option<Simulator>,
+    rate: TrialRate,
+
+    /// Goes up with every load and start, so `finished` isn't sent for a run that was replaced
+    run: u64
+}
+
+struct RpcError {
+    code: i32,
+    message: String
+}
+
+impl RpcError {
+    fn new(code: i32, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+}
+
+pub fn rpc(jobs: Option<NonZeroUsize>) {
+    let session = Arc::new(Mutex::new(Session::default()));
+    for line in stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (id, result) = match JsonValue::parse(&line) {
+            Ok(request) => {
+                let id = request.get("id").cloned();
+                (id, handle(&request, &session, jobs))
+            },
+            Err(e) => (Some(JsonValue::Null), Err(RpcError::new(PARSE_ERROR, format!("Failed to parse request: {e}"))))
+        };
+
+        // requests without an id are notifications, which don't get a response
+        let Some(id) = id else {
+            continue;
+        };
+        let response = match result {
+            Ok(result) => JsonValue::object([("jsonrpc", JsonValue::from("2.0")), ("id", id), ("result", result)]),
+            Err(error) => {
+                let error = JsonValue::object([("code", JsonValue::from(error.code)), ("message", JsonValue::from(error.message))]);
+                JsonValue::object([("jsonrpc", JsonValue::from("2.0")), ("id", id), ("error", error)])
+            }
+        };
+        send(&response);
+    }
+
+    let mut session = session.lock().unwrap();
+    if let Some(simulator) = session.simulator.as_mut() {
+        simulator.stop();
+    }
+}
+
+/// Write a message to stdout as one line.
+fn send(message: &JsonValue) {
+    let mut stdout = stdout().lock();
+    let _ = writeln!(stdout, "{message}");
+    let _ = stdout.flush();
+}
+
+fn handle(request: &JsonValue, session: &Arc<Mutex<Session>>, jobs: Option<NonZeroUsize>) -> Result<JsonValue, RpcError> {
+    let method = request.get("method").and_then(JsonValue::as_str).ok_or_else(|| RpcError::new(INVALID_REQUEST, "No method"))?;
+    let params = request.get("params").unwrap_or(&JsonValue::Null);
+    let param = |key: &str| params.get(key).filter(|value| **value != JsonValue::Null);
+    let mut guard = session.lock().unwrap();
+    let session_state = &mut *guard;
+
+    match method {
+        "load" => {
+            let path = |key: &str| param(key).and_then(JsonValue::as_str).ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("{key} should be a file path")));
+            let (rom_path, save_state_path) = (path("rom")?, path("save_state")?);
+            let game = match param("game") {
+                Some(name) => {
+                    let name = name.as_str().unwrap_or_default();
+                    Some(Game::from_short_name(name).ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Unknown game {name}")))?)
+                },
+                None => None
+            };
+            let read = |what: &str, path: &str| std::fs::read(path).map_err(|_| RpcError::new(SIMULATOR_ERROR, format!("Failed to read {what} {path}")));
+            let (rom, save_state) = (read("ROM", rom_path)?, read("save state", save_state_path)?);
+
+            if let Some(simulator) = session_state.simulator.as_mut() {
+                simulator.stop();
+            }
+            session_state.simulator = None;
+            let simulator = Simulator::new_from_vec(rom, save_state, None, game)
+                .map_err(|e| RpcError::new(SIMULATOR_ERROR, format!("Failed to load simulator: {e}")))?;
+            let result = JsonValue::object([
+                ("game", JsonValue::from(simulator.game_name())),
+                ("state_hash", JsonValue::from(format!("{:016x}", simulator.state_hash()))),
+                ("opponent", simulator.opponent().map(|o| JsonValue::from(o.to_string())).unwrap_or(JsonValue::Null))
+            ]);
+            session_state.simulator = Some(simulator);
+            session_state.run += 1;
+            Ok(result)
+        },
+        "start" => {
+            let number = |key: &str| {
+                param(key)
+                    .map(|value| value.as_u64().filter(|&n| n != 0).ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("{key} should be a number above 0"))))
+                    .transpose()
+            };
+            let trials = number("trials")?;
+            let jobs = number("jobs")?.and_then(|n| NonZeroUsize::new(n as usize)).or(jobs);
+            let simulator = loaded(session_state)?;
+            if simulator.is_running() {
+                return Err(RpcError::new(SIMULATOR_ERROR, "Already running"));
+            }
+            simulator.set_trials(trials.map(|t| simulator.sample_count().saturating_add(t)));
+            simulator.start(jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap()));
+            let progress = simulator.progress();
+            session_state.rate = TrialRate::default();
+            session_state.run += 1;
+
+            let (session, run) = (Arc::clone(session), session_state.run);
+            std::thread::spawn(move || {
+                progress.wait(Duration::MAX);
+                let session = session.lock().unwrap();
+                if let Some(simulator) = session.simulator.as_ref().filter(|_| session.run == run) {
+                    send(&JsonValue::object([
+                        ("jsonrpc", JsonValue::from("2.0")),
+                        ("method", JsonValue::from("finished")),
+                        ("params", results_json(simulator))
+                    ]));
+                }
+            });
+            Ok(JsonValue::Null)
+        },
+        "stop" => {
+            loaded(session_state)?.stop();
+            Ok(JsonValue::Null)
+        },
+        "status" => {
+            let simulator = loaded(session_state)?;
+            let (running, trials) = (simulator.is_running(), simulator.sample_count());
+            let rate = session_state.rate.update(trials);
+            Ok(JsonValue::object([
+                ("running", JsonValue::from(running)),
+                ("trials", JsonValue::from(trials)),
+                ("trials_per_second", rate.map(JsonValue::Number).unwrap_or(JsonValue::Null))
+            ]))
+        },
+        "results" => Ok(results_json(loaded(session_state)?)),
+        "battle_context" => battle_context_json(loaded(session_state)?),
+        _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("Unknown method {method}")))
+    }
+}
+
+fn loaded(session: &mut Session) -> Result<&mut Simulator, RpcError> {
+    session.simulator.as_mut().ok_or_else(|| RpcError::new(SIMULATOR_ERROR, "Nothing loaded; call load first"))
+}
+
+fn results_json(simulator: &Simulator) -> JsonValue {
+    let elapsed = simulator.snapshot().elapsed;
+    crate::status::json(&Report::new(simulator), elapsed, simulator.is_running())
+}
+
+fn battle_context_json(simulator: &Simulator) -> Result<JsonValue, RpcError> {
+    let context = simulator.battle_context().ok_or_else(|| RpcError::new(SIMULATOR_ERROR, "The save state is not in a battle"))?;
+    let badges = badge_names(simulator.generation(), context.badges).into_iter().map(JsonValue::from).collect();
+    Ok(JsonValue::object([
+        ("trainer_battle", JsonValue::from(context.trainer_battle)),
+        ("player", mon_json(simulator, &context.player, &context.player_stat_stages)),
+        ("enemy", mon_json(simulator, &context.enemy, &context.enemy_stat_stages)),
+        ("badges", JsonValue::Array(badges))
+    ]))
+}
+
+fn mon_json(simulator: &Simulator, mon: &BattleMon, stages: &StatStages) -> JsonValue {
+    let generation = simulator.generation();
+    let status = match mon.status {
+        StatusCondition::None => JsonValue::Null,
+        status => JsonValue::from(status.abbreviation().unwrap_or_default())
+    };
+    let sleep_turns = match mon.status {
+        StatusCondition::Sleep(turns) => turns,
+        _ => 0
+    };
+    let types = mon
+        .types
+        .iter()
+        .map(|&t| JsonValue::object([("index", JsonValue::from(t)), ("name", Type::from_u8(generation, t).map(|t| JsonValue::from(t.name())).unwrap_or(JsonValue::Null))]))
+        .collect();
+    let moves = mon
+        .moves
+        .iter()
+        .zip(mon.pp)
+        .filter(|(&index, _)| index != 0)
+        .map(|(&index, pp)| {
+            JsonValue::object([
+                ("index", JsonValue::from(index)),
+                ("name", simulator.outcome_name(Outcome::Move(index)).map(JsonValue::from).unwrap_or(JsonValue::Null)),
+                ("pp", JsonValue::from(pp & 0x3F)),
+                ("pp_ups", JsonValue::from(pp >> 6))
+            ])
+        })
+        .collect();
+    let stage = |stage: i8| JsonValue::from(stage as i32);
+    JsonValue::object([
+        ("species_index", JsonValue::from(mon.species_index)),
+        ("species", JsonValue::from(species_name(mon.species, mon.species_index))),
+        ("item", mon.item.map(JsonValue::from).unwrap_or(JsonValue::Null)),
+        ("level", JsonValue::from(mon.level)),
+        ("hp", JsonValue::from(mon.hp)),
+        ("max_hp", JsonValue::from(mon.max_hp)),
+        ("status", status),
+        ("sleep_turns", JsonValue::from(sleep_turns)),
+        ("types", JsonValue::Array(types)),
+        ("moves", JsonValue::Array(moves)),
+        ("dvs", JsonValue::from(mon.dvs)),
+        ("stats", JsonValue::object([
+            ("attack", JsonValue::from(mon.stats.attack)),
+            ("defense", JsonValue::from(mon.stats.defense)),
+            ("speed", JsonValue::from(mon.stats.speed)),
+            ("special_attack", JsonValue::from(mon.stats.special_attack)),
+            ("special_defense", JsonValue::from(mon.stats.special_defense))
+        ])),
+        ("stat_stages", JsonValue::object([
+            ("attack", stage(stages.attack)),
+            ("defense", stage(stages.defense)),
+            ("speed", stage(stages.speed)),
+            ("special_attack", stage(stages.special_attack)),
+            ("special_defense", stage(stages.special_defense)),
+            ("accuracy", stage(stages.accuracy)),
+            ("evasion", stage(stages.evasion))
+        ]))
+    ])
+}
@@ -0,0 +1,374 @@
+//! Running the simulator behind a small HTTP server with `serve`, so web frontends and other machines can use it
+//! without the C API.
+//!
+//! There's one simulator at a time. `/` is a page for running it from a browser (see `dashboard.html`), and the rest
+//! is for that page and anything else to use. Request bodies are the raw file, and responses are JSON:
+//!
+//! - `PUT /rom` sets the ROM
+//! - `PUT /state` loads a save state with the ROM, stopping the last run (`?game=red` simulates it as another game)
+//! - `POST /start` starts simulating (`?trials=10000` stops after that many more, and `?jobs=4` uses that many threads)
+//! - `POST /stop` stops
+//! - `GET /status` gives whether it's running, the number of trials, and the trials per second
+//! - `GET /results` gives the results in the same format as the JSON status file
+//! - `GET /results/stream` sends the results as server-sent events every second until it stops
+//! - `GET /metrics` gives Prometheus metrics, if built with the `metrics` feature
+//!
+//! Each request gets its own connection, which is closed after the response. There's no authentication, so it only
+//! listens on the loopback address unless it's told otherwise, and there are no CORS headers. Browsers still send
+//! some requests from other sites without asking, so any with an `Origin` other than the server's are refused. A site
+//! can also point its own name at this server (DNS rebinding), so the `Host` has to be `localhost`, a loopback
+//! address, the address it was told to listen on, or the one the connection came in on.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::{Game, Simulator, TrialRate};
+use crate::report::Report;
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+/// Largest request body accepted, which is bigger than any Game Boy ROM
+const MAX_BODY_SIZE: usize = 16 << 20;
+
+/// Largest request line or header accepted
+const MAX_LINE_LENGTH: usize = 8 << 10;
+
+/// The page at `/`, which only uses the endpoints below so it works the same as any other client
+const DASHBOARD: &str = include_str!("dashboard.html");
+
+/// How often `/results/stream` sends the results
+const STREAM_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What every connection shares.
+#[derive(Default)]
+struct Server {
+    rom: Option<Arc<Vec<u8>>>,
+    simulator: Option<Simulator>,
+    rate: TrialRate,
+    jobs: Option<NonZeroUsize>
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    host: Option<String>,
+    origin: Option<String>,
+    body: Vec<u8>
+}
+
+impl Request {
+    fn query(&self, key: &str) -> Option<&str> {
+        self.query.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+}
+
+/// An error response.
+struct HttpError {
+    status: u16,
+    message: String
+}
+
+impl HttpError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+}
+
+pub fn serve(address: &str, rom_path: Option<&Path>, jobs: Option<NonZeroUsize>) {
+    let mut server = Server { jobs, ..Default::default() };
+    if let Some(path) = rom_path {
+        match std::fs::read(path) {
+            Ok(rom) => server.rom = Some(Arc::new(rom)),
+            Err(_) => {
+                eprintln!("Failed to read ROM {}", path.display());
+                return;
+            }
+        }
+    }
+    let listener = match TcpListener::bind(address) {
+        Ok(n) => n,
+        Err(e) => {
+            eprintln!("Failed to listen on {address}: {e}");
+            return;
+        }
+    };
+
+    println!("Listening on http://{address} - open it in a browser for the dashboard");
+    let server = Arc::new(Mutex::new(server));
+    let address: Arc<str> = address.into();
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let server = Arc::clone(&server);
+        let address = Arc::clone(&address);
+        std::thread::spawn(move || {
+            if let Err(e) = handle(stream, &server, &address) {
+                log::debug!("Connection failed: {e}");
+            }
+        });
+    }
+}
+
+fn handle(stream: TcpStream, server: &Mutex<Server>, address: &str) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let local = stream.local_addr()?;
+    let request = match read_request(&mut BufReader::new(stream)) {
+        Ok(request) => request,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+            return write_response(&mut writer, Err(HttpError::new(400, e.to_string())));
+        },
+        Err(e) => return Err(e)
+    };
+    log::info!("{} {}", request.method, request.path);
+
+    // another name for this server, which could be a page on another site that rebound its name to here
+    if !request.host.as_ref().is_some_and(|host| is_allowed_host(host, local, address)) {
+        let host = request.host.as_deref().unwrap_or("");
+        return write_response(&mut writer, Err(HttpError::new(403, format!("Requests to host \"{host}\" aren't allowed"))));
+    }
+
+    // a page on another site, which could otherwise start and stop runs
+    if let Some(origin) = &request.origin {
+        if request.host.as_ref().is_none_or(|host| *origin != format!("http://{host}")) {
+            return write_response(&mut writer, Err(HttpError::new(403, format!("Requests from {origin} aren't allowed"))));
+        }
+    }
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/") => return write_ok(&mut writer, "text/html; charset=utf-8", DASHBOARD),
+        ("GET", "/results/stream") => return stream_results(&mut writer, server),
+        _ => ()
+    }
+    #[cfg(feature = "metrics")]
+    if (request.method.as_str(), request.path.as_str()) == ("GET", "/metrics") {
+        return write_metrics(&mut writer, &mut server.lock().unwrap());
+    }
+    let response = respond(&request, &mut server.lock().unwrap());
+    write_response(&mut writer, response)
+}
+
+/// Get whether a `Host` header names this server: `localhost`, a loopback address, the address it was told to listen
+/// on, or the one the connection came in on, with the port it's listening on.
+fn is_allowed_host(host: &str, local: SocketAddr, address: &str) -> bool {
+    let (name, port) = split_host(host);
+    if port.unwrap_or("80").parse() != Ok(local.port()) {
+        return false;
+    }
+    name.eq_ignore_ascii_case("localhost")
+        || name.eq_ignore_ascii_case(split_host(address).0)
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback() || ip == local.ip())
+}
+
+/// Split a host into its name (without the brackets around an IPv6 address) and port.
+fn split_host(host: &str) -> (&str, Option<&str>) {
+    if let Some(rest) = host.strip_prefix('[') {
+        let (name, rest) = rest.split_once(']').unwrap_or((rest, ""));
+        return (name, rest.strip_prefix(':'));
+    }
+    match host.rsplit_once(':') {
+        Some((name, port)) => (name, Some(port)),
+        None => (host, None)
+    }
+}
+
+fn respond(request: &Request, server: &mut Server) -> Result<JsonValue, HttpError> {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("PUT", "/rom") => {
+            if let Some(simulator) = server.simulator.as_mut() {
+                simulator.stop();
+            }
+            server.simulator = None;
+            server.rom = Some(Arc::new(request.body.clone()));
+            Ok(JsonValue::object([("title", JsonValue::from(lorelei_simulator::rom_title(&request.body)))]))
+        },
+        ("PUT", "/state") => {
+            let rom = server.rom.clone().ok_or_else(|| HttpError::new(409, "No ROM; PUT one to /rom first"))?;
+            let game = match request.query("game") {
+                Some(name) => Some(Game::from_short_name(name).ok_or_else(|| HttpError::new(400, format!("Unknown game {name}")))?),
+                None => None
+            };
+            if let Some(simulator) = server.simulator.as_mut() {
+                simulator.stop();
+            }
+            server.simulator = None;
+            let simulator = Simulator::new_from_vec(rom.to_vec(), request.body.clone(), None, game)
+                .map_err(|e| HttpError::new(400, format!("Failed to load simulator: {e}")))?;
+            let json = JsonValue::object([
+                ("game", JsonValue::from(simulator.game_name())),
+                ("state_hash", JsonValue::from(format!("{:016x}", simulator.state_hash()))),
+                ("opponent", simulator.opponent().map(|o| JsonValue::from(o.to_string())).unwrap_or(JsonValue::Null))
+            ]);
+            server.simulator = Some(simulator);
+            Ok(json)
+        },
+        ("POST", "/start") => {
+            let trials = parse_query::<u64>(request, "trials")?.filter(|&t| t != 0);
+            let jobs = parse_query::<NonZeroUsize>(request, "jobs")?.or(server.jobs);
+            let simulator = loaded(server)?;
+            if simulator.is_running() {
+                return Err(HttpError::new(409, "Already running"));
+            }
+            simulator.set_trials(trials.map(|t| simulator.sample_count().saturating_add(t)));
+            simulator.start(jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap()));
+            server.rate = TrialRate::default();
+            Ok(JsonValue::object([("running", JsonValue::from(true))]))
+        },
+        ("POST", "/stop") => {
+            loaded(server)?.stop();
+            Ok(JsonValue::object([("running", JsonValue::from(false))]))
+        },
+        ("GET", "/status") => {
+            let simulator = server.simulator.as_ref().ok_or_else(|| HttpError::new(409, "No save state; PUT one to /state first"))?;
+            let (running, trials) = (simulator.is_running(), simulator.sample_count());
+            let rate = server.rate.update(trials);
+            Ok(JsonValue::object([
+                ("running", JsonValue::from(running)),
+                ("trials", JsonValue::from(trials)),
+                ("trials_per_second", rate.map(JsonValue::Number).unwrap_or(JsonValue::Null))
+            ]))
+        },
+        ("GET", "/results") => Ok(results_json(loaded(server)?)),
+        (_, "/rom" | "/state" | "/start" | "/stop" | "/status" | "/results" | "/results/stream") => {
+            Err(HttpError::new(405, format!("{} isn't allowed here", request.method)))
+        },
+        (_, path) => Err(HttpError::new(404, format!("Nothing at {path}")))
+    }
+}
+
+fn loaded(server: &mut Server) -> Result<&mut Simulator, HttpError> {
+    server.simulator.as_mut().ok_or_else(|| HttpError::new(409, "No save state; PUT one to /state first"))
+}
+
+fn parse_query<T: std::str::FromStr>(request: &Request, key: &str) -> Result<Option<T>, HttpError> {
+    request
+        .query(key)
+        .map(|value| value.parse().map_err(|_| HttpError::new(400, format!("{key} should be a number, not {value}"))))
+        .transpose()
+}
+
+fn results_json(simulator: &Simulator) -> JsonValue {
+    let elapsed = simulator.snapshot().elapsed;
+    crate::status::json(&Report::new(simulator), elapsed, simulator.is_running())
+}
+
+/// Send the results every [`STREAM_INTERVAL`] until the simulator stops (or is replaced), then once more.
+fn stream_results(writer: &mut TcpStream, server: &Mutex<Server>) -> std::io::Result<()> {
+    let headers = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n";
+    let state_hash = match server.lock().unwrap().simulator.as_ref() {
+        Some(simulator) => simulator.state_hash(),
+        None => return write_response(writer, Err(HttpError::new(409, "No save state; PUT one to /state first")))
+    };
+    writer.write_all(headers.as_bytes())?;
+    loop {
+        let (json, running) = match server.lock().unwrap().simulator.as_ref() {
+            Some(simulator) if simulator.state_hash() == state_hash => (results_json(simulator), simulator.is_running()),
+            _ => return Ok(())
+        };
+        writer.write_all(format!("data: {json}\n\n").as_bytes())?;
+        writer.flush()?;
+        if !running {
+            return Ok(());
+        }
+        std::thread::sleep(STREAM_INTERVAL);
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn write_metrics(writer: &mut impl Write, server: &mut Server) -> std::io::Result<()> {
+    let Some(simulator) = server.simulator.as_ref() else {
+        return write_response(writer, Err(HttpError::new(409, "No save state; PUT one to /state first")));
+    };
+    let rate = server.rate.update(simulator.sample_count());
+    write_ok(writer, "text/plain; version=0.0.4", &crate::metrics::write(simulator, rate))
+}
+
+fn read_request(reader: &mut impl BufRead) -> std::io::Result<Request> {
+    let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_owned());
+
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(invalid("Bad request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (key.to_owned(), value.to_owned())
+        })
+        .collect();
+
+    let (mut content_length, mut host, mut origin) = (0, None, None);
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(invalid("Bad header"));
+        };
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse().map_err(|_| invalid("Bad Content-Length"))?;
+        }
+        else if name.eq_ignore_ascii_case("host") {
+            host = Some(value.trim().to_owned());
+        }
+        else if name.eq_ignore_ascii_case("origin") {
+            origin = Some(value.trim().to_owned());
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(invalid("Body is too big"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request { method: method.to_owned(), path: path.to_owned(), query, host, origin, body })
+}
+
+/// Read a line without its line ending.
+fn read_line(reader: &mut impl BufRead) -> std::io::Result<String> {
+    let mut line = Vec::new();
+    reader.by_ref().take(MAX_LINE_LENGTH as u64).read_until(b'\n', &mut line)?;
+    if line.last() != Some(&b'\n') {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Line is too long or the connection was closed"));
+    }
+    let line = String::from_utf8_lossy(&line);
+    Ok(line.trim_end_matches(['\r', '\n']).to_owned())
+}
+
+/// Write a successful response that isn't JSON.
+fn write_ok(writer: &mut impl Write, content_type: &str, body: &str) -> std::io::Result<()> {
+    write!(writer, "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())?;
+    writer.flush()
+}
+
+fn write_response(writer: &mut impl Write, response: Result<JsonValue, HttpError>) -> std::io::Result<()> {
+    let (status, body) = match response {
+        Ok(json) => (200, json),
+        Err(error) => (error.status, JsonValue::object([("error", JsonValue::from(error.message))]))
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Conflict"
+    };
+    let body = body.to_string();
+    write!(
+        writer,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    writer.flush()
+}
@@ -0,0 +1,40 @@
+//! A small file with the current results, rewritten while running for stream overlays (e.g. an OBS text source).
+
+use std::path::Path;
+use std::time::Duration;
+use lorelei_simulator::json::JsonValue;
+use crate::report::Report;
+
+/// Write the status file.
+///
+/// It's JSON if the file name ends in `.json`, and plain text otherwise. The file is replaced all at once, so
+/// anything reading it never sees it half written.
+pub fn write(path: &Path, report: &Report, elapsed: Duration, running: bool) -> std::io::Result<()> {
+    let is_json = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("json"));
+    let contents = if is_json { json(report, elapsed, running).to_string() } else { text(report, running) };
+
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    std::fs::write(&temporary, contents)?;
+    std::fs::rename(&temporary, path)
+}
+
+fn text(report: &Report, running: bool) -> String {
+    let mut text = String::new();
+    for row in &report.rows {
+        text += &format!("{} {:.1}%\n", row.name, 100.0 * row.fraction(report.total));
+    }
+    let s = if report.total == 1 { "" } else { "s" };
+    text += &format!("{} trial{s}{}\n", report.total, if running { "" } else { " (finished)" });
+    text
+}
+
+/// The usual JSON report, plus how long it's been running and whether it still is.
+pub fn json(report: &Report, elapsed: Duration, running: bool) -> JsonValue {
+    let mut json = report.to_json();
+    if let JsonValue::Object(entries) = &mut json {
+        entries.push(("elapsed_seconds".to_owned(), JsonValue::Number(elapsed.as_secs_f64())));
+        entries.push(("running".to_owned(), JsonValue::from(running)));
+    }
+    json
+}
@@ -0,0 +1,231 @@
+//! Simulating a save state with every combination of changes from a sweep file.
+//!
+//! A sweep file is TOML with an `[[axis]]` table for each thing to change:
+//!
+//! ```toml
+//! trials = 5000
+//!
+//! [[axis]]
+//! patch = "enemy_hp"
+//! values = [100, 50, 25]
+//!
+//! [[axis]]
+//! patch = "player_status"
+//! values = ["none", "PAR"]
+//! ```
+
+use std::io::{BufWriter, stdout, Write};
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use lorelei_simulator::battle::{BattlePatch, Side};
+use lorelei_simulator::input::InputStrategy;
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::move_id_from_name;
+use crate::batch::{run_to_completion, write_matrix};
+use crate::report::{Report, ReportFormat};
+use crate::{toml, GameArgs, InputArgs, StateSource};
+
+/// Most combinations a sweep can have, to catch typos that would take days to simulate
+const MAX_COMBINATIONS: usize = 1000;
+
+const DEFAULT_TRIALS: u64 = 10000;
+
+/// Turns one of an axis's values into the patch for it
+type ValueParser = Box<dyn Fn(&JsonValue) -> Option<BattlePatch>>;
+
+pub struct SweepArgs {
+    pub rom: PathBuf,
+    pub save_state: PathBuf,
+    pub file: PathBuf,
+    pub trials: Option<NonZeroU64>,
+    pub jobs: Option<NonZeroUsize>,
+    pub mash: Option<InputStrategy>,
+    pub overrides: GameArgs,
+    pub format: ReportFormat
+}
+
+/// Something to change, and what to change it to
+struct Axis {
+    name: String,
+    values: Vec<(String, BattlePatch)>
+}
+
+pub fn sweep(args: SweepArgs) {
+    let (axes, file_trials) = match read_sweep(&args.file) {
+        Ok(sweep) => sweep,
+        Err(e) => {
+            eprintln!("Failed to read {}: {e}", args.file.display());
+            return;
+        }
+    };
+    let combinations: usize = axes.iter().map(|axis| axis.values.len()).product();
+    if combinations > MAX_COMBINATIONS {
+        eprintln!("The sweep has {combinations} combinations, which is more than the limit of {MAX_COMBINATIONS}");
+        return;
+    }
+    let trials = args.trials.or(file_trials).unwrap_or(NonZeroU64::new(DEFAULT_TRIALS).unwrap());
+
+    let input = InputArgs {
+        rom: Some(args.rom.clone()),
+        save_state: None,
+        slot: None,
+        battery: None,
+        input_script: None,
+        warm_state: None,
+        mash: args.mash.clone(),
+        overrides: args.overrides.clone()
+    };
+    let thread_count = args.jobs.unwrap_or_else(|| std::thread::available_parallelism().unwrap());
+
+    let bail = Arc::new(AtomicBool::new(false));
+    let bail_copy = bail.clone();
+    let _ = ctrlc::set_handler(move || { bail_copy.swap(true, Ordering::Relaxed); } );
+
+    let mut reports = Vec::with_capacity(combinations);
+    for combination in 0..combinations {
+        if bail.load(Ordering::Relaxed) {
+            break;
+        }
+
+        // Count through the combinations with the last axis changing fastest.
+        let mut remaining = combination;
+        let mut chosen = Vec::with_capacity(axes.len());
+        for axis in axes.iter().rev() {
+            chosen.push((axis, &axis.values[remaining % axis.values.len()]));
+            remaining /= axis.values.len();
+        }
+        chosen.reverse();
+        let label = chosen.iter().map(|(axis, (value, _))| format!("{} {value}", axis.name)).collect::<Vec<_>>().join(", ");
+        let patches: Vec<BattlePatch> = chosen.iter().map(|(_, &(_, patch))| patch).collect();
+
+        let mut simulator = match input.open(&args.rom, &StateSource::SaveState(args.save_state.clone()), Some(trials.get())) {
+            Ok(simulator) => simulator,
+            Err(e) => {
+                eprintln!("{e}");
+                return;
+            }
+        };
+        if !simulator.patch_battle(&patches) {
+            eprintln!("The save state is not in a battle");
+            return;
+        }
+
+        let progress = format!("[{}/{combinations}] {label}", combination + 1);
+        run_to_completion(&mut simulator, thread_count, &bail, &progress, trials);
+
+        let report = Report::new(&simulator);
+        let mut writer = BufWriter::new(stdout().lock());
+        if args.format == ReportFormat::Json {
+            // one line per combination, like batch
+            let mut json = report.to_json();
+            if let JsonValue::Object(entries) = &mut json {
+                let values = chosen.iter().map(|(axis, (value, _))| (axis.name.clone(), JsonValue::from(value.as_str())));
+                entries.insert(0, ("sweep".to_owned(), JsonValue::object(values)));
+            }
+            let _ = writeln!(writer, "{json}");
+        }
+        else {
+            let _ = writeln!(writer, "{label}: {} trials", report.total);
+        }
+        drop(writer);
+        reports.push((label, report));
+    }
+
+    if args.format != ReportFormat::Json && !reports.is_empty() {
+        let labels: Vec<(String, &Report)> = reports.iter().map(|(label, report)| (label.clone(), report)).collect();
+        let mut writer = BufWriter::new(stdout().lock());
+        let _ = writeln!(writer);
+        let _ = write_matrix(&mut writer, "sweep", &labels, args.format);
+    }
+}
+
+/// Read the axes and trial count from a sweep file.
+fn read_sweep(path: &Path) -> Result<(Vec<Axis>, Option<NonZeroU64>), String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let sweep = toml::parse(&text).map_err(|e| e.to_string())?;
+
+    let trials = match sweep.get("trials") {
+        Some(trials) => Some(trials.as_u64().and_then(NonZeroU64::new).ok_or("trials should be a number above 0")?),
+        None => None
+    };
+
+    let axes = sweep.get("axis").and_then(JsonValue::as_array).ok_or("there should be at least one [[axis]]")?;
+    let axes = axes.iter().enumerate().map(|(i, axis)| read_axis(axis).map_err(|e| format!("axis {}: {e}", i + 1))).collect::<Result<Vec<_>, _>>()?;
+    if axes.is_empty() {
+        return Err("there should be at least one [[axis]]".to_owned());
+    }
+    Ok((axes, trials))
+}
+
+fn read_axis(axis: &JsonValue) -> Result<Axis, String> {
+    let patch = axis.get("patch").and_then(JsonValue::as_str).ok_or("patch should be a string, e.g. \"enemy_hp\"")?;
+    let values = axis.get("values").and_then(JsonValue::as_array).filter(|v| !v.is_empty()).ok_or("values should be a list with at least one value")?;
+
+    let (side, what) = match patch.split_once('_') {
+        Some(("player", what)) => (Some(Side::Player), what),
+        Some(("enemy", what)) => (Some(Side::Enemy), what),
+        _ => (None, patch)
+    };
+
+    let make: ValueParser = match (side, what) {
+        (Some(side), "hp") => Box::new(move |value| {
+            let percent = value.as_u64().filter(|&p| p <= 100)? as u8;
+            Some(BattlePatch::HpPercent { side, percent })
+        }),
+        (Some(side), "status") => Box::new(move |value| Some(BattlePatch::Status { side, status: parse_status(value)? })),
+        (Some(side), what) if what.starts_with("move") => {
+            let slot = what["move".len()..].parse::<u8>().ok().filter(|s| (1..=4).contains(s)).ok_or("moves are move1 to move4")?;
+            Box::new(move |value| {
+                let move_index = match value {
+                    JsonValue::String(name) => move_id_from_name(name)?,
+                    value => u8::try_from(value.as_u64()?).ok()?
+                };
+                Some(BattlePatch::Move { side, slot: slot - 1, move_index })
+            })
+        },
+        (None, "memory") => {
+            let address = axis.get("address")
+                .and_then(JsonValue::as_u64)
+                .and_then(|a| u16::try_from(a).ok())
+                .ok_or("memory needs an address, e.g. address = 0xCFE6")?;
+            Box::new(move |value| Some(BattlePatch::Memory { address, value: u8::try_from(value.as_u64()?).ok()? }))
+        },
+        _ => return Err(format!("{patch} isn't something that can be changed (try enemy_hp, player_status, player_move1, or memory)"))
+    };
+
+    let values = values.iter().map(|value| {
+        let label = match value {
+            JsonValue::String(s) => s.clone(),
+            value => value.to_string()
+        };
+        let patch = make(value).ok_or_else(|| format!("{label} isn't a valid value for {patch}"))?;
+        let label = if what == "hp" { format!("{label}%") } else { label };
+        Ok((label, patch))
+    }).collect::<Result<Vec<_>, String>>()?;
+
+    let name = match axis.get("name") {
+        Some(name) => name.as_str().ok_or("name should be a string")?.to_owned(),
+        None => patch.to_owned()
+    };
+    Ok(Axis { name, values })
+}
+
+/// Parse a status as its in-game abbreviation (e.g. PAR, or SLP3 for 3 turns of sleep), none, or a raw byte.
+fn parse_status(value: &JsonValue) -> Option<u8> {
+    let JsonValue::String(name) = value else {
+        return u8::try_from(value.as_u64()?).ok();
+    };
+    let name = name.to_ascii_uppercase();
+    match name.as_str() {
+        "NONE" => Some(0),
+        "PSN" => Some(1 << 3),
+        "BRN" => Some(1 << 4),
+        "FRZ" => Some(1 << 5),
+        "PAR" => Some(1 << 6),
+        // sleeping for 3 turns unless given
+        "SLP" => Some(3),
+        _ => name.strip_prefix("SLP")?.parse::<u8>().ok().filter(|t| (1..=7).contains(t))
+    }
+}
@@ -0,0 +1,461 @@
+//! Reading the parts of TOML used by sweep and config files.
+//!
+//! Values are read into [`JsonValue`]s, with tables as objects. Dates and times, multi-line strings, and dotted keys
+//! (other than in headers) aren't supported.
+
+use std::fmt::{Display, Formatter};
+use lorelei_simulator::json::JsonValue;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TomlError {
+    /// Line the error is on, starting from 1
+    pub line: usize,
+    pub message: String
+}
+
+impl Display for TomlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parse a TOML document into an object.
+pub fn parse(text: &str) -> Result<JsonValue, TomlError> {
+    let mut root = JsonValue::Object(Vec::new());
+
+    // The keys of the table key/value pairs go in (see `resolve`), from `[table]` and `[[array of tables]]` headers
+    let mut current: Vec<String> = Vec::new();
+
+    let mut parser = Parser { text, position: 0, line: 1 };
+    loop {
+        parser.skip_blank();
+        let Some(c) = parser.peek() else {
+            return Ok(root);
+        };
+
+        if c == '[' {
+            parser.position += 1;
+            let array = parser.eat('[');
+            let mut path = Vec::new();
+            loop {
+                parser.skip_spaces();
+                path.push(parser.key_part()?);
+                parser.skip_spaces();
+                if !parser.eat('.') {
+                    break;
+                }
+            }
+            if !parser.eat(']') || (array && !parser.eat(']')) {
+                return Err(parser.error("expected ']'"));
+            }
+            add_table(&mut root, &path, array).map_err(|message| parser.error(message))?;
+            current = path;
+        }
+        else {
+            let key = parser.key()?;
+            parser.skip_spaces();
+            if !parser.eat('=') {
+                return Err(parser.error("expected '='"));
+            }
+            parser.skip_spaces();
+            let value = parser.value()?;
+            let JsonValue::Object(entries) = resolve(&mut root, &current) else {
+                unreachable!("tables are always objects");
+            };
+            if entries.iter().any(|(k, _)| *k == key) {
+                return Err(parser.error(format!("{key} is given more than once")));
+            }
+            entries.push((key, value));
+        }
+        parser.end_of_line()?;
+    }
+}
+
+/// Make the table a header refers to if it doesn't exist. For arrays of tables, a new table is always added.
+fn add_table(root: &mut JsonValue, path: &[String], array: bool) -> Result<(), &'static str> {
+    let (key, parents) = path.split_last().unwrap();
+    let mut table = root;
+    for parent in parents {
+        table = match table_entry(table, parent, || JsonValue::Object(Vec::new()))? {
+            JsonValue::Array(tables) => tables.last_mut().filter(|t| matches!(t, JsonValue::Object(_))).ok_or("this key is already used for something else")?,
+            table @ JsonValue::Object(_) => table,
+            _ => return Err("this key is already used for something else")
+        };
+    }
+    let new = || if array { JsonValue::Array(Vec::new()) } else { JsonValue::Object(Vec::new()) };
+    match (table_entry(table, key, new)?, array) {
+        (JsonValue::Array(tables), true) => {
+            tables.push(JsonValue::Object(Vec::new()));
+            Ok(())
+        },
+        (JsonValue::Object(_), false) => Ok(()),
+        _ => Err("this key is already used for something else")
+    }
+}
+
+/// Get the value of `key` in `table`, adding it with `new` if it isn't there.
+fn table_entry<'a>(table: &'a mut JsonValue, key: &str, new: impl FnOnce() -> JsonValue) -> Result<&'a mut JsonValue, &'static str> {
+    let JsonValue::Object(entries) = table else {
+        return Err("this key is already used for something else");
+    };
+    let index = match entries.iter().position(|(k, _)| k == key) {
+        Some(index) => index,
+        None => {
+            entries.push((key.to_owned(), new()));
+            entries.len() - 1
+        }
+    };
+    Ok(&mut entries[index].1)
+}
+
+/// Get the table a header's keys refer to, where an array of tables refers to its last table.
+fn resolve<'a>(root: &'a mut JsonValue, path: &[String]) -> &'a mut JsonValue {
+    let mut value = root;
+    for key in path {
+        let JsonValue::Object(entries) = value else {
+            unreachable!("headers only refer to tables");
+        };
+        value = match &mut entries.iter_mut().find(|(k, _)| k == key).unwrap().1 {
+            JsonValue::Array(tables) => tables.last_mut().unwrap(),
+            table => table
+        };
+    }
+    value
+}
+
+struct Parser<'a> {
+    text: &'a str,
+
+    /// Position in bytes
+    position: usize,
+    line: usize
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> TomlError {
+        TomlError { line: self.line, message: message.into() }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    /// Consume `expected` if it's next.
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.position += expected.len_utf8();
+        }
+        found
+    }
+
+    fn skip_spaces(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.position += 1;
+        }
+    }
+
+    /// Skip whitespace, newlines, and comments.
+    fn skip_blank(&mut self) {
+        loop {
+            match self.peek() {
+                Some(' ' | '\t' | '\r') => self.position += 1,
+                Some('\n') => {
+                    self.position += 1;
+                    self.line += 1;
+                },
+                Some('#') => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.position += self.peek().unwrap().len_utf8();
+                    }
+                },
+                _ => return
+            }
+        }
+    }
+
+    /// Make sure nothing but a comment is left on the line.
+    fn end_of_line(&mut self) -> Result<(), TomlError> {
+        self.skip_spaces();
+        match self.peek() {
+            None | Some('\n' | '#') => Ok(()),
+            Some('\r') if self.text[self.position..].starts_with("\r\n") => Ok(()),
+            Some(_) => Err(self.error("expected the end of the line"))
+        }
+    }
+
+    /// Read a key, or one part of a dotted one.
+    fn key_part(&mut self) -> Result<String, TomlError> {
+        match self.peek() {
+            Some('"') => self.basic_string(),
+            Some('\'') => self.literal_string(),
+            _ => {
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                    self.position += 1;
+                }
+                if start == self.position {
+                    return Err(self.error("expected a key"));
+                }
+                Ok(self.text[start..self.position].to_owned())
+            }
+        }
+    }
+
+    /// Read a key that can't be dotted, i.e. one that isn't in a header.
+    fn key(&mut self) -> Result<String, TomlError> {
+        let key = self.key_part()?;
+        self.skip_spaces();
+        if self.peek() == Some('.') {
+            return Err(self.error("dotted keys aren't supported"));
+        }
+        Ok(key)
+    }
+
+    fn value(&mut self) -> Result<JsonValue, TomlError> {
+        match self.peek() {
+            Some('"') => self.basic_string().map(JsonValue::String),
+            Some('\'') => self.literal_string().map(JsonValue::String),
+            Some('[') => {
+                self.position += 1;
+                let mut values = Vec::new();
+                loop {
+                    self.skip_blank();
+                    if self.eat(']') {
+                        return Ok(JsonValue::Array(values));
+                    }
+                    values.push(self.value()?);
+                    self.skip_blank();
+                    if !self.eat(',') {
+                        self.skip_blank();
+                        if self.eat(']') {
+                            return Ok(JsonValue::Array(values));
+                        }
+                        return Err(self.error("expected ',' or ']'"));
+                    }
+                }
+            },
+            Some('{') => {
+                self.position += 1;
+                let mut entries: Vec<(String, JsonValue)> = Vec::new();
+                self.skip_spaces();
+                if self.eat('}') {
+                    return Ok(JsonValue::Object(entries));
+                }
+                loop {
+                    self.skip_spaces();
+                    let key = self.key()?;
+                    self.skip_spaces();
+                    if !self.eat('=') {
+                        return Err(self.error("expected '='"));
+                    }
+                    self.skip_spaces();
+                    let value = self.value()?;
+                    if entries.iter().any(|(k, _)| *k == key) {
+                        return Err(self.error(format!("{key} is given more than once")));
+                    }
+                    entries.push((key, value));
+                    self.skip_spaces();
+                    if self.eat('}') {
+                        return Ok(JsonValue::Object(entries));
+                    }
+                    if !self.eat(',') {
+                        return Err(self.error("expected ',' or '}'"));
+                    }
+                }
+            },
+            _ => {
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || "+-._".contains(c)) {
+                    self.position += 1;
+                }
+                let word = &self.text[start..self.position];
+                match word {
+                    "true" => Ok(JsonValue::Bool(true)),
+                    "false" => Ok(JsonValue::Bool(false)),
+                    "" => Err(self.error("expected a value")),
+                    _ => parse_number(word).map(JsonValue::Number).ok_or_else(|| self.error(format!("{word} isn't a valid value")))
+                }
+            }
+        }
+    }
+
+    fn basic_string(&mut self) -> Result<String, TomlError> {
+        self.position += 1;
+        let mut string = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.position += c.len_utf8();
+            match c {
+                '"' => return Ok(string),
+                '\n' => return Err(self.error("unterminated string")),
+                '\\' => {
+                    let escape = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+                    self.position += escape.len_utf8();
+                    string.push(match escape {
+                        '"' => '"',
+                        '\\' => '\\',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' | 'U' => {
+                            let length = if escape == 'u' { 4 } else { 8 };
+                            let code = self.text.get(self.position..self.position + length)
+                                .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid escape"))?;
+                            self.position += length;
+                            code
+                        },
+                        _ => return Err(self.error("invalid escape"))
+                    });
+                },
+                c => string.push(c)
+            }
+        }
+    }
+
+    fn literal_string(&mut self) -> Result<String, TomlError> {
+        self.position += 1;
+        let rest = &self.text[self.position..];
+        let end = rest.find(['\'', '\n']).filter(|&end| rest.as_bytes()[end] == b'\'').ok_or_else(|| self.error("unterminated string"))?;
+        self.position += end + 1;
+        Ok(rest[..end].to_owned())
+    }
+}
+
+/// Parse an integer (decimal, or hexadecimal, octal, or binary with a prefix) or a float, with `_` between digits.
+fn parse_number(word: &str) -> Option<f64> {
+    let word = word.replace('_', "");
+    let (sign, digits) = match word.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, word.strip_prefix('+').unwrap_or(&word))
+    };
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(digits) = digits.strip_prefix(prefix) {
+            return u64::from_str_radix(digits, radix).ok().map(|n| sign * n as f64);
+        }
+    }
+    match digits {
+        "inf" => Some(sign * f64::INFINITY),
+        "nan" => Some(f64::NAN),
+        _ if digits.starts_with(|c: char| c.is_ascii_digit()) => digits.parse::<f64>().ok().map(|n| sign * n),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(text: &str) -> (usize, String) {
+        let error = parse(text).unwrap_err();
+        (error.line, error.message)
+    }
+
+    #[test]
+    fn parses_tables() {
+        let document = parse("top = 1\n\n[a]\nx = true # comment\n\n[a.b]\ny = 'z'\n\n[c]\n").unwrap();
+        assert_eq!(document, JsonValue::object([
+            ("top", JsonValue::Number(1.0)),
+            ("a", JsonValue::object([
+                ("x", JsonValue::Bool(true)),
+                ("b", JsonValue::object([("y", JsonValue::from("z"))]))
+            ])),
+            ("c", JsonValue::Object(Vec::new()))
+        ]));
+    }
+
+    #[test]
+    fn parses_arrays_of_tables() {
+        let document = parse("[[run]]\nname = \"a\"\n[run.extra]\nn = 1\n\n[[run]]\nname = \"b\"\n").unwrap();
+        assert_eq!(document, JsonValue::object([
+            ("run", JsonValue::Array(vec![
+                JsonValue::object([("name", JsonValue::from("a")), ("extra", JsonValue::object([("n", JsonValue::Number(1.0))]))]),
+                JsonValue::object([("name", JsonValue::from("b"))])
+            ]))
+        ]));
+    }
+
+    #[test]
+    fn parses_arrays_and_inline_tables() {
+        let document = parse("a = [1, 2, 3]\nb = [\n  \"x\", # first\n  [true, false],\n]\nc = { d = 1, \"e f\" = {} }\nd = []\n").unwrap();
+        assert_eq!(document, JsonValue::object([
+            ("a", JsonValue::from(vec![1u8, 2, 3])),
+            ("b", JsonValue::Array(vec![JsonValue::from("x"), JsonValue::Array(vec![JsonValue::Bool(true), JsonValue::Bool(false)])])),
+            ("c", JsonValue::object([("d", JsonValue::Number(1.0)), ("e f", JsonValue::Object(Vec::new()))])),
+            ("d", JsonValue::Array(Vec::new()))
+        ]));
+    }
+
+    #[test]
+    fn parses_strings() {
+        let document = parse(r#"a = "q\"b\\t\tn\nu\u00e9U\U0001F600"
+b = 'C:\no\escapes'
+"quoted key" = ""
+"#).unwrap();
+        assert_eq!(document, JsonValue::object([
+            ("a", JsonValue::from("q\"b\\t\tn\nu\u{e9}U\u{1F600}")),
+            ("b", JsonValue::from(r"C:\no\escapes")),
+            ("quoted key", JsonValue::from(""))
+        ]));
+    }
+
+    #[test]
+    fn parses_numbers() {
+        let document = parse("a = 1_000\nb = -0x1f\nc = 0o17\nd = 0b101\ne = +1.5e3\nf = -inf\n").unwrap();
+        assert_eq!(document, JsonValue::object([
+            ("a", JsonValue::Number(1000.0)),
+            ("b", JsonValue::Number(-31.0)),
+            ("c", JsonValue::Number(15.0)),
+            ("d", JsonValue::Number(5.0)),
+            ("e", JsonValue::Number(1500.0)),
+            ("f", JsonValue::Number(f64::NEG_INFINITY))
+        ]));
+        assert!(parse("a = nan").unwrap().get("a").and_then(JsonValue::as_f64).unwrap().is_nan());
+    }
+
+    #[test]
+    fn parses_crlf() {
+        assert_eq!(parse("a = 1\r\n[b]\r\nc = 2\r\n").unwrap(), JsonValue::object([
+            ("a", JsonValue::Number(1.0)),
+            ("b", JsonValue::object([("c", JsonValue::Number(2.0))]))
+        ]));
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(parse_error("a = 1\nb 2"), (2, "expected '='".to_owned()));
+        assert_eq!(parse_error("a = 1\n\n= 2"), (3, "expected a key".to_owned()));
+        assert_eq!(parse_error("a = 1 2"), (1, "expected the end of the line".to_owned()));
+        assert_eq!(parse_error("a = 1\na = 2"), (2, "a is given more than once".to_owned()));
+        assert_eq!(parse_error("a = { b = 1, b = 2 }"), (1, "b is given more than once".to_owned()));
+        assert_eq!(parse_error("a = yes"), (1, "yes isn't a valid value".to_owned()));
+        assert_eq!(parse_error("a ="), (1, "expected a value".to_owned()));
+        assert_eq!(parse_error("a = [1,\n2\n3]"), (3, "expected ',' or ']'".to_owned()));
+        assert_eq!(parse_error("a = { b = 1 c = 2 }"), (1, "expected ',' or '}'".to_owned()));
+        assert_eq!(parse_error("[a\nb = 1"), (1, "expected ']'".to_owned()));
+        assert_eq!(parse_error("a.b = 1"), (1, "dotted keys aren't supported".to_owned()));
+    }
+
+    #[test]
+    fn reports_string_errors() {
+        assert_eq!(parse_error("a = \"abc\nb = 1"), (1, "unterminated string".to_owned()));
+        assert_eq!(parse_error("a = 'abc"), (1, "unterminated string".to_owned()));
+        assert_eq!(parse_error(r#"a = "\q""#), (1, "invalid escape".to_owned()));
+        assert_eq!(parse_error(r#"a = "\uD800""#), (1, "invalid escape".to_owned()));
+        assert_eq!(parse_error(r#"a = "\u12""#), (1, "invalid escape".to_owned()));
+    }
+
+    #[test]
+    fn reports_conflicting_tables() {
+        assert_eq!(parse_error("a = 1\n[a]"), (2, "this key is already used for something else".to_owned()));
+        assert_eq!(parse_error("[a]\n[[a]]"), (2, "this key is already used for something else".to_owned()));
+        assert_eq!(parse_error("[[a]]\n[a]"), (2, "this key is already used for something else".to_owned()));
+        assert_eq!(parse_error("a = 1\n[a.b]"), (2, "this key is already used for something else".to_owned()));
+    }
+}
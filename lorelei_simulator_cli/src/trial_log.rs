@@ -0,0 +1,47 @@
+//! Logging every trial as a line of JSON (NDJSON), for other programs to analyze.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::UNIX_EPOCH;
+use lorelei_simulator::json::JsonValue;
+use lorelei_simulator::{Simulator, Trial};
+use lorelei_simulator::report::display_name;
+use crate::report::kind_and_index;
+
+pub struct TrialLog {
+    writer: BufWriter<File>,
+    trials: Receiver<Trial>,
+    tag: String,
+    count: u64
+}
+
+impl TrialLog {
+    /// Create the log file, returning the log and the listener to give the simulator.
+    pub fn create(path: &Path, tag: String) -> std::io::Result<(Self, Sender<Trial>)> {
+        let writer = BufWriter::new(File::create(path)?);
+        let (listener, trials) = channel();
+        Ok((Self { writer, trials, tag, count: 0 }, listener))
+    }
+
+    /// Write every trial that finished since the last call, and flush them to the file.
+    pub fn write_pending(&mut self, simulator: &Simulator) -> std::io::Result<()> {
+        while let Ok(trial) = self.trials.try_recv() {
+            self.count += 1;
+            let (kind, index) = kind_and_index(trial.outcome);
+            let timestamp = trial.finished.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as f64;
+            let line = JsonValue::object([
+                ("trial", JsonValue::from(self.count)),
+                ("scenario", JsonValue::from(self.tag.as_str())),
+                ("kind", JsonValue::from(kind)),
+                ("index", JsonValue::from(index)),
+                ("name", JsonValue::from(display_name(simulator, trial.outcome).into_owned())),
+                ("frames", JsonValue::from(trial.frames)),
+                ("unix_time_ms", JsonValue::Number(timestamp))
+            ]);
+            writeln!(self.writer, "{line}")?;
+        }
+        self.writer.flush()
+    }
+}
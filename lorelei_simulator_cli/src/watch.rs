@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// File extensions emulators use for save states.
+///
+/// A trailing `#` is a slot number.
+const SAVE_STATE_EXTENSIONS: &[&str] = &["s#", "ss#", "sn#", "gqs", "state", "state#"];
+
+/// Polls a file for changes.
+///
+/// If the path is a directory, this watches the newest save state in it instead.
+pub struct FileWatcher {
+    path: PathBuf,
+    modified: Option<(PathBuf, SystemTime)>
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> Self {
+        Self { path: path.to_owned(), modified: modified(path) }
+    }
+
+    /// Check if the file was changed since the last time this was called.
+    ///
+    /// Emulators don't always write save states all at once, so this waits for the file to stop changing before
+    /// returning `true`. It returns `false` while the file doesn't exist.
+    pub fn changed(&mut self) -> bool {
+        let mut current = modified(&self.path);
+        if current == self.modified {
+            return false;
+        }
+        loop {
+            std::thread::sleep(Duration::from_millis(100));
+            let again = modified(&self.path);
+            if again == current {
+                break;
+            }
+            current = again;
+        }
+        self.modified = current;
+        self.modified.is_some()
+    }
+}
+
+/// Find the most recently modified save state in a directory.
+pub fn newest_save_state(directory: &Path) -> Option<(PathBuf, SystemTime)> {
+    std::fs::read_dir(directory)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| is_save_state_name(path))
+        .filter_map(|path| modified(&path))
+        .max_by_key(|&(_, modified)| modified)
+}
+
+/// Get every save state in a directory, sorted by name.
+pub fn save_states_in(directory: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let mut states: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_save_state_name(path))
+        .collect();
+    states.sort();
+    states
+}
+
+fn is_save_state_name(path: &Path) -> bool {
+    let Some(extension) = path.extension().and_then(|e| e.to_str()) else {
+        return false;
+    };
+    let extension = extension.to_ascii_lowercase();
+    let without_slot = extension.strip_suffix(|c: char| c.is_ascii_digit());
+    SAVE_STATE_EXTENSIONS.iter().any(|&known| match known.strip_suffix('#') {
+        Some(known) => without_slot == Some(known),
+        None => extension == known
+    })
+}
+
+fn modified(path: &Path) -> Option<(PathBuf, SystemTime)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if metadata.is_dir() {
+        return newest_save_state(path);
+    }
+    Some((path.to_owned(), metadata.modified().ok()?))
+}
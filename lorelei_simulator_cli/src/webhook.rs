@@ -0,0 +1,104 @@
+//! Posting the results to a Discord webhook with `--webhook`, which is how they usually get shared.
+//!
+//! This uses curl, which comes with Windows, macOS, and most Linux distributions, so no HTTPS client has to be built
+//! in.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::thread::JoinHandle;
+use lorelei_simulator::json::JsonValue;
+use crate::report::{Report, ReportFormat};
+
+/// Most characters Discord allows in a message
+const MAX_MESSAGE_LENGTH: usize = 2000;
+
+/// Give up on a post after this many seconds rather than holding up the exit.
+const TIMEOUT_SECONDS: &str = "15";
+
+/// Most posts that can wait to be sent before new ones are dropped
+const QUEUE_LENGTH: usize = 4;
+
+/// Posts messages to a webhook on a background thread, so a slow endpoint doesn't hold up the progress output or
+/// the stop rules.
+///
+/// Dropping this waits for the posts that are still queued.
+pub struct Webhook {
+    sender: Option<SyncSender<String>>,
+    thread: Option<JoinHandle<()>>
+}
+
+impl Webhook {
+    pub fn new(url: String) -> Self {
+        let (sender, receiver) = sync_channel::<String>(QUEUE_LENGTH);
+        let thread = std::thread::spawn(move || {
+            for message in receiver {
+                post(&url, &message);
+            }
+        });
+        Self { sender: Some(sender), thread: Some(thread) }
+    }
+
+    /// Queue a message, skipping it if the endpoint hasn't kept up with the ones before it.
+    pub fn post(&self, message: String) {
+        if let Err(TrySendError::Full(_)) = self.sender.as_ref().unwrap().try_send(message) {
+            eprintln!("Skipped a webhook post since the previous ones haven't gone through yet");
+        }
+    }
+
+    /// Queue a last message, waiting for room if needed, and wait for everything queued to be posted.
+    pub fn finish(self, message: String) {
+        let _ = self.sender.as_ref().unwrap().send(message);
+    }
+}
+
+impl Drop for Webhook {
+    fn drop(&mut self) {
+        // closing the channel ends the thread once it has posted what's left
+        self.sender = None;
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Get the message for `heading` and the results table.
+pub fn report_message(heading: &str, report: &Report) -> String {
+    let mut table = Vec::new();
+    let _ = report.write(&mut table, ReportFormat::Table);
+    let table = String::from_utf8_lossy(&table);
+
+    // the table is in a code block so its columns line up
+    let mut message = format!("{heading}\n```\n{}\n```", table.trim_end());
+    if message.chars().count() > MAX_MESSAGE_LENGTH {
+        message = heading.chars().take(MAX_MESSAGE_LENGTH).collect();
+    }
+    message
+}
+
+/// Post a message to the webhook at `url`, printing an error if it fails.
+fn post(url: &str, message: &str) {
+    let body = JsonValue::object([("content", JsonValue::from(message))]).to_string();
+    let child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--max-time", TIMEOUT_SECONDS])
+        .args(["--header", "Content-Type: application/json", "--data-binary", "@-", "--", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("Failed to post to the webhook: couldn't run curl: {e}");
+            return;
+        }
+    };
+
+    // dropping stdin closes it so curl knows the body is done
+    let _ = child.stdin.take().unwrap().write_all(body.as_bytes());
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => (),
+        Ok(output) => eprintln!("Failed to post to the webhook: {}", String::from_utf8_lossy(&output.stderr).trim()),
+        Err(e) => eprintln!("Failed to post to the webhook: {e}")
+    }
+}